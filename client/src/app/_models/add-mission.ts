@@ -1,5 +1,12 @@
+import { MissionDifficulty } from './mission'
+
 export interface AddMission {
     name: string
     description?: string
-    difficulty: string
+    difficulty: MissionDifficulty
+    min_crew?: number
+    max_crew?: number
+    // UTC, e.g. 2026-11-03T18:00:00
+    scheduled_start?: string
+    deadline?: string
 }
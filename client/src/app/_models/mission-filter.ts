@@ -1,10 +1,22 @@
+import { MissionDifficulty } from './mission'
+
 export interface MissionFilter {
+    q?: string
     name?: string
     status?: MissionStatus
+    difficulty?: MissionDifficulty[]
+    chief_id?: number
+    created_after?: string
+    created_before?: string
+    has_open_slots?: boolean
+    membership?: MissionMembership
 }
 
+export type MissionMembership = 'joined' | 'not_joined'
+
 export type MissionStatus =
     'Open' |
     'InProgress' |
     'Completed' |
-    'Failed'
\ No newline at end of file
+    'Failed' |
+    'Cancelled'
\ No newline at end of file
@@ -0,0 +1,42 @@
+import { MissionDifficulty } from './mission'
+
+export type RecurrenceFrequency = 'Daily' | 'Weekly' | 'Monthly'
+export type Weekday = 'Mon' | 'Tue' | 'Wed' | 'Thu' | 'Fri' | 'Sat' | 'Sun'
+
+export interface MissionSeries {
+    id: number
+    chief_id: number
+    name: string
+    description?: string
+    difficulty: MissionDifficulty
+    min_crew: number
+    max_crew: number
+    join_policy: string
+    duration_minutes?: number
+    frequency: RecurrenceFrequency
+    // Only used by Weekly series
+    weekdays: Weekday[]
+    starts_at: string
+    ends_on?: string
+    occurrence_count?: number
+    generated_count: number
+    // Start of the next occurrence to be created, empty once the series has ended
+    next_occurrence?: string
+    created_at: Date
+    updated_at: Date
+}
+
+export interface AddMissionSeries {
+    name: string
+    description?: string
+    difficulty: MissionDifficulty
+    min_crew?: number
+    max_crew?: number
+    join_policy?: string
+    duration_minutes?: number
+    frequency: RecurrenceFrequency
+    weekdays?: Weekday[]
+    starts_at: string
+    ends_on?: string
+    occurrence_count?: number
+}
@@ -0,0 +1,26 @@
+import { MissionDifficulty } from './mission'
+
+export interface MissionTemplate {
+    id: number
+    owner_id: number
+    name: string
+    description?: string
+    difficulty: MissionDifficulty
+    min_crew: number
+    max_crew: number
+    join_policy: string
+    // A mission made from the template gets deadline = scheduled_start + duration
+    duration_minutes?: number
+    created_at: Date
+    updated_at: Date
+}
+
+export interface AddMissionTemplate {
+    name: string
+    description?: string
+    difficulty: MissionDifficulty
+    min_crew?: number
+    max_crew?: number
+    join_policy?: string
+    duration_minutes?: number
+}
@@ -2,12 +2,22 @@ export interface Mission {
     id: number
     name: string
     description?: string
-    difficulty: string
+    difficulty: MissionDifficulty
     status: string
     chief_id: number
     chief_display_name: string
     crew_count: number
     max_crew: number
+    // crew members needed before the mission can start
+    min_crew: number
+    scheduled_start?: string
+    deadline?: string
+    // Set when the mission is an occurrence of a recurring series
+    series_id?: number
     created_at: Date
     updated_at: Date
-}
\ No newline at end of file
+    // Only present on full-text search results, snippet marks matches with <mark>
+    rank?: number
+    snippet?: string
+}
+export type MissionDifficulty = 'Easy' | 'Medium' | 'Hard'
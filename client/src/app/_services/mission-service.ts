@@ -5,6 +5,10 @@ import { MissionFilter } from '../_models/mission-filter'
 import { firstValueFrom } from 'rxjs'
 import { Mission } from '../_models/mission'
 import { AddMission } from '../_models/add-mission'
+import { Page } from '../_models/page'
+
+// Missions fetched per request, the next ones are asked for with the page's next_cursor
+const PAGE_SIZE = 20
 
 @Injectable({
   providedIn: 'root',
@@ -16,23 +20,48 @@ export class MissionService {
 
   filter: MissionFilter = {}
 
-  async getByFilter(filter: MissionFilter): Promise<Mission[]> {
-    const queryString = this.createQueryString(filter)
+  async getByFilter(filter: MissionFilter, cursor?: string): Promise<Page<Mission>> {
+    const queryString = this.createQueryString(filter, cursor)
     const url = this._base_url + '/view/filter?' + queryString
-    const missions = await firstValueFrom(this._http.get<Mission[]>(url))
-    return missions
+    return await firstValueFrom(this._http.get<Page<Mission>>(url))
   }
 
-  private createQueryString(filter: MissionFilter): string {
+  private createQueryString(filter: MissionFilter, cursor?: string): string {
     this.filter = filter
     const params: string[] = []
 
+    if (filter.q && filter.q.trim()) {
+      params.push(`q=${encodeURIComponent(filter.q.trim())}`)
+    }
     if (filter.name && filter.name.trim()) {
       params.push(`name=${encodeURIComponent(filter.name.trim())}`)
     }
     if (filter.status) {
       params.push(`status=${encodeURIComponent(filter.status)}`)
     }
+    if (filter.difficulty && filter.difficulty.length > 0) {
+      params.push(`difficulty=${encodeURIComponent(filter.difficulty.join(','))}`)
+    }
+    if (filter.chief_id !== undefined) {
+      params.push(`chief_id=${filter.chief_id}`)
+    }
+    if (filter.created_after) {
+      params.push(`created_after=${encodeURIComponent(filter.created_after)}`)
+    }
+    if (filter.created_before) {
+      params.push(`created_before=${encodeURIComponent(filter.created_before)}`)
+    }
+    if (filter.has_open_slots !== undefined) {
+      params.push(`has_open_slots=${filter.has_open_slots}`)
+    }
+    if (filter.membership) {
+      params.push(`membership=${filter.membership}`)
+    }
+
+    params.push(`limit=${PAGE_SIZE}`)
+    if (cursor) {
+      params.push(`cursor=${encodeURIComponent(cursor)}`)
+    }
 
     return params.join("&")
   }
@@ -44,12 +73,14 @@ export class MissionService {
     return resp.mission_id
   }
 
-  async getMyMissions(): Promise<Mission[]> {
-    const url = this._base_url + '/brawler/my-missions'
+  async getMyMissions(cursor?: string): Promise<Page<Mission>> {
+    let url = this._base_url + `/brawler/my-missions?limit=${PAGE_SIZE}`
+    if (cursor) {
+      url += `&cursor=${encodeURIComponent(cursor)}`
+    }
     console.log('get ' + url)
-    const observable = this._http.get<Mission[]>(url)
-    const missions = await firstValueFrom(observable)
-    return missions
+    const observable = this._http.get<Page<Mission>>(url)
+    return await firstValueFrom(observable)
   }
 
   async join(missionId: number): Promise<string> {
@@ -28,6 +28,8 @@ export class MissionManager {
   readonly myMissions$ = this._missionsSubject.asObservable()
 
   isLoading = false
+  // Where the next page starts, null once the last one is loaded
+  nextCursor: string | null = null
 
   constructor() {
     this.loadMyMission()
@@ -36,8 +38,21 @@ export class MissionManager {
   private async loadMyMission() {
     try {
       this.isLoading = true
-      const missions = await this._mission.getMyMissions()
-      this._missionsSubject.next(missions)
+      const page = await this._mission.getMyMissions()
+      this._missionsSubject.next(page.items)
+      this.nextCursor = page.next_cursor
+    } finally {
+      this.isLoading = false
+    }
+  }
+
+  async loadMore() {
+    if (!this.nextCursor) return
+    try {
+      this.isLoading = true
+      const page = await this._mission.getMyMissions(this.nextCursor)
+      this._missionsSubject.next([...this._missionsSubject.value, ...page.items])
+      this.nextCursor = page.next_cursor
     } finally {
       this.isLoading = false
     }
@@ -82,6 +97,7 @@ export class MissionManager {
     this.editForm = {
       name: mission.name,
       description: mission.description,
+      min_crew: mission.min_crew,
       max_crew: mission.max_crew,
       difficulty: mission.difficulty
     }
@@ -96,7 +112,10 @@ export class MissionManager {
     if (this.editingId && this.editForm) {
       try {
         this.isLoading = true
-        // Ensure max_crew is a number if present
+        // Ensure min_crew and max_crew are numbers if present
+        if (this.editForm.min_crew) {
+          this.editForm.min_crew = Number(this.editForm.min_crew);
+        }
         if (this.editForm.max_crew) {
           this.editForm.max_crew = Number(this.editForm.max_crew);
         }
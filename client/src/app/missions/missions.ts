@@ -28,6 +28,8 @@ export class Missions {
   userId: Signal<number | undefined>
 
   isLoading = false
+  // Where the next page starts, null once the last one is loaded
+  nextCursor: string | null = null
   editingId: number | undefined
   editForm: Partial<Mission> = {}
   myMemberships: number[] = []
@@ -53,8 +55,23 @@ export class Missions {
   private async loadMyMission() {
     try {
       this.isLoading = true
-      const missions = await this._mission.getByFilter(this.filter)
-      this._missionsSubject.next(missions)
+      const page = await this._mission.getByFilter(this.filter)
+      this._missionsSubject.next(page.items)
+      this.nextCursor = page.next_cursor
+    } catch (e) {
+      console.error(e)
+    } finally {
+      this.isLoading = false
+    }
+  }
+
+  async loadMore() {
+    if (!this.nextCursor) return
+    try {
+      this.isLoading = true
+      const page = await this._mission.getByFilter(this.filter, this.nextCursor)
+      this._missionsSubject.next([...this._missionsSubject.value, ...page.items])
+      this.nextCursor = page.next_cursor
     } catch (e) {
       console.error(e)
     } finally {
@@ -1,46 +1,253 @@
 use std::sync::Arc;
 
-use anyhow::Result;
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
 
 use crate::{
-    domain::repositories::brawlers::BrawlerRepository,
+    config::config_loader::get_jwt_env,
+    domain::{
+        entities::refresh_tokens::AddRefreshTokenEntity,
+        errors::{AppError, AppResult},
+        repositories::{
+            brawlers::BrawlerRepository, refresh_tokens::RefreshTokenRepository,
+            token_revocation::TokenRevocationRepository,
+        },
+        value_objects::{brawler_model::RegisterBrawlerModel, brawler_progression::XpRules},
+    },
     infrastructure::{
         argon2,
-        jwt::{authentication_model::LoginModel, jwt_model::Passport},
+        jwt::{
+            authentication_model::LoginModel,
+            generate_token, hash_token,
+            jwt_model::{Claims, Passport, RefreshClaims},
+            verify_refresh_token,
+        },
     },
 };
-pub struct AuthenticationUseCase<T>
+pub struct AuthenticationUseCase<T1, T2, T3>
 where
-    T: BrawlerRepository + Send + Sync,
+    T1: BrawlerRepository + Send + Sync,
+    T2: RefreshTokenRepository + Send + Sync,
+    T3: TokenRevocationRepository + Send + Sync,
 {
-    brawler_repository: Arc<T>,
+    brawler_repository: Arc<T1>,
+    refresh_token_repository: Arc<T2>,
+    token_revocation_repository: Arc<T3>,
+    xp_rules: XpRules,
 }
-impl<T> AuthenticationUseCase<T>
+impl<T1, T2, T3> AuthenticationUseCase<T1, T2, T3>
 where
-    T: BrawlerRepository + Sync + Send,
+    T1: BrawlerRepository + Sync + Send,
+    T2: RefreshTokenRepository + Send + Sync,
+    T3: TokenRevocationRepository + Send + Sync,
 {
-    pub fn new(brawler_repository: Arc<T>) -> Self {
-        Self { brawler_repository }
+    pub fn new(
+        brawler_repository: Arc<T1>,
+        refresh_token_repository: Arc<T2>,
+        token_revocation_repository: Arc<T3>,
+        xp_rules: XpRules,
+    ) -> Self {
+        Self {
+            brawler_repository,
+            refresh_token_repository,
+            token_revocation_repository,
+            xp_rules,
+        }
     }
 
-    pub async fn login(&self, login_model: LoginModel) -> Result<Passport> {
+    pub async fn register(
+        &self,
+        mut register_brawler_model: RegisterBrawlerModel,
+    ) -> AppResult<Passport> {
+        register_brawler_model.password = argon2::hash(register_brawler_model.password.clone())?;
+
+        let register_entity = register_brawler_model.to_entity();
+        let passport = self.brawler_repository.register(register_entity).await?;
+
+        let (refresh_token, entity) = sign_refresh_token(passport.id, Uuid::new_v4().to_string())?;
+        self.refresh_token_repository.store(entity).await?;
+
+        Ok(passport.with_refresh_token(refresh_token))
+    }
+
+    pub async fn login(&self, login_model: LoginModel) -> AppResult<Passport> {
         let username = login_model.username.clone();
 
         //find this user in database
-        let user = self.brawler_repository.find_by_username(username).await?;
+        let user = match self.brawler_repository.find_by_username(username).await {
+            Ok(user) => user,
+            Err(AppError::NotFound(_)) => {
+                return Err(AppError::Unauthorized(
+                    "Invalid username or password".to_string(),
+                ));
+            }
+            Err(e) => return Err(e),
+        };
+        let progression = self.xp_rules.progression(user.xp);
         let hashed_password = user.password;
 
         if !argon2::verify(login_model.password, hashed_password)? {
-            return Err(anyhow::anyhow!("Invalid Password !!"));
+            return Err(AppError::Unauthorized(
+                "Invalid username or password".to_string(),
+            ));
         }
 
-        let passport = Passport::new(user.id, user.display_name, user.avatar_url)?;
-        Ok(passport)
+        let passport = Passport::new(
+            user.id,
+            user.token_generation,
+            user.display_name,
+            user.avatar_url,
+            progression,
+        )?;
+
+        // Every login starts a new token family
+        let (refresh_token, entity) = sign_refresh_token(user.id, Uuid::new_v4().to_string())?;
+        self.refresh_token_repository.store(entity).await?;
+
+        Ok(passport.with_refresh_token(refresh_token))
+    }
+
+    pub async fn refresh(&self, refresh_token: String) -> AppResult<Passport> {
+        let jwt_env = get_jwt_env()?;
+        let claims = verify_refresh_token(jwt_env.refresh_secret, refresh_token.clone())
+            .map_err(|_| AppError::Unauthorized("Invalid refresh token".to_string()))?;
+
+        let stored = self
+            .refresh_token_repository
+            .find_by_hash(hash_token(&refresh_token))
+            .await
+            .map_err(|_| AppError::Unauthorized("Invalid refresh token".to_string()))?;
+
+        if stored.family_id != claims.fid || stored.brawler_id.to_string() != claims.sub {
+            return Err(AppError::Unauthorized("Invalid refresh token".to_string()));
+        }
+
+        // A revoked token being presented again means it leaked: kill the whole family
+        if stored.revoked_at.is_some() {
+            self.refresh_token_repository
+                .revoke_family(stored.family_id)
+                .await?;
+            return Err(AppError::Unauthorized(
+                "Refresh token reuse detected".to_string(),
+            ));
+        }
+
+        let (new_refresh_token, entity) =
+            sign_refresh_token(stored.brawler_id, stored.family_id.clone())?;
+
+        // Losing the race to rotate means another request already used this token
+        if !self
+            .refresh_token_repository
+            .rotate(stored.id, entity)
+            .await?
+        {
+            self.refresh_token_repository
+                .revoke_family(stored.family_id)
+                .await?;
+            return Err(AppError::Unauthorized(
+                "Refresh token reuse detected".to_string(),
+            ));
+        }
+
+        let user = self
+            .brawler_repository
+            .find_by_id(stored.brawler_id)
+            .await?;
+        let progression = self.xp_rules.progression(user.xp);
+        let passport = Passport::new(
+            user.id,
+            user.token_generation,
+            user.display_name,
+            user.avatar_url,
+            progression,
+        )?;
+        Ok(passport.with_refresh_token(new_refresh_token))
     }
 
-    pub async fn get_me(&self, user_id: i32) -> Result<Passport> {
+    pub async fn get_me(&self, claims: Claims) -> AppResult<Passport> {
+        let user_id = claims
+            .sub
+            .parse::<i32>()
+            .map_err(|_| AppError::Unauthorized("Invalid token subject".to_string()))?;
+        if self
+            .token_revocation_repository
+            .is_revoked(claims.jti, user_id, claims.gen)
+            .await?
+        {
+            return Err(AppError::Unauthorized("Token has been revoked".to_string()));
+        }
+
         let user = self.brawler_repository.find_by_id(user_id).await?;
-        let passport = Passport::new(user.id, user.display_name, user.avatar_url)?;
+        let progression = self.xp_rules.progression(user.xp);
+        let passport = Passport::new(
+            user.id,
+            user.token_generation,
+            user.display_name,
+            user.avatar_url,
+            progression,
+        )?;
         Ok(passport)
     }
+
+    pub async fn logout(&self, claims: Claims, refresh_token: Option<String>) -> AppResult<()> {
+        let user_id = claims
+            .sub
+            .parse::<i32>()
+            .map_err(|_| AppError::Unauthorized("Invalid token subject".to_string()))?;
+        let expires_at = DateTime::from_timestamp(claims.exp as i64, 0)
+            .unwrap_or_else(Utc::now)
+            .naive_utc();
+        self.token_revocation_repository
+            .revoke(claims.jti, user_id, expires_at)
+            .await?;
+
+        // Also end the refresh token family of this session, if the client sent it
+        if let Some(refresh_token) = refresh_token {
+            if let Ok(stored) = self
+                .refresh_token_repository
+                .find_by_hash(hash_token(&refresh_token))
+                .await
+            {
+                if stored.brawler_id == user_id {
+                    self.refresh_token_repository
+                        .revoke_family(stored.family_id)
+                        .await?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub async fn logout_all(&self, brawler_id: i32) -> AppResult<()> {
+        self.token_revocation_repository
+            .revoke_all(brawler_id)
+            .await?;
+        Ok(())
+    }
+}
+
+fn sign_refresh_token(
+    brawler_id: i32,
+    family_id: String,
+) -> AppResult<(String, AddRefreshTokenEntity)> {
+    let jwt_env = get_jwt_env()?;
+    let expires_at = Utc::now() + Duration::days(jwt_env.refresh_ttl);
+    let claims = RefreshClaims {
+        sub: brawler_id.to_string(),
+        jti: Uuid::new_v4().to_string(),
+        fid: family_id.clone(),
+        exp: expires_at.timestamp() as usize,
+        iat: Utc::now().timestamp() as usize,
+    };
+    let token = generate_token(jwt_env.refresh_secret, &claims)?;
+
+    let entity = AddRefreshTokenEntity {
+        brawler_id,
+        family_id,
+        token_hash: hash_token(&token),
+        expires_at: expires_at.naive_utc(),
+    };
+
+    Ok((token, entity))
 }
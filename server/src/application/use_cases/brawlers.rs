@@ -1,14 +1,17 @@
 use crate::{
     domain::{
+        errors::{AppError, AppResult},
         repositories::brawlers::BrawlerRepository,
         value_objects::{
-            base64_img::Base64Img, brawler_model::RegisterBrawlerModel,
-            mission_model::MissionModel, uploaded_img::UploadedImg,
+            base64_img::Base64Img,
+            brawler_progression::XpRules,
+            mission_model::MissionModel,
+            pagination::{MissionPageQuery, Page},
+            uploaded_img::UploadedImg,
         },
     },
-    infrastructure::{argon2::hash, cloudinary::UploadImageOptions, jwt::jwt_model::Passport},
+    infrastructure::cloudinary::UploadImageOptions,
 };
-use anyhow::{Ok, Result};
 use std::sync::Arc;
 
 pub struct BrawlersUseCase<T>
@@ -16,43 +19,33 @@ where
     T: BrawlerRepository + Send + Sync,
 {
     brawler_repository: Arc<T>,
+    xp_rules: XpRules,
 }
 
 impl<T> BrawlersUseCase<T>
 where
     T: BrawlerRepository + Send + Sync,
 {
-    pub fn new(brawler_repository: Arc<T>) -> Self {
-        Self { brawler_repository }
-    }
-
-    pub async fn register(
-        &self,
-        mut register_brawler_model: RegisterBrawlerModel,
-    ) -> Result<Passport> {
-        let hashed_password = hash(register_brawler_model.password.clone())?;
-
-        register_brawler_model.password = hashed_password;
-
-        let register_entity = register_brawler_model.to_entity();
-
-        let passport = self.brawler_repository.register(register_entity).await?;
-
-        Ok(passport)
+    pub fn new(brawler_repository: Arc<T>, xp_rules: XpRules) -> Self {
+        Self {
+            brawler_repository,
+            xp_rules,
+        }
     }
 
     pub async fn upload_base64img(
         &self,
         user_id: i32,
         base64string: String,
-    ) -> Result<UploadedImg> {
+    ) -> AppResult<UploadedImg> {
         let opt = UploadImageOptions {
             folder: Some("avatar".to_string()),
             public_id: Some(user_id.to_string()),
             transformation: Some("c_scale,w_256".to_string()),
         };
 
-        let base64img = Base64Img::new(base64string)?;
+        let base64img =
+            Base64Img::new(base64string).map_err(|e| AppError::Validation(e.to_string()))?;
 
         let uploaded = self
             .brawler_repository
@@ -62,27 +55,33 @@ where
         Ok(uploaded)
     }
 
-    pub async fn get_missions(&self, brawler_id: i32) -> Result<Vec<MissionModel>> {
-        let missions = self.brawler_repository.get_missions(brawler_id).await?;
+    pub async fn get_missions(
+        &self,
+        brawler_id: i32,
+        page: &MissionPageQuery,
+    ) -> AppResult<Page<MissionModel>> {
+        page.validate()?;
+
+        let missions = self.brawler_repository.get_missions(brawler_id, page).await?;
         Ok(missions)
     }
     
     pub async fn get_profile_stats(
         &self,
         brawler_id: i32,
-    ) -> Result<crate::domain::value_objects::profile_stats::ProfileStats> {
+    ) -> AppResult<crate::domain::value_objects::profile_stats::ProfileStats> {
         let stats = self.brawler_repository.get_profile_stats(brawler_id).await?;
-        Ok(stats)
+        Ok(stats.to_model(&self.xp_rules))
     }
 
-    pub async fn update_username(&self, brawler_id: i32, new_username: String) -> Result<()> {
+    pub async fn update_username(&self, brawler_id: i32, new_username: String) -> AppResult<()> {
         self.brawler_repository
             .update_username(brawler_id, new_username)
             .await?;
         Ok(())
     }
 
-    pub async fn update_avatar_url(&self, brawler_id: i32, url: String) -> Result<()> {
+    pub async fn update_avatar_url(&self, brawler_id: i32, url: String) -> AppResult<()> {
         self.brawler_repository.update_avatar_url(brawler_id, url).await?;
         Ok(())
     }
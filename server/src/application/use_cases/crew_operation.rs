@@ -1,61 +1,85 @@
 use crate::domain::{
     entities::crew_memberships::CrewMemberShips,
+    errors::{AppError, AppResult},
     repositories::{
-        crew_operation::CrewOperationRepository, mission_viewing::MissionViewingRepository,
+        crew_operation::CrewOperationRepository, join_requests::JoinRequestRepository,
+        mission_viewing::MissionViewingRepository,
+    },
+    value_objects::{
+        crew_management::KickCrewMemberModel,
+        join_requests::{JoinOutcome, JoinRequestModel, MissionInvitationModel},
+        mission_join_policy::MissionJoinPolicy,
+        mission_statuses::MissionStatuses,
     },
-    value_objects::mission_statuses::MissionStatuses,
 };
-use anyhow::Result;
 use std::sync::Arc;
 
-pub struct CrewOperationUseCase<T1, T2>
+pub struct CrewOperationUseCase<T1, T2, T3>
 where
     T1: CrewOperationRepository + Send + Sync,
     T2: MissionViewingRepository + Send + Sync,
+    T3: JoinRequestRepository + Send + Sync,
 {
     crew_operation_repository: Arc<T1>,
     mission_viewing_repository: Arc<T2>,
+    join_request_repository: Arc<T3>,
 }
 
-impl<T1, T2> CrewOperationUseCase<T1, T2>
+impl<T1, T2, T3> CrewOperationUseCase<T1, T2, T3>
 where
     T1: CrewOperationRepository + Send + Sync + 'static,
     T2: MissionViewingRepository + Send + Sync,
+    T3: JoinRequestRepository + Send + Sync,
 {
-    pub fn new(crew_operation_repository: Arc<T1>, mission_viewing_repository: Arc<T2>) -> Self {
+    pub fn new(
+        crew_operation_repository: Arc<T1>,
+        mission_viewing_repository: Arc<T2>,
+        join_request_repository: Arc<T3>,
+    ) -> Self {
         Self {
             crew_operation_repository,
             mission_viewing_repository,
+            join_request_repository,
         }
     }
 
-    pub async fn join(&self, mission_id: i32, brawler_id: i32) -> Result<()> {
+    pub async fn join(&self, mission_id: i32, brawler_id: i32) -> AppResult<JoinOutcome> {
         let mission = self.mission_viewing_repository.get_one(mission_id).await?;
 
-        if mission.chief_id == brawler_id {
-            return Err(anyhow::anyhow!(
-                "The Chief can not join in his own mission as a crew member!!"
-            ));
-        }
-
-        if self
-            .mission_viewing_repository
-            .is_crew_member(mission_id, brawler_id)
-            .await?
-        {
-            return Err(anyhow::anyhow!("You have already joined this mission!!"));
+        // Status, capacity and membership are all re-checked by the repositories while the
+        // mission row is locked, the policy read here only decides where the join goes.
+        match mission.join_policy {
+            MissionJoinPolicy::Open => {
+                self.crew_operation_repository
+                    .join(CrewMemberShips {
+                        mission_id,
+                        brawler_id,
+                    })
+                    .await?;
+                Ok(JoinOutcome::Joined)
+            }
+            MissionJoinPolicy::ApprovalRequired => {
+                let request_id = self
+                    .join_request_repository
+                    .request(mission_id, brawler_id)
+                    .await?;
+                Ok(JoinOutcome::Requested { request_id })
+            }
+            MissionJoinPolicy::InviteOnly => Err(AppError::Forbidden(
+                "This mission is invite only, ask the chief for an invitation".to_string(),
+            )),
         }
+    }
 
-        if mission.status != MissionStatuses::Open.to_string() {
-            return Err(anyhow::anyhow!("Mission is not joinable"));
-        }
+    pub async fn leave(&self, mission_id: i32, brawler_id: i32) -> AppResult<()> {
+        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
 
-        if mission.crew_count >= mission.max_crew as i64 {
-            return Err(anyhow::anyhow!("Mission is full"));
+        let leaving_condition = mission.status == MissionStatuses::Open;
+        if !leaving_condition {
+            return Err(AppError::Conflict("Mission is not leavable".to_string()));
         }
-
         self.crew_operation_repository
-            .join(CrewMemberShips {
+            .leave(CrewMemberShips {
                 mission_id,
                 brawler_id,
             })
@@ -64,20 +88,80 @@ where
         Ok(())
     }
 
-    pub async fn leave(&self, mission_id: i32, brawler_id: i32) -> Result<()> {
-        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
-
-        let leaving_condition = mission.status == MissionStatuses::Open.to_string();
-        if !leaving_condition {
-            return Err(anyhow::anyhow!("Mission is not leavable"));
+    pub async fn kick(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        kick_crew_member_model: KickCrewMemberModel,
+    ) -> AppResult<()> {
+        if kick_crew_member_model.brawler_id == chief_id {
+            return Err(AppError::Validation(
+                "The Chief can not remove himself, transfer the mission first".to_string(),
+            ));
         }
+
         self.crew_operation_repository
-            .leave(CrewMemberShips {
-                mission_id,
-                brawler_id,
-            })
+            .kick(kick_crew_member_model.to_entity(mission_id, chief_id))
             .await?;
+        Ok(())
+    }
 
+    pub async fn get_pending_requests(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+    ) -> AppResult<Vec<JoinRequestModel>> {
+        let result = self
+            .join_request_repository
+            .get_pending_requests(mission_id, chief_id)
+            .await?;
+        Ok(result)
+    }
+
+    pub async fn approve_request(&self, request_id: i32, chief_id: i32) -> AppResult<()> {
+        self.join_request_repository
+            .approve(request_id, chief_id)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn reject_request(&self, request_id: i32, chief_id: i32) -> AppResult<()> {
+        self.join_request_repository
+            .reject(request_id, chief_id)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn invite(&self, mission_id: i32, chief_id: i32, brawler_id: i32) -> AppResult<i32> {
+        let result = self
+            .join_request_repository
+            .invite(mission_id, chief_id, brawler_id)
+            .await?;
+        Ok(result)
+    }
+
+    pub async fn get_pending_invitations(
+        &self,
+        brawler_id: i32,
+    ) -> AppResult<Vec<MissionInvitationModel>> {
+        let result = self
+            .join_request_repository
+            .get_pending_invitations(brawler_id)
+            .await?;
+        Ok(result)
+    }
+
+    pub async fn accept_invitation(&self, invitation_id: i32, brawler_id: i32) -> AppResult<()> {
+        self.join_request_repository
+            .accept_invitation(invitation_id, brawler_id)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn decline_invitation(&self, invitation_id: i32, brawler_id: i32) -> AppResult<()> {
+        self.join_request_repository
+            .decline_invitation(invitation_id, brawler_id)
+            .await?;
         Ok(())
     }
 }
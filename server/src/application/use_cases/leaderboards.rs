@@ -0,0 +1,83 @@
+use std::sync::Arc;
+
+use chrono::{NaiveDateTime, TimeDelta};
+
+use crate::domain::{
+    errors::AppResult,
+    repositories::leaderboards::LeaderboardRepository,
+    value_objects::leaderboards::{
+        LeaderboardBoard, LeaderboardModel, LeaderboardQuery, LeaderboardWindow, TOP_TEN,
+    },
+};
+
+pub struct LeaderboardUseCase<T>
+where
+    T: LeaderboardRepository + Send + Sync,
+{
+    leaderboard_repository: Arc<T>,
+}
+
+impl<T> LeaderboardUseCase<T>
+where
+    T: LeaderboardRepository + Send + Sync,
+{
+    pub fn new(leaderboard_repository: Arc<T>) -> Self {
+        Self {
+            leaderboard_repository,
+        }
+    }
+
+    pub async fn get_board(
+        &self,
+        board: LeaderboardBoard,
+        query: &LeaderboardQuery,
+    ) -> AppResult<LeaderboardModel> {
+        let places = query.places()?;
+        self.load(board, query.window, places).await
+    }
+
+    // What the team posts in chat: the top ten of the last 7 days on every board, so the post
+    // covers a full week whatever day it goes out. `this_week` is there for a running tally.
+    pub async fn get_weekly_top_ten(&self) -> AppResult<Vec<LeaderboardModel>> {
+        let mut boards = Vec::new();
+        for board in LeaderboardBoard::ALL {
+            boards.push(
+                self.load(board, LeaderboardWindow::Last7Days, TOP_TEN)
+                    .await?,
+            );
+        }
+        Ok(boards)
+    }
+
+    // Called by the scheduler, rebuilds the boards once they are older than `max_age`.
+    // Returns whether they were rebuilt.
+    pub async fn refresh(&self, now: NaiveDateTime, max_age: TimeDelta) -> AppResult<bool> {
+        let refreshed_at = self.leaderboard_repository.refreshed_at().await?;
+        if refreshed_at.is_some_and(|refreshed_at| now - refreshed_at < max_age) {
+            return Ok(false);
+        }
+
+        self.leaderboard_repository.refresh(now).await?;
+        Ok(true)
+    }
+
+    async fn load(
+        &self,
+        board: LeaderboardBoard,
+        window: LeaderboardWindow,
+        places: i32,
+    ) -> AppResult<LeaderboardModel> {
+        let refreshed_at = self.leaderboard_repository.refreshed_at().await?;
+        let entries = self
+            .leaderboard_repository
+            .get_entries(board, window, places)
+            .await?;
+
+        Ok(LeaderboardModel {
+            board,
+            window,
+            refreshed_at,
+            entries,
+        })
+    }
+}
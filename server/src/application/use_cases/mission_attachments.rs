@@ -0,0 +1,165 @@
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        errors::{AppError, AppResult},
+        repositories::{
+            mission_attachments::MissionAttachmentRepository,
+            mission_viewing::MissionViewingRepository,
+        },
+        value_objects::{
+            base64_img::Base64Img,
+            mission_attachment::{
+                MAX_ATTACHMENT_BYTES, MissionAttachmentKind, MissionAttachmentModel,
+                UploadMissionAttachmentModel, ensure_room_for_file,
+            },
+        },
+    },
+    infrastructure::cloudinary::UploadImageOptions,
+};
+
+pub struct MissionAttachmentUseCase<T1, T2>
+where
+    T1: MissionAttachmentRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    mission_attachment_repository: Arc<T1>,
+    mission_viewing_repository: Arc<T2>,
+}
+
+impl<T1, T2> MissionAttachmentUseCase<T1, T2>
+where
+    T1: MissionAttachmentRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    pub fn new(
+        mission_attachment_repository: Arc<T1>,
+        mission_viewing_repository: Arc<T2>,
+    ) -> Self {
+        Self {
+            mission_attachment_repository,
+            mission_viewing_repository,
+        }
+    }
+
+    async fn ensure_chief(&self, mission_id: i32, chief_id: i32) -> AppResult<()> {
+        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+        if mission.chief_id != chief_id {
+            return Err(AppError::Forbidden(
+                "Only the chief can manage attachments of this mission".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    // Checked before anything is sent to storage
+    fn decode(kind: MissionAttachmentKind, base64string: String) -> AppResult<Base64Img> {
+        let base64img = Base64Img::with_types(base64string, kind.allowed_types())
+            .map_err(|e| AppError::Validation(e.to_string()))?;
+        if base64img.size() > MAX_ATTACHMENT_BYTES {
+            return Err(AppError::Validation(format!(
+                "Attachments must be at most {} MB",
+                MAX_ATTACHMENT_BYTES / (1024 * 1024)
+            )));
+        }
+        Ok(base64img)
+    }
+
+    pub async fn get_all(&self, mission_id: i32) -> AppResult<Vec<MissionAttachmentModel>> {
+        // Soft-deleted or unknown missions should 404 instead of returning an empty list
+        self.mission_viewing_repository.get_one(mission_id).await?;
+
+        let attachments = self
+            .mission_attachment_repository
+            .get_all(mission_id)
+            .await?;
+
+        Ok(attachments.iter().map(|a| a.to_model()).collect())
+    }
+
+    pub async fn upload_cover(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        base64string: String,
+    ) -> AppResult<MissionAttachmentModel> {
+        let kind = MissionAttachmentKind::Cover;
+        let base64img = Self::decode(kind, base64string)?;
+        self.ensure_chief(mission_id, chief_id).await?;
+
+        let opt = UploadImageOptions {
+            folder: Some("mission-cover".to_string()),
+            public_id: None,
+            transformation: Some("c_limit,w_1600".to_string()),
+        };
+
+        let attachment = self
+            .mission_attachment_repository
+            .upload(
+                mission_id,
+                chief_id,
+                kind,
+                "cover".to_string(),
+                base64img,
+                opt,
+            )
+            .await?;
+
+        Ok(attachment.to_model())
+    }
+
+    pub async fn upload_file(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        upload_mission_attachment_model: UploadMissionAttachmentModel,
+    ) -> AppResult<MissionAttachmentModel> {
+        let kind = MissionAttachmentKind::File;
+        let file_name = upload_mission_attachment_model.file_name()?;
+        let base64img = Self::decode(kind, upload_mission_attachment_model.base64_string)?;
+        self.ensure_chief(mission_id, chief_id).await?;
+
+        // Saves a pointless upload, the repository checks again under the mission lock
+        let count = self
+            .mission_attachment_repository
+            .count_files(mission_id)
+            .await?;
+        ensure_room_for_file(count)?;
+
+        let opt = UploadImageOptions {
+            folder: Some("mission-attachment".to_string()),
+            public_id: None,
+            transformation: None,
+        };
+
+        let attachment = self
+            .mission_attachment_repository
+            .upload(mission_id, chief_id, kind, file_name, base64img, opt)
+            .await?;
+
+        Ok(attachment.to_model())
+    }
+
+    pub async fn remove(
+        &self,
+        mission_id: i32,
+        attachment_id: i32,
+        chief_id: i32,
+    ) -> AppResult<()> {
+        self.ensure_chief(mission_id, chief_id).await?;
+
+        self.mission_attachment_repository
+            .remove(attachment_id, mission_id)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn remove_cover(&self, mission_id: i32, chief_id: i32) -> AppResult<()> {
+        self.ensure_chief(mission_id, chief_id).await?;
+
+        self.mission_attachment_repository
+            .remove_cover(mission_id)
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,172 @@
+use std::sync::Arc;
+
+use crate::domain::{
+    errors::{AppError, AppResult},
+    repositories::{
+        mission_comments::MissionCommentRepository, mission_viewing::MissionViewingRepository,
+    },
+    value_objects::{
+        mission_comment::{
+            AddMissionCommentModel, CommentAccess, EditMissionCommentModel, MissionCommentModel,
+            validate_body,
+        },
+        pagination::{CommentPageQuery, Page},
+    },
+};
+
+pub struct MissionCommentUseCase<T1, T2>
+where
+    T1: MissionCommentRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    mission_comment_repository: Arc<T1>,
+    mission_viewing_repository: Arc<T2>,
+}
+
+impl<T1, T2> MissionCommentUseCase<T1, T2>
+where
+    T1: MissionCommentRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    pub fn new(mission_comment_repository: Arc<T1>, mission_viewing_repository: Arc<T2>) -> Self {
+        Self {
+            mission_comment_repository,
+            mission_viewing_repository,
+        }
+    }
+
+    // Soft-deleted or unknown missions 404 before any permission is checked
+    async fn access(&self, mission_id: i32, viewer_id: Option<i32>) -> AppResult<CommentAccess> {
+        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+
+        let is_crew = match viewer_id {
+            Some(viewer_id) => {
+                self.mission_viewing_repository
+                    .is_crew_member(mission_id, viewer_id)
+                    .await?
+            }
+            None => false,
+        };
+
+        Ok(CommentAccess::new(&mission, viewer_id, is_crew))
+    }
+
+    pub async fn get_comments(
+        &self,
+        mission_id: i32,
+        viewer_id: Option<i32>,
+        page: &CommentPageQuery,
+    ) -> AppResult<Page<MissionCommentModel>> {
+        page.validate()?;
+        self.access(mission_id, viewer_id)
+            .await?
+            .ensure_can_read()?;
+
+        let result = self
+            .mission_comment_repository
+            .get_page(mission_id, None, page)
+            .await?;
+        Ok(result)
+    }
+
+    pub async fn get_replies(
+        &self,
+        mission_id: i32,
+        comment_id: i32,
+        viewer_id: Option<i32>,
+        page: &CommentPageQuery,
+    ) -> AppResult<Page<MissionCommentModel>> {
+        page.validate()?;
+        self.access(mission_id, viewer_id)
+            .await?
+            .ensure_can_read()?;
+
+        let result = self
+            .mission_comment_repository
+            .get_page(mission_id, Some(comment_id), page)
+            .await?;
+        Ok(result)
+    }
+
+    pub async fn add(
+        &self,
+        mission_id: i32,
+        author_id: i32,
+        add_mission_comment_model: AddMissionCommentModel,
+    ) -> AppResult<i32> {
+        let body = validate_body(&add_mission_comment_model.body)?;
+        self.access(mission_id, Some(author_id))
+            .await?
+            .ensure_can_post()?;
+
+        let result = self
+            .mission_comment_repository
+            .add(add_mission_comment_model.to_entity(mission_id, author_id, body))
+            .await?;
+        Ok(result)
+    }
+
+    // Authors keep editing rights only while they could still post the comment
+    pub async fn edit(
+        &self,
+        mission_id: i32,
+        comment_id: i32,
+        author_id: i32,
+        edit_mission_comment_model: EditMissionCommentModel,
+    ) -> AppResult<()> {
+        let body = validate_body(&edit_mission_comment_model.body)?;
+        self.access(mission_id, Some(author_id))
+            .await?
+            .ensure_can_post()?;
+
+        let comment = self
+            .mission_comment_repository
+            .get_one(comment_id, mission_id)
+            .await?;
+        if comment.author_id != author_id {
+            return Err(AppError::Forbidden(
+                "Only the author can edit this comment".to_string(),
+            ));
+        }
+
+        self.mission_comment_repository
+            .edit(comment_id, mission_id, author_id, body)
+            .await?;
+        Ok(())
+    }
+
+    // Authors delete their own comments, the chief may delete any of them
+    pub async fn remove(&self, mission_id: i32, comment_id: i32, brawler_id: i32) -> AppResult<()> {
+        let access = self.access(mission_id, Some(brawler_id)).await?;
+
+        let comment = self
+            .mission_comment_repository
+            .get_one(comment_id, mission_id)
+            .await?;
+        if comment.author_id != brawler_id {
+            access.ensure_can_moderate()?;
+        }
+
+        self.mission_comment_repository
+            .remove(comment_id, mission_id)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn set_pinned(
+        &self,
+        mission_id: i32,
+        comment_id: i32,
+        chief_id: i32,
+        pinned: bool,
+    ) -> AppResult<()> {
+        self.access(mission_id, Some(chief_id))
+            .await?
+            .ensure_can_moderate()?;
+
+        self.mission_comment_repository
+            .set_pinned(comment_id, mission_id, pinned)
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,225 @@
+use std::sync::Arc;
+
+use chrono::{NaiveDateTime, Utc};
+
+use crate::domain::{
+    entities::mission_outcome_proposals::AddOutcomeVoteEntity,
+    errors::{AppError, AppResult},
+    repositories::{
+        mission_consensus::MissionConsensusRepository, mission_viewing::MissionViewingRepository,
+    },
+    value_objects::{
+        mission_completion_mode::MissionCompletionMode,
+        mission_consensus::{
+            ConsensusRules, OutcomeProposalModel, OutcomeVoteModel, ProposalResolution,
+            ProposeOutcomeModel, ResolveDisputeModel,
+        },
+        mission_state_machine::MissionStateMachine,
+        mission_statuses::MissionStatuses,
+    },
+};
+
+pub struct MissionConsensusUseCase<T1, T2>
+where
+    T1: MissionConsensusRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    mission_consensus_repository: Arc<T1>,
+    mission_viewing_repository: Arc<T2>,
+    rules: ConsensusRules,
+}
+
+impl<T1, T2> MissionConsensusUseCase<T1, T2>
+where
+    T1: MissionConsensusRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    pub fn new(
+        mission_consensus_repository: Arc<T1>,
+        mission_viewing_repository: Arc<T2>,
+        rules: ConsensusRules,
+    ) -> Self {
+        Self {
+            mission_consensus_repository,
+            mission_viewing_repository,
+            rules,
+        }
+    }
+
+    pub async fn get_proposal(&self, mission_id: i32) -> AppResult<OutcomeProposalModel> {
+        self.mission_viewing_repository.get_one(mission_id).await?;
+
+        let result = self
+            .mission_consensus_repository
+            .get_latest(mission_id)
+            .await?;
+        Ok(result)
+    }
+
+    // The crew of the mission right now is who gets to vote
+    pub async fn propose(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        propose_outcome_model: ProposeOutcomeModel,
+    ) -> AppResult<i32> {
+        let outcome = propose_outcome_model.validate()?;
+        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+        if mission.completion_mode != MissionCompletionMode::Consensus {
+            return Err(AppError::InvalidStateTransition(
+                "This mission is settled by its chief, there is nothing to vote on".to_string(),
+            ));
+        }
+        MissionStateMachine::from_model(&mission).transition(chief_id, outcome)?;
+
+        let now = Utc::now().naive_utc();
+        let entity = propose_outcome_model.to_entity(
+            mission_id,
+            chief_id,
+            mission.crew_count,
+            &self.rules,
+            now,
+        );
+        let proposal_id = self.mission_consensus_repository.propose(entity).await?;
+
+        // Without anyone to vote the quorum can never be reached
+        self.settle_if_decided(mission_id, now).await?;
+        Ok(proposal_id)
+    }
+
+    pub async fn vote(
+        &self,
+        mission_id: i32,
+        brawler_id: i32,
+        outcome_vote_model: OutcomeVoteModel,
+    ) -> AppResult<()> {
+        let is_crew = self
+            .mission_viewing_repository
+            .is_crew_member(mission_id, brawler_id)
+            .await?;
+        if !is_crew {
+            return Err(AppError::Forbidden(
+                "Only the crew can vote on the outcome of this mission".to_string(),
+            ));
+        }
+
+        let proposal = self
+            .mission_consensus_repository
+            .get_latest(mission_id)
+            .await?;
+        let now = Utc::now().naive_utc();
+        if !proposal.is_open() || now >= proposal.closes_at {
+            return Err(AppError::InvalidStateTransition(
+                "Voting on this outcome is closed".to_string(),
+            ));
+        }
+
+        self.mission_consensus_repository
+            .vote(AddOutcomeVoteEntity {
+                proposal_id: proposal.id,
+                brawler_id,
+                agree: outcome_vote_model.agree,
+            })
+            .await?;
+
+        self.settle_if_decided(mission_id, now).await?;
+        Ok(())
+    }
+
+    // Called by the scheduler for the votes whose window ran out. Returns the missions
+    // that went to Disputed.
+    pub async fn close_expired(&self, now: NaiveDateTime) -> AppResult<Vec<i32>> {
+        let proposals = self.mission_consensus_repository.get_expired(now).await?;
+
+        let mut disputed = Vec::new();
+        for proposal in proposals {
+            match self.settle(&proposal, now).await {
+                Ok(Some(ProposalResolution::Disputed)) => disputed.push(proposal.mission_id),
+                Ok(_) => {}
+                // The mission changed or was removed since it was loaded
+                Err(AppError::InvalidStateTransition(_)) | Err(AppError::NotFound(_)) => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(disputed)
+    }
+
+    pub async fn resolve_dispute(
+        &self,
+        mission_id: i32,
+        admin_id: i32,
+        resolve_dispute_model: ResolveDisputeModel,
+    ) -> AppResult<i32> {
+        let outcome = resolve_dispute_model.validate()?;
+        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+        if mission.status != MissionStatuses::Disputed {
+            return Err(AppError::InvalidStateTransition(format!(
+                "Mission is {}, only a disputed mission can be resolved",
+                mission.status
+            )));
+        }
+
+        let transition = MissionStateMachine::from_model(&mission)
+            .settle(outcome)?
+            .with_reason(resolve_dispute_model.reason);
+
+        let result = self
+            .mission_consensus_repository
+            .resolve_dispute(mission_id, mission.chief_id, admin_id, transition)
+            .await?;
+        Ok(result)
+    }
+
+    async fn settle_if_decided(&self, mission_id: i32, now: NaiveDateTime) -> AppResult<()> {
+        let proposal = self
+            .mission_consensus_repository
+            .get_latest(mission_id)
+            .await?;
+        if proposal.is_open() {
+            self.settle(&proposal, now).await?;
+        }
+        Ok(())
+    }
+
+    // Moves the mission once the vote is decided, acting as its current chief so the
+    // history shows who owned it
+    async fn settle(
+        &self,
+        proposal: &OutcomeProposalModel,
+        now: NaiveDateTime,
+    ) -> AppResult<Option<ProposalResolution>> {
+        let Some(resolution) = proposal.verdict(now) else {
+            return Ok(None);
+        };
+
+        let mission = self
+            .mission_viewing_repository
+            .get_one(proposal.mission_id)
+            .await?;
+        let (to, reason) = match resolution {
+            ProposalResolution::Applied => (proposal.outcome, proposal.reason.clone()),
+            ProposalResolution::Disputed => (
+                MissionStatuses::Disputed,
+                Some(format!(
+                    "The crew did not confirm {}, {} of the {} votes needed",
+                    proposal.outcome, proposal.agree_count, proposal.required_votes
+                )),
+            ),
+        };
+        let transition = MissionStateMachine::from_model(&mission)
+            .settle(to)?
+            .with_reason(reason);
+
+        let settled = self
+            .mission_consensus_repository
+            .settle(
+                proposal.id,
+                mission.id,
+                mission.chief_id,
+                resolution,
+                transition,
+            )
+            .await?;
+        Ok(settled.then_some(resolution))
+    }
+}
@@ -0,0 +1,130 @@
+use std::sync::Arc;
+
+use crate::domain::{
+    errors::{AppError, AppResult},
+    repositories::{
+        mission_debrief::MissionDebriefRepository, mission_viewing::MissionViewingRepository,
+    },
+    value_objects::{
+        mission_debrief::{
+            MissionDebriefModel, RateChiefModel, RateCrewMemberModel, ensure_debrief_open,
+        },
+        mission_model::MissionModel,
+    },
+};
+
+pub struct MissionDebriefUseCase<T1, T2>
+where
+    T1: MissionDebriefRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    mission_debrief_repository: Arc<T1>,
+    mission_viewing_repository: Arc<T2>,
+}
+
+impl<T1, T2> MissionDebriefUseCase<T1, T2>
+where
+    T1: MissionDebriefRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    pub fn new(mission_debrief_repository: Arc<T1>, mission_viewing_repository: Arc<T2>) -> Self {
+        Self {
+            mission_debrief_repository,
+            mission_viewing_repository,
+        }
+    }
+
+    async fn ensure_crew_member(
+        &self,
+        mission: &MissionModel,
+        brawler_id: i32,
+        message: &str,
+    ) -> AppResult<()> {
+        let is_crew = self
+            .mission_viewing_repository
+            .is_crew_member(mission.id, brawler_id)
+            .await?;
+        if !is_crew {
+            return Err(AppError::Forbidden(message.to_string()));
+        }
+        Ok(())
+    }
+
+    // Only the people who took part can read what they said about each other
+    pub async fn get_debrief(
+        &self,
+        mission_id: i32,
+        viewer_id: i32,
+    ) -> AppResult<MissionDebriefModel> {
+        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+        if mission.chief_id != viewer_id {
+            self.ensure_crew_member(
+                &mission,
+                viewer_id,
+                "Only the chief and crew can read this debrief",
+            )
+            .await?;
+        }
+
+        let ratings = self
+            .mission_debrief_repository
+            .get_ratings(mission_id)
+            .await?;
+        Ok(MissionDebriefModel::new(&mission, ratings))
+    }
+
+    pub async fn rate_chief(
+        &self,
+        mission_id: i32,
+        rater_id: i32,
+        rate_chief_model: RateChiefModel,
+    ) -> AppResult<i32> {
+        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+        ensure_debrief_open(&mission)?;
+        if mission.chief_id == rater_id {
+            return Err(AppError::Forbidden(
+                "The chief rates the crew, not themselves".to_string(),
+            ));
+        }
+        self.ensure_crew_member(
+            &mission,
+            rater_id,
+            "Only the crew can rate the chief of this mission",
+        )
+        .await?;
+
+        let entity = rate_chief_model.to_entity(mission_id, rater_id, mission.chief_id)?;
+        let result = self.mission_debrief_repository.add_rating(entity).await?;
+        Ok(result)
+    }
+
+    pub async fn rate_crew_member(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        brawler_id: i32,
+        rate_crew_member_model: RateCrewMemberModel,
+    ) -> AppResult<i32> {
+        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+        ensure_debrief_open(&mission)?;
+        if mission.chief_id != chief_id {
+            return Err(AppError::Forbidden(
+                "Only the chief can rate the crew of this mission".to_string(),
+            ));
+        }
+        let is_crew = self
+            .mission_viewing_repository
+            .is_crew_member(mission_id, brawler_id)
+            .await?;
+        if !is_crew {
+            return Err(AppError::NotFound(format!(
+                "Brawler {} is not in the crew of this mission",
+                brawler_id
+            )));
+        }
+
+        let entity = rate_crew_member_model.to_entity(mission_id, chief_id, brawler_id)?;
+        let result = self.mission_debrief_repository.add_rating(entity).await?;
+        Ok(result)
+    }
+}
@@ -1,10 +1,25 @@
 use std::sync::Arc;
 
+use chrono::{NaiveDateTime, Utc};
+
 use crate::domain::{
+    errors::{AppError, AppResult},
     repositories::{
         mission_management::MissionManagementRepository, mission_viewing::MissionViewingRepository,
     },
-    value_objects::mission_model::{AddMissionModel, EditMissionModel},
+    value_objects::{
+        crew_management::TransferChiefModel,
+        crew_requirement::{CrewLimits, CrewRequirement},
+        mission_difficulty::MissionDifficulty,
+        mission_model::{
+            AddMissionModel, CloneMissionModel, DeletedMissionModel, EditMissionModel,
+            validate_mission_name,
+        },
+        mission_retention::MissionRetention,
+        mission_schedule::MissionSchedule,
+        mission_state_machine::MissionStateMachine,
+        mission_statuses::MissionStatuses,
+    },
 };
 
 pub struct MissionManagementUseCase<T1, T2>
@@ -13,12 +28,11 @@ where
     T2: MissionViewingRepository + Send + Sync,
 {
     mission_management_repository: Arc<T1>,
-    #[allow(dead_code)]
     mission_viewing_repository: Arc<T2>,
+    crew_limits: CrewLimits,
+    retention: MissionRetention,
 }
 
-use anyhow::Result;
-
 impl<T1, T2> MissionManagementUseCase<T1, T2>
 where
     T1: MissionManagementRepository + Send + Sync,
@@ -27,21 +41,36 @@ where
     pub fn new(
         mission_management_repository: Arc<T1>,
         mission_viewing_repository: Arc<T2>,
+        crew_limits: CrewLimits,
+        retention: MissionRetention,
     ) -> Self {
         Self {
             mission_management_repository,
             mission_viewing_repository,
+            crew_limits,
+            retention,
         }
     }
 
-    pub async fn add(&self, chief_id: i32, add_mission_model: AddMissionModel) -> Result<i32> {
-        if add_mission_model.name.trim().is_empty() || add_mission_model.name.trim().len() < 3 {
-            return Err(anyhow::anyhow!(
-                "Mission name must be least 4 characters long"
-            ));
-        }
+    pub async fn add(&self, chief_id: i32, add_mission_model: AddMissionModel) -> AppResult<i32> {
+        validate_mission_name(&add_mission_model.name)?;
 
-        let insert_mission_entity = add_mission_model.to_entity(chief_id);
+        let difficulty = add_mission_model.difficulty.parse::<MissionDifficulty>()?;
+
+        let schedule = MissionSchedule::new(
+            add_mission_model.scheduled_start,
+            add_mission_model.deadline,
+        );
+        schedule.validate(&schedule, Utc::now().naive_utc())?;
+
+        let defaults = self.crew_limits.default_requirement();
+        let crew = CrewRequirement::new(
+            add_mission_model.min_crew.unwrap_or(defaults.min_crew),
+            add_mission_model.max_crew.unwrap_or(defaults.max_crew),
+        );
+        self.crew_limits.validate(&crew)?;
+
+        let insert_mission_entity = add_mission_model.to_entity(chief_id, difficulty, crew);
 
         let result = self
             .mission_management_repository
@@ -56,15 +85,12 @@ where
         mission_id: i32,
         chief_id: i32,
         mut edit_mission_model: EditMissionModel,
-    ) -> Result<i32> {
+    ) -> AppResult<i32> {
         if let Some(name) = edit_mission_model.name {
             if name.trim().is_empty() {
                 edit_mission_model.name = None;
-            } else if name.trim().len() < 3 {
-                return Err(anyhow::anyhow!(
-                    "Mission name must be least 4 characters long"
-                ));
             } else {
+                validate_mission_name(&name)?;
                 edit_mission_model.name = Some(name.trim().to_string())
             }
         }
@@ -79,7 +105,43 @@ where
         //     ));
         // }
 
-        let edit_mission_entity = edit_mission_model.to_entity(chief_id);
+        let changed = MissionSchedule::new(
+            edit_mission_model.scheduled_start,
+            edit_mission_model.deadline,
+        );
+        let schedule_changed = changed != MissionSchedule::default();
+        let crew_changed =
+            edit_mission_model.min_crew.is_some() || edit_mission_model.max_crew.is_some();
+
+        if schedule_changed || crew_changed {
+            // Whatever is left out of the request keeps its stored value and is checked with it
+            let current = self.mission_viewing_repository.get_one(mission_id).await?;
+
+            if crew_changed {
+                let crew = CrewRequirement::new(
+                    edit_mission_model.min_crew.unwrap_or(current.min_crew),
+                    edit_mission_model.max_crew.unwrap_or(current.max_crew),
+                );
+                self.crew_limits.validate(&crew)?;
+            }
+
+            if schedule_changed {
+                let schedule = MissionSchedule::new(
+                    changed.scheduled_start.or(current.scheduled_start),
+                    changed.deadline.or(current.deadline),
+                );
+                schedule.validate(&changed, Utc::now().naive_utc())?;
+            }
+        }
+
+        // The repository only edits Open missions, which also keeps difficulty fixed once started
+        let difficulty = edit_mission_model
+            .difficulty
+            .as_deref()
+            .map(str::parse::<MissionDifficulty>)
+            .transpose()?;
+
+        let edit_mission_entity = edit_mission_model.to_entity(chief_id, difficulty);
 
         let result = self
             .mission_management_repository
@@ -89,7 +151,45 @@ where
         Ok(result)
     }
 
-    pub async fn remove(&self, mission_id: i32, chief_id: i32) -> Result<()> {
+    pub async fn clone_mission(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        clone_mission_model: CloneMissionModel,
+    ) -> AppResult<i32> {
+        let source = self.mission_viewing_repository.get_one(mission_id).await?;
+        if source.chief_id != chief_id {
+            return Err(AppError::Forbidden(
+                "Only the chief can clone this mission".to_string(),
+            ));
+        }
+
+        let add_mission_entity = clone_mission_model.to_entity(&source, chief_id);
+
+        self.crew_limits.validate(&CrewRequirement::new(
+            add_mission_entity.min_crew,
+            add_mission_entity.max_crew,
+        ))?;
+
+        let schedule = MissionSchedule::new(
+            add_mission_entity.scheduled_start,
+            add_mission_entity.deadline,
+        );
+        schedule.validate(&schedule, Utc::now().naive_utc())?;
+
+        let result = self
+            .mission_management_repository
+            .add_clone(
+                mission_id,
+                add_mission_entity,
+                clone_mission_model.reinvite_crew,
+            )
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn remove(&self, mission_id: i32, chief_id: i32) -> AppResult<()> {
         // let crew_count = self
         //     .mission_viewing_repository
         //     .crew_counting(mission_id)
@@ -106,23 +206,71 @@ where
         Ok(())
     }
 
-    pub async fn start(&self, mission_id: i32, chief_id: i32) -> Result<()> {
+    pub async fn get_deleted(&self, chief_id: i32) -> AppResult<Vec<DeletedMissionModel>> {
+        let result = self
+            .mission_management_repository
+            .get_deleted(chief_id, self.retention, Utc::now().naive_utc())
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn restore(&self, mission_id: i32, chief_id: i32) -> AppResult<()> {
+        let deleted_after = self.retention.cutoff(Utc::now().naive_utc());
+
+        self.mission_management_repository
+            .restore(mission_id, chief_id, deleted_after)
+            .await?;
+        Ok(())
+    }
+
+    // Deletes for good every mission that was removed longer ago than the retention window
+    pub async fn purge_deleted(&self, now: NaiveDateTime) -> AppResult<Vec<i32>> {
+        let result = self
+            .mission_management_repository
+            .purge(self.retention.cutoff(now))
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn start(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        reason: Option<String>,
+    ) -> AppResult<()> {
         let mission = self.mission_viewing_repository.get_one(mission_id).await?;
 
-        if mission.chief_id != chief_id {
-            return Err(anyhow::anyhow!(
-                "Only the Chief can start the mission!"
-            ));
-        }
+        let transition = MissionStateMachine::from_model(&mission)
+            .transition(chief_id, MissionStatuses::InProgress)?
+            .with_reason(reason);
 
-        if mission.status != "Open" {
-             return Err(anyhow::anyhow!(
-                "Mission is not in Open status!"
+        self.mission_management_repository
+            .start(mission_id, chief_id, transition)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn transfer_chief(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        transfer_chief_model: TransferChiefModel,
+    ) -> AppResult<()> {
+        if transfer_chief_model.new_chief_id == chief_id {
+            return Err(AppError::Validation(
+                "You are already the chief of this mission".to_string(),
             ));
         }
 
         self.mission_management_repository
-            .start(mission_id)
+            .transfer_chief(
+                mission_id,
+                chief_id,
+                transfer_chief_model.new_chief_id,
+                transfer_chief_model.stay_in_crew.unwrap_or(true),
+            )
             .await?;
         Ok(())
     }
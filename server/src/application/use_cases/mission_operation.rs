@@ -1,12 +1,14 @@
 use std::sync::Arc;
 
-use anyhow::Result;
-
 use crate::domain::{
+    errors::{AppError, AppResult},
     repositories::{
         mission_operation::MissionOperationRepository, mission_viewing::MissionViewingRepository,
     },
-    value_objects::mission_statuses::MissionStatuses,
+    value_objects::{
+        mission_completion_mode::MissionCompletionMode, mission_state_machine::MissionStateMachine,
+        mission_statuses::MissionStatuses,
+    },
 };
 pub struct MissionOperationUseCase<T1, T2>
 where
@@ -29,62 +31,66 @@ where
         }
     }
 
-    pub async fn in_progress(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
+    pub async fn in_progress(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        reason: Option<String>,
+    ) -> AppResult<i32> {
         let mission = self.mission_viewing_repository.get_one(mission_id).await?;
 
-        let crew_count = self
-            .mission_viewing_repository
-            .crew_counting(mission_id)
-            .await?;
-
-        let is_status_open_or_fail = mission.status == MissionStatuses::Open.to_string()
-            || mission.status == MissionStatuses::Failed.to_string();
-
-        let max_crew_per_mission = std::env::var("MAX_CREW_PER_MISSION")
-            .unwrap_or_else(|_| "5".to_string())
-            .parse::<i32>()
-            .unwrap_or(5);
-
-        let update_condition = is_status_open_or_fail
-            && crew_count > 0
-            && crew_count < max_crew_per_mission.into()
-            && mission.chief_id == chief_id;
-        if !update_condition {
-            return Err(anyhow::anyhow!("Invalid condition to change stages!"));
-        }
+        let transition = MissionStateMachine::from_model(&mission)
+            .transition(chief_id, MissionStatuses::InProgress)?
+            .with_reason(reason);
 
         let result = self
             .mission_operation_repository
-            .to_progress(mission_id, chief_id)
+            .transition(mission_id, chief_id, transition)
             .await?;
         Ok(result)
     }
-    pub async fn to_completed(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
-        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
-
-        let update_condition = mission.status == MissionStatuses::InProgress.to_string()
-            && mission.chief_id == chief_id;
-        if !update_condition {
-            return Err(anyhow::anyhow!("Invalid condition to change stages!"));
-        }
-        let result = self
-            .mission_operation_repository
-            .to_completed(mission_id, chief_id)
-            .await?;
-
-        Ok(result)
+    pub async fn to_completed(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        reason: Option<String>,
+    ) -> AppResult<i32> {
+        self.finish(mission_id, chief_id, MissionStatuses::Completed, reason)
+            .await
     }
-    pub async fn to_failed(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
+    pub async fn to_failed(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        reason: Option<String>,
+    ) -> AppResult<i32> {
+        self.finish(mission_id, chief_id, MissionStatuses::Failed, reason)
+            .await
+    }
+
+    async fn finish(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        to: MissionStatuses,
+        reason: Option<String>,
+    ) -> AppResult<i32> {
         let mission = self.mission_viewing_repository.get_one(mission_id).await?;
 
-        let update_condition = mission.status == MissionStatuses::InProgress.to_string()
-            && mission.chief_id == chief_id;
-        if !update_condition {
-            return Err(anyhow::anyhow!("Invalid condition to change stages!"));
+        let transition = MissionStateMachine::from_model(&mission)
+            .transition(chief_id, to)?
+            .with_reason(reason);
+
+        // The crew has the last word on these, the chief can only propose the outcome
+        if mission.completion_mode == MissionCompletionMode::Consensus {
+            return Err(AppError::InvalidStateTransition(
+                "This mission is settled by a crew vote, propose the outcome instead".to_string(),
+            ));
         }
+
         let result = self
             .mission_operation_repository
-            .to_failed(mission_id, chief_id)
+            .transition(mission_id, chief_id, transition)
             .await?;
 
         Ok(result)
@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use chrono::NaiveDateTime;
+
+use crate::domain::{
+    errors::{AppError, AppResult},
+    repositories::{
+        mission_operation::MissionOperationRepository,
+        mission_scheduler::MissionSchedulerRepository,
+    },
+    value_objects::{
+        mission_schedule::SchedulerReport, mission_state_machine::MissionStateMachine,
+    },
+};
+
+pub struct MissionSchedulerUseCase<T1, T2>
+where
+    T1: MissionSchedulerRepository + Send + Sync,
+    T2: MissionOperationRepository + Send + Sync,
+{
+    mission_scheduler_repository: Arc<T1>,
+    mission_operation_repository: Arc<T2>,
+}
+
+impl<T1, T2> MissionSchedulerUseCase<T1, T2>
+where
+    T1: MissionSchedulerRepository + Send + Sync,
+    T2: MissionOperationRepository + Send + Sync,
+{
+    pub fn new(
+        mission_scheduler_repository: Arc<T1>,
+        mission_operation_repository: Arc<T2>,
+    ) -> Self {
+        Self {
+            mission_scheduler_repository,
+            mission_operation_repository,
+        }
+    }
+
+    // Moves every due mission on, acting as its chief so the history shows who owned it
+    pub async fn run(&self, now: NaiveDateTime) -> AppResult<SchedulerReport> {
+        let due_missions = self
+            .mission_scheduler_repository
+            .get_due_missions(now)
+            .await?;
+
+        let mut report = SchedulerReport::default();
+        for mission in due_missions {
+            let Some((to, reason)) = mission.next_status(now) else {
+                continue;
+            };
+
+            let transition = MissionStateMachine::new(mission.status, mission.chief_id)
+                .transition(mission.chief_id, to)?
+                .with_reason(Some(reason.to_string()));
+
+            match self
+                .mission_operation_repository
+                .transition(mission.id, mission.chief_id, transition)
+                .await
+            {
+                Ok(_) => report.record(mission.id, to),
+                // The chief changed or removed the mission since it was loaded
+                Err(AppError::InvalidStateTransition(_)) | Err(AppError::NotFound(_)) => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(report)
+    }
+}
@@ -0,0 +1,213 @@
+use std::sync::Arc;
+
+use chrono::{NaiveDateTime, Utc};
+
+use crate::domain::{
+    errors::{AppError, AppResult},
+    repositories::{
+        mission_management::MissionManagementRepository, mission_series::MissionSeriesRepository,
+    },
+    value_objects::{
+        crew_requirement::{CrewLimits, CrewRequirement},
+        mission_difficulty::MissionDifficulty,
+        mission_model::validate_mission_name,
+        mission_series::{AddMissionSeriesModel, EditMissionSeriesModel, MissionSeriesModel},
+        mission_template::validate_duration,
+    },
+};
+
+pub struct MissionSeriesUseCase<T1, T2>
+where
+    T1: MissionSeriesRepository + Send + Sync,
+    T2: MissionManagementRepository + Send + Sync,
+{
+    mission_series_repository: Arc<T1>,
+    mission_management_repository: Arc<T2>,
+    crew_limits: CrewLimits,
+}
+
+impl<T1, T2> MissionSeriesUseCase<T1, T2>
+where
+    T1: MissionSeriesRepository + Send + Sync,
+    T2: MissionManagementRepository + Send + Sync,
+{
+    pub fn new(
+        mission_series_repository: Arc<T1>,
+        mission_management_repository: Arc<T2>,
+        crew_limits: CrewLimits,
+    ) -> Self {
+        Self {
+            mission_series_repository,
+            mission_management_repository,
+            crew_limits,
+        }
+    }
+
+    pub async fn add(
+        &self,
+        chief_id: i32,
+        add_mission_series_model: AddMissionSeriesModel,
+    ) -> AppResult<i32> {
+        validate_mission_name(&add_mission_series_model.name)?;
+
+        let difficulty = add_mission_series_model
+            .difficulty
+            .parse::<MissionDifficulty>()?;
+
+        let defaults = self.crew_limits.default_requirement();
+        let crew = CrewRequirement::new(
+            add_mission_series_model
+                .min_crew
+                .unwrap_or(defaults.min_crew),
+            add_mission_series_model
+                .max_crew
+                .unwrap_or(defaults.max_crew),
+        );
+        self.crew_limits.validate(&crew)?;
+        validate_duration(add_mission_series_model.duration_minutes)?;
+        add_mission_series_model
+            .rule()
+            .validate(Utc::now().naive_utc())?;
+
+        // The first occurrences are created by the scheduler on its next run
+        let result = self
+            .mission_series_repository
+            .add(add_mission_series_model.to_entity(chief_id, difficulty, crew))
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn edit(
+        &self,
+        series_id: i32,
+        chief_id: i32,
+        mut edit_mission_series_model: EditMissionSeriesModel,
+    ) -> AppResult<i32> {
+        if let Some(name) = edit_mission_series_model.name {
+            if name.trim().is_empty() {
+                edit_mission_series_model.name = None;
+            } else {
+                validate_mission_name(&name)?;
+                edit_mission_series_model.name = Some(name.trim().to_string())
+            }
+        }
+
+        let current = self
+            .mission_series_repository
+            .get_one(series_id, chief_id)
+            .await?;
+
+        if edit_mission_series_model.min_crew.is_some()
+            || edit_mission_series_model.max_crew.is_some()
+        {
+            let crew = CrewRequirement::new(
+                edit_mission_series_model
+                    .min_crew
+                    .unwrap_or(current.min_crew),
+                edit_mission_series_model
+                    .max_crew
+                    .unwrap_or(current.max_crew),
+            );
+            self.crew_limits.validate(&crew)?;
+        }
+        validate_duration(edit_mission_series_model.duration_minutes)?;
+
+        // A new end date can bring back occurrences the old one cut off, or end the series
+        let next_occurrence = match edit_mission_series_model.ends_on {
+            Some(ends_on) if ends_on < current.starts_at.date() => {
+                return Err(AppError::Validation(
+                    "ends_on must not be earlier than starts_at".to_string(),
+                ));
+            }
+            Some(ends_on) => {
+                let mut rule = current.rule();
+                rule.ends_on = Some(ends_on);
+                Some(rule.nth_occurrence(current.generated_count))
+            }
+            None => None,
+        };
+
+        let difficulty = edit_mission_series_model
+            .difficulty
+            .as_deref()
+            .map(str::parse::<MissionDifficulty>)
+            .transpose()?;
+
+        let edit_mission_series_entity =
+            edit_mission_series_model.to_entity(chief_id, difficulty, next_occurrence);
+
+        let result = self
+            .mission_series_repository
+            .edit(
+                series_id,
+                edit_mission_series_entity,
+                Utc::now().naive_utc(),
+            )
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn remove(&self, series_id: i32, chief_id: i32) -> AppResult<()> {
+        self.mission_series_repository
+            .remove(series_id, chief_id, Utc::now().naive_utc())
+            .await?;
+        Ok(())
+    }
+
+    pub async fn get_all(&self, chief_id: i32) -> AppResult<Vec<MissionSeriesModel>> {
+        let series = self.mission_series_repository.get_all(chief_id).await?;
+
+        Ok(series.iter().map(|s| s.to_model()).collect())
+    }
+
+    // Creates the missions of every occurrence that starts before `horizon`. Occurrences
+    // that already started while the job was not running are skipped, not created late.
+    pub async fn materialize(
+        &self,
+        now: NaiveDateTime,
+        horizon: NaiveDateTime,
+    ) -> AppResult<Vec<i32>> {
+        let due_series = self
+            .mission_series_repository
+            .get_due_series(horizon)
+            .await?;
+
+        let mut created = Vec::new();
+        for series in due_series {
+            let rule = series.rule();
+            let mut generated = series.generated_count;
+            let mut next_occurrence = series.next_occurrence;
+
+            while let Some(start) = next_occurrence.filter(|start| *start <= horizon) {
+                if start > now {
+                    match self
+                        .mission_management_repository
+                        .add(series.occurrence(start))
+                        .await
+                    {
+                        Ok(mission_id) => created.push(mission_id),
+                        // Created by an earlier run that stopped before advancing the series
+                        Err(AppError::Conflict(_)) => {}
+                        Err(e) => return Err(e),
+                    }
+                }
+
+                let next = rule.nth_occurrence(generated + 1);
+                if !self
+                    .mission_series_repository
+                    .advance(series.id, start, next)
+                    .await?
+                {
+                    // Another run or an edit moved the series on in the meantime
+                    break;
+                }
+                generated += 1;
+                next_occurrence = next;
+            }
+        }
+
+        Ok(created)
+    }
+}
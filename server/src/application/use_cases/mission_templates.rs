@@ -0,0 +1,178 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+
+use crate::domain::{
+    errors::AppResult,
+    repositories::{
+        mission_management::MissionManagementRepository,
+        mission_templates::MissionTemplateRepository,
+    },
+    value_objects::{
+        crew_requirement::{CrewLimits, CrewRequirement},
+        mission_difficulty::MissionDifficulty,
+        mission_model::validate_mission_name,
+        mission_schedule::MissionSchedule,
+        mission_template::{
+            AddMissionTemplateModel, EditMissionTemplateModel, MissionFromTemplateModel,
+            MissionTemplateModel, validate_duration,
+        },
+    },
+};
+
+pub struct MissionTemplateUseCase<T1, T2>
+where
+    T1: MissionTemplateRepository + Send + Sync,
+    T2: MissionManagementRepository + Send + Sync,
+{
+    mission_template_repository: Arc<T1>,
+    mission_management_repository: Arc<T2>,
+    crew_limits: CrewLimits,
+}
+
+impl<T1, T2> MissionTemplateUseCase<T1, T2>
+where
+    T1: MissionTemplateRepository + Send + Sync,
+    T2: MissionManagementRepository + Send + Sync,
+{
+    pub fn new(
+        mission_template_repository: Arc<T1>,
+        mission_management_repository: Arc<T2>,
+        crew_limits: CrewLimits,
+    ) -> Self {
+        Self {
+            mission_template_repository,
+            mission_management_repository,
+            crew_limits,
+        }
+    }
+
+    pub async fn add(
+        &self,
+        owner_id: i32,
+        add_mission_template_model: AddMissionTemplateModel,
+    ) -> AppResult<i32> {
+        validate_mission_name(&add_mission_template_model.name)?;
+
+        let difficulty = add_mission_template_model
+            .difficulty
+            .parse::<MissionDifficulty>()?;
+
+        let defaults = self.crew_limits.default_requirement();
+        let crew = CrewRequirement::new(
+            add_mission_template_model
+                .min_crew
+                .unwrap_or(defaults.min_crew),
+            add_mission_template_model
+                .max_crew
+                .unwrap_or(defaults.max_crew),
+        );
+        self.crew_limits.validate(&crew)?;
+        validate_duration(add_mission_template_model.duration_minutes)?;
+
+        let result = self
+            .mission_template_repository
+            .add(add_mission_template_model.to_entity(owner_id, difficulty, crew))
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn edit(
+        &self,
+        template_id: i32,
+        owner_id: i32,
+        mut edit_mission_template_model: EditMissionTemplateModel,
+    ) -> AppResult<i32> {
+        if let Some(name) = edit_mission_template_model.name {
+            if name.trim().is_empty() {
+                edit_mission_template_model.name = None;
+            } else {
+                validate_mission_name(&name)?;
+                edit_mission_template_model.name = Some(name.trim().to_string())
+            }
+        }
+
+        if edit_mission_template_model.min_crew.is_some()
+            || edit_mission_template_model.max_crew.is_some()
+        {
+            let current = self
+                .mission_template_repository
+                .get_one(template_id, owner_id)
+                .await?;
+            let crew = CrewRequirement::new(
+                edit_mission_template_model
+                    .min_crew
+                    .unwrap_or(current.min_crew),
+                edit_mission_template_model
+                    .max_crew
+                    .unwrap_or(current.max_crew),
+            );
+            self.crew_limits.validate(&crew)?;
+        }
+        validate_duration(edit_mission_template_model.duration_minutes)?;
+
+        let difficulty = edit_mission_template_model
+            .difficulty
+            .as_deref()
+            .map(str::parse::<MissionDifficulty>)
+            .transpose()?;
+
+        let result = self
+            .mission_template_repository
+            .edit(
+                template_id,
+                edit_mission_template_model.to_entity(owner_id, difficulty),
+            )
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn remove(&self, template_id: i32, owner_id: i32) -> AppResult<()> {
+        self.mission_template_repository
+            .remove(template_id, owner_id)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn get_all(&self, owner_id: i32) -> AppResult<Vec<MissionTemplateModel>> {
+        let templates = self.mission_template_repository.get_all(owner_id).await?;
+
+        Ok(templates.iter().map(|t| t.to_model()).collect())
+    }
+
+    // The owner becomes the chief of the new mission
+    pub async fn add_mission(
+        &self,
+        template_id: i32,
+        owner_id: i32,
+        mission_from_template_model: MissionFromTemplateModel,
+    ) -> AppResult<i32> {
+        let template = self
+            .mission_template_repository
+            .get_one(template_id, owner_id)
+            .await?;
+
+        let add_mission_entity = mission_from_template_model.to_entity(&template, owner_id);
+
+        // The bounds may have been tightened since the template was saved
+        self.crew_limits.validate(&CrewRequirement::new(
+            add_mission_entity.min_crew,
+            add_mission_entity.max_crew,
+        ))?;
+
+        let schedule = MissionSchedule::new(
+            add_mission_entity.scheduled_start,
+            add_mission_entity.deadline,
+        );
+        schedule.validate(&schedule, Utc::now().naive_utc())?;
+
+        let result = self
+            .mission_management_repository
+            .add(add_mission_entity)
+            .await?;
+
+        Ok(result)
+    }
+}
@@ -1,31 +1,48 @@
 use std::sync::Arc;
 
-use anyhow::{Ok, Result};
-
 use crate::domain::{
-    repositories::mission_viewing::MissionViewingRepository,
+    errors::AppResult,
+    repositories::{
+        mission_status_history::MissionStatusHistoryRepository,
+        mission_viewing::MissionViewingRepository,
+    },
     value_objects::{
-        brawler_model::BrawlerModel, mission_filter::MissionFilter, mission_model::MissionModel,
+        brawler_model::BrawlerModel,
+        brawler_progression::XpRules,
+        mission_filter::MissionFilter,
+        mission_model::{MissionListItem, MissionModel},
+        mission_status_history::MissionStatusHistoryModel,
+        pagination::{MissionPageQuery, Page},
     },
 };
-pub struct MissionViewingUseCase<T>
+pub struct MissionViewingUseCase<T1, T2>
 where
-    T: MissionViewingRepository + Send + Sync,
+    T1: MissionViewingRepository + Send + Sync,
+    T2: MissionStatusHistoryRepository + Send + Sync,
 {
-    mission_viewing_repository: Arc<T>,
+    mission_viewing_repository: Arc<T1>,
+    mission_status_history_repository: Arc<T2>,
+    xp_rules: XpRules,
 }
 
-impl<T> MissionViewingUseCase<T>
+impl<T1, T2> MissionViewingUseCase<T1, T2>
 where
-    T: MissionViewingRepository + Send + Sync,
+    T1: MissionViewingRepository + Send + Sync,
+    T2: MissionStatusHistoryRepository + Send + Sync,
 {
-    pub fn new(mission_viewing_repository: Arc<T>) -> Self {
+    pub fn new(
+        mission_viewing_repository: Arc<T1>,
+        mission_status_history_repository: Arc<T2>,
+        xp_rules: XpRules,
+    ) -> Self {
         Self {
             mission_viewing_repository,
+            mission_status_history_repository,
+            xp_rules,
         }
     }
 
-    pub async fn get_one(&self, mission_id: i32) -> Result<MissionModel> {
+    pub async fn get_one(&self, mission_id: i32) -> AppResult<MissionModel> {
         // let crew_count = self
         //     .mission_viewing_repository
         //     .crew_counting(mission_id)
@@ -38,31 +55,48 @@ where
         Ok(model)
     }
 
-    pub async fn get_crew(&self, mission_id: i32) -> Result<Vec<BrawlerModel>> {
-        let result = self.mission_viewing_repository.get_crew(mission_id).await?;
-        Ok(result)
+    pub async fn get_crew(&self, mission_id: i32) -> AppResult<Vec<BrawlerModel>> {
+        let rows = self.mission_viewing_repository.get_crew(mission_id).await?;
+        Ok(rows
+            .iter()
+            .map(|row| row.to_model(&self.xp_rules))
+            .collect())
     }
 
-    pub async fn get_all(&self, filter: &MissionFilter) -> Result<Vec<MissionModel>> {
-        let models = self.mission_viewing_repository.get_all(filter).await?;
-
-        // let mut result = Vec::new();
-
-        // for model in models.into_iter() {
-        //     let crew_count = self
-        //         .mission_viewing_repository
-        //         .crew_counting(model.id)
-        //         .await
-        //         .unwrap_or(0);
+    pub async fn get_all(
+        &self,
+        filter: &MissionFilter,
+        viewer_id: Option<i32>,
+        page: &MissionPageQuery,
+    ) -> AppResult<Page<MissionListItem>> {
+        filter.validate(viewer_id)?;
+        let page = page.resolve_for(filter)?;
+        page.validate()?;
 
-        //     result.push(model.to_model(crew_count));
-        // }
+        let models = self
+            .mission_viewing_repository
+            .get_all(filter, viewer_id, &page)
+            .await?;
 
         Ok(models)
     }
 
-    pub async fn get_my_memberships(&self, brawler_id: i32) -> Result<Vec<i32>> {
-        let result = self.mission_viewing_repository.get_my_memberships(brawler_id).await?;
+    pub async fn get_my_memberships(&self, brawler_id: i32) -> AppResult<Vec<i32>> {
+        let result = self
+            .mission_viewing_repository
+            .get_my_memberships(brawler_id)
+            .await?;
+        Ok(result)
+    }
+
+    pub async fn get_history(&self, mission_id: i32) -> AppResult<Vec<MissionStatusHistoryModel>> {
+        // Soft-deleted or unknown missions should 404 instead of returning an empty trail
+        self.mission_viewing_repository.get_one(mission_id).await?;
+
+        let result = self
+            .mission_status_history_repository
+            .get_history(mission_id)
+            .await?;
         Ok(result)
     }
 }
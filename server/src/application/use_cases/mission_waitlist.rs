@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use crate::domain::{
+    errors::AppResult,
+    repositories::mission_waitlist::MissionWaitlistRepository,
+    value_objects::mission_waitlist::{WaitlistPositionModel, WaitlistPromotionModel},
+};
+
+pub struct MissionWaitlistUseCase<T>
+where
+    T: MissionWaitlistRepository + Send + Sync,
+{
+    mission_waitlist_repository: Arc<T>,
+}
+
+impl<T> MissionWaitlistUseCase<T>
+where
+    T: MissionWaitlistRepository + Send + Sync,
+{
+    pub fn new(mission_waitlist_repository: Arc<T>) -> Self {
+        Self {
+            mission_waitlist_repository,
+        }
+    }
+
+    pub async fn enqueue(
+        &self,
+        mission_id: i32,
+        brawler_id: i32,
+    ) -> AppResult<WaitlistPositionModel> {
+        let result = self
+            .mission_waitlist_repository
+            .enqueue(mission_id, brawler_id)
+            .await?;
+        Ok(result)
+    }
+
+    pub async fn withdraw(&self, mission_id: i32, brawler_id: i32) -> AppResult<()> {
+        self.mission_waitlist_repository
+            .withdraw(mission_id, brawler_id)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn position(
+        &self,
+        mission_id: i32,
+        brawler_id: i32,
+    ) -> AppResult<WaitlistPositionModel> {
+        let result = self
+            .mission_waitlist_repository
+            .position(mission_id, brawler_id)
+            .await?;
+        Ok(result)
+    }
+
+    pub async fn get_promotions(&self, brawler_id: i32) -> AppResult<Vec<WaitlistPromotionModel>> {
+        let result = self
+            .mission_waitlist_repository
+            .get_promotions(brawler_id)
+            .await?;
+        Ok(result)
+    }
+
+    pub async fn mark_promotion_seen(&self, promotion_id: i32, brawler_id: i32) -> AppResult<()> {
+        self.mission_waitlist_repository
+            .mark_promotion_seen(promotion_id, brawler_id)
+            .await?;
+        Ok(())
+    }
+}
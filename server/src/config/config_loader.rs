@@ -1,9 +1,12 @@
 use std::env;
 
-use anyhow::Result;
+use anyhow::{Result, bail};
 
 use crate::config::{
-    config_model::{CloudinaryEnv, Database, DotEnvyConfig, JwtEnv, Server},
+    config_model::{
+        CloudinaryEnv, Consensus, Crew, Database, DotEnvyConfig, JwtEnv, Retention, Scheduler,
+        Server, Xp,
+    },
     stage::Stage,
 };
 
@@ -28,6 +31,134 @@ pub fn load() -> Result<DotEnvyConfig> {
             .parse()?,
     };
 
+    let scheduler = Scheduler {
+        interval: std::env::var("SCHEDULER_INTERVAL")
+            .unwrap_or_else(|_| "60".to_string())
+            .parse()?,
+        series_lead: std::env::var("SCHEDULER_SERIES_LEAD")
+            .unwrap_or_else(|_| "48".to_string())
+            .parse()?,
+        leaderboard_refresh: std::env::var("SCHEDULER_LEADERBOARD_REFRESH")
+            .unwrap_or_else(|_| "15".to_string())
+            .parse()?,
+    };
+    // tokio::time::interval panics on a zero period, which would take the scheduler down
+    if scheduler.interval == 0 {
+        bail!("SCHEDULER_INTERVAL must be positive, got 0");
+    }
+    // A year ahead is already more than any series needs, and keeps TimeDelta::hours in range
+    if !(1..=24 * 365).contains(&scheduler.series_lead) {
+        bail!(
+            "SCHEDULER_SERIES_LEAD must be between 1 and {} hours, got {}",
+            24 * 365,
+            scheduler.series_lead
+        );
+    }
+    if scheduler.leaderboard_refresh < 0 {
+        bail!(
+            "SCHEDULER_LEADERBOARD_REFRESH must not be negative, got {}",
+            scheduler.leaderboard_refresh
+        );
+    }
+
+    let crew = Crew {
+        min_crew: std::env::var("MIN_CREW_PER_MISSION")
+            .unwrap_or_else(|_| "1".to_string())
+            .parse()?,
+        max_crew: std::env::var("MAX_CREW_PER_MISSION")
+            .unwrap_or_else(|_| "5".to_string())
+            .parse()?,
+    };
+    if crew.min_crew < 0 || crew.min_crew > crew.max_crew {
+        bail!(
+            "MIN_CREW_PER_MISSION must be between 0 and MAX_CREW_PER_MISSION, got {} and {}",
+            crew.min_crew,
+            crew.max_crew
+        );
+    }
+
+    let retention = Retention {
+        deleted_missions: std::env::var("DELETED_MISSION_RETENTION")
+            .unwrap_or_else(|_| "30".to_string())
+            .parse()?,
+    };
+    if retention.deleted_missions < 0 {
+        bail!(
+            "DELETED_MISSION_RETENTION must not be negative, got {}",
+            retention.deleted_missions
+        );
+    }
+
+    let consensus = Consensus {
+        quorum: std::env::var("CONSENSUS_QUORUM")
+            .unwrap_or_else(|_| "60".to_string())
+            .parse()?,
+        voting_window: std::env::var("CONSENSUS_VOTING_WINDOW")
+            .unwrap_or_else(|_| "24".to_string())
+            .parse()?,
+    };
+    if !(1..=100).contains(&consensus.quorum) {
+        bail!(
+            "CONSENSUS_QUORUM must be between 1 and 100, got {}",
+            consensus.quorum
+        );
+    }
+    if consensus.voting_window <= 0 {
+        bail!(
+            "CONSENSUS_VOTING_WINDOW must be positive, got {}",
+            consensus.voting_window
+        );
+    }
+
+    let var = |key: &str, default: &str| env::var(key).unwrap_or_else(|_| default.to_string());
+
+    let rank_levels = var("XP_RANK_LEVELS", "5,10,20,35")
+        .split(',')
+        .map(|level| level.trim().parse::<i32>())
+        .collect::<Result<Vec<i32>, _>>()?;
+    let Ok(rank_levels) = <[i32; 4]>::try_from(rank_levels) else {
+        bail!("XP_RANK_LEVELS must list the first level of Silver, Gold, Platinum and Diamond");
+    };
+    if rank_levels[0] <= 1 || rank_levels.windows(2).any(|pair| pair[0] >= pair[1]) {
+        bail!(
+            "XP_RANK_LEVELS must be increasing and above 1, got {:?}",
+            rank_levels
+        );
+    }
+
+    let xp = Xp {
+        base: var("XP_BASE", "100").parse()?,
+        per_crew_member: var("XP_PER_CREW_MEMBER", "10").parse()?,
+        easy_weight: var("XP_EASY_WEIGHT", "100").parse()?,
+        medium_weight: var("XP_MEDIUM_WEIGHT", "150").parse()?,
+        hard_weight: var("XP_HARD_WEIGHT", "200").parse()?,
+        chief_bonus: var("XP_CHIEF_BONUS", "25").parse()?,
+        level_step: var("XP_LEVEL_STEP", "100").parse()?,
+        rank_levels,
+    };
+    if [
+        xp.base,
+        xp.per_crew_member,
+        xp.easy_weight,
+        xp.medium_weight,
+        xp.hard_weight,
+        xp.chief_bonus,
+    ]
+    .iter()
+    .any(|value| *value < 0)
+    {
+        bail!(
+            "XP_BASE, XP_PER_CREW_MEMBER, the XP weights and XP_CHIEF_BONUS must not be negative"
+        );
+    }
+    if xp.level_step <= 0 {
+        bail!("XP_LEVEL_STEP must be positive, got {}", xp.level_step);
+    }
+
+    // Only read when a token is issued or checked, checked here so a missing or renamed
+    // variable stops the server at boot
+    get_jwt_env()?;
+
     let secret = std::env::var("JWT_USER_SECRET")
         .expect("SECRET is valid")
         .parse()?;
@@ -35,6 +166,11 @@ pub fn load() -> Result<DotEnvyConfig> {
     let config = DotEnvyConfig {
         server,
         database,
+        scheduler,
+        crew,
+        retention,
+        consensus,
+        xp,
         secret,
     };
 
@@ -50,10 +186,27 @@ pub fn get_stage() -> Stage {
 
 pub fn get_jwt_env() -> Result<JwtEnv> {
     dotenvy::dotenv().ok();
-    Ok(JwtEnv {
+
+    // JWT_TTL used to be days, reading it as minutes would quietly hand out tokens that
+    // expire a thousand times sooner
+    if env::var("JWT_TTL").is_ok() {
+        bail!("JWT_TTL is no longer read, set JWT_ACCESS_TTL_MINUTES instead");
+    }
+
+    let jwt_env = JwtEnv {
         secret: env::var("JWT_USER_SECRET")?,
-        ttl: env::var("JWT_TTL")?.parse::<i64>()?,
-    })
+        refresh_secret: env::var("JWT_USER_REFRESH_SECRET")?,
+        access_ttl: env::var("JWT_ACCESS_TTL_MINUTES")?.parse::<i64>()?,
+        refresh_ttl: env::var("JWT_REFRESH_TTL")?.parse::<i64>()?,
+    };
+    if jwt_env.access_ttl <= 0 {
+        bail!(
+            "JWT_ACCESS_TTL_MINUTES must be positive, got {}",
+            jwt_env.access_ttl
+        );
+    }
+
+    Ok(jwt_env)
 }
 
 pub fn get_cloudinary_env() -> Result<CloudinaryEnv> {
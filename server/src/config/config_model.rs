@@ -10,10 +10,62 @@ pub struct Database {
     pub url: String,
 }
 
+#[derive(Debug, Clone)]
+pub struct Scheduler {
+    // seconds between two runs of the background jobs
+    pub interval: u64,
+    // hours ahead of its start an occurrence of a recurring series is created
+    pub series_lead: i64,
+    // minutes the leaderboards are served before they are rebuilt
+    pub leaderboard_refresh: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct Crew {
+    // bounds a chief can pick min_crew and max_crew from
+    pub min_crew: i32,
+    pub max_crew: i32,
+}
+
+#[derive(Debug, Clone)]
+pub struct Retention {
+    // days a removed mission can be restored before the scheduler deletes it for good
+    pub deleted_missions: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct Consensus {
+    // percent of the crew that has to confirm the outcome a chief proposed
+    pub quorum: i64,
+    // hours the crew has to vote before the mission goes to Disputed
+    pub voting_window: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct Xp {
+    // XP a completed mission is worth before it is weighted
+    pub base: i32,
+    pub per_crew_member: i32,
+    // percent of the base for an Easy, Medium and Hard mission
+    pub easy_weight: i32,
+    pub medium_weight: i32,
+    pub hard_weight: i32,
+    // extra percent for the chief
+    pub chief_bonus: i32,
+    // XP from level 1 to 2, each next level costs this much more
+    pub level_step: i32,
+    // first level of Silver, Gold, Platinum and Diamond
+    pub rank_levels: [i32; 4],
+}
+
 #[derive(Debug, Clone)]
 pub struct JwtEnv {
     pub secret: String,
-    pub ttl: i64,
+    pub refresh_secret: String,
+    // access token lifetime in minutes
+    pub access_ttl: i64,
+    // refresh token lifetime in days
+    pub refresh_ttl: i64,
 }
 
 #[derive(Debug, Clone)]
@@ -27,6 +79,10 @@ pub struct CloudinaryEnv {
 pub struct DotEnvyConfig {
     pub server: Server,
     pub database: Database,
+    pub scheduler: Scheduler,
+    pub crew: Crew,
+    pub retention: Retention,
+    pub consensus: Consensus,
+    pub xp: Xp,
     pub secret: String,
-    // pub max_crew_per_mission: u32,
 }
@@ -13,6 +13,9 @@ pub struct BrawlerEntity {
     pub display_name: String,
     pub avatar_url: Option<String>,
     pub avatar_public_id: Option<String>,
+    pub token_generation: i32,
+    pub is_admin: bool,
+    pub xp: i32,
 }
 
 #[derive(Debug, Clone, Insertable)]
@@ -0,0 +1,13 @@
+use diesel::prelude::*;
+
+use crate::infrastructure::database::schema::crew_removals;
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = crew_removals)]
+pub struct AddCrewRemovalEntity {
+    pub mission_id: i32,
+    pub brawler_id: i32,
+    pub removed_by: i32,
+    pub reason: Option<String>,
+    pub banned: bool,
+}
@@ -0,0 +1,18 @@
+use diesel::prelude::*;
+
+use crate::infrastructure::database::schema::{join_requests, mission_invitations};
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = join_requests)]
+pub struct AddJoinRequestEntity {
+    pub mission_id: i32,
+    pub brawler_id: i32,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = mission_invitations)]
+pub struct AddMissionInvitationEntity {
+    pub mission_id: i32,
+    pub brawler_id: i32,
+    pub invited_by: i32,
+}
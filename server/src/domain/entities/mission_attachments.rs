@@ -0,0 +1,52 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::value_objects::mission_attachment::{MissionAttachmentKind, MissionAttachmentModel},
+    infrastructure::database::schema::mission_attachments,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(table_name = mission_attachments)]
+pub struct MissionAttachmentEntity {
+    pub id: i32,
+    pub mission_id: i32,
+    pub uploaded_by: i32,
+    pub kind: MissionAttachmentKind,
+    pub file_name: String,
+    pub content_type: String,
+    pub size_bytes: i32,
+    pub url: String,
+    pub public_id: String,
+    pub created_at: NaiveDateTime,
+}
+
+impl MissionAttachmentEntity {
+    pub fn to_model(&self) -> MissionAttachmentModel {
+        MissionAttachmentModel {
+            id: self.id,
+            mission_id: self.mission_id,
+            uploaded_by: self.uploaded_by,
+            kind: self.kind,
+            file_name: self.file_name.clone(),
+            content_type: self.content_type.clone(),
+            size_bytes: self.size_bytes,
+            url: self.url.clone(),
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = mission_attachments)]
+pub struct AddMissionAttachmentEntity {
+    pub mission_id: i32,
+    pub uploaded_by: i32,
+    pub kind: MissionAttachmentKind,
+    pub file_name: String,
+    pub content_type: String,
+    pub size_bytes: i32,
+    pub url: String,
+    pub public_id: String,
+}
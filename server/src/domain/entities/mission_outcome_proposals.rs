@@ -0,0 +1,27 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::value_objects::mission_statuses::MissionStatuses,
+    infrastructure::database::schema::{mission_outcome_proposals, mission_outcome_votes},
+};
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = mission_outcome_proposals)]
+pub struct AddOutcomeProposalEntity {
+    pub mission_id: i32,
+    pub proposed_by: i32,
+    pub outcome: MissionStatuses,
+    pub reason: Option<String>,
+    pub eligible_voters: i32,
+    pub required_votes: i32,
+    pub closes_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = mission_outcome_votes)]
+pub struct AddOutcomeVoteEntity {
+    pub proposal_id: i32,
+    pub brawler_id: i32,
+    pub agree: bool,
+}
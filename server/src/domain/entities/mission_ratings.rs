@@ -0,0 +1,29 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::infrastructure::database::schema::mission_ratings;
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(table_name = mission_ratings)]
+pub struct MissionRatingEntity {
+    pub id: i32,
+    pub mission_id: i32,
+    pub rater_id: i32,
+    pub ratee_id: i32,
+    pub score: i32,
+    pub mission_score: Option<i32>,
+    pub comment: Option<String>,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = mission_ratings)]
+pub struct AddMissionRatingEntity {
+    pub mission_id: i32,
+    pub rater_id: i32,
+    pub ratee_id: i32,
+    pub score: i32,
+    pub mission_score: Option<i32>,
+    pub comment: Option<String>,
+}
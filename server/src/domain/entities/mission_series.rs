@@ -0,0 +1,162 @@
+use chrono::{Duration, NaiveDate, NaiveDateTime};
+use diesel::prelude::*;
+
+use crate::{
+    domain::{
+        entities::missions::{AddMissionEntity, EditMissionEntity},
+        value_objects::{
+            mission_completion_mode::MissionCompletionMode,
+            mission_difficulty::MissionDifficulty,
+            mission_join_policy::MissionJoinPolicy,
+            mission_recurrence::{RecurrenceFrequency, RecurrenceRule, RecurrenceWeekdays},
+            mission_series::MissionSeriesModel,
+            mission_statuses::MissionStatuses,
+        },
+    },
+    infrastructure::database::schema::mission_series,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(table_name = mission_series)]
+pub struct MissionSeriesEntity {
+    pub id: i32,
+    pub chief_id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub difficulty: MissionDifficulty,
+    pub min_crew: i32,
+    pub max_crew: i32,
+    pub join_policy: MissionJoinPolicy,
+    pub duration_minutes: Option<i32>,
+    pub frequency: RecurrenceFrequency,
+    pub weekdays: RecurrenceWeekdays,
+    pub starts_at: NaiveDateTime,
+    pub ends_on: Option<NaiveDate>,
+    pub occurrence_count: Option<i32>,
+    pub generated_count: i32,
+    pub next_occurrence: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl MissionSeriesEntity {
+    pub fn rule(&self) -> RecurrenceRule {
+        RecurrenceRule {
+            frequency: self.frequency,
+            weekdays: self.weekdays.clone(),
+            starts_at: self.starts_at,
+            ends_on: self.ends_on,
+            occurrence_count: self.occurrence_count,
+        }
+    }
+
+    // The Open mission for the occurrence starting at `scheduled_start`
+    pub fn occurrence(&self, scheduled_start: NaiveDateTime) -> AddMissionEntity {
+        AddMissionEntity {
+            chief_id: self.chief_id,
+            name: self.name.clone(),
+            status: MissionStatuses::Open,
+            description: self.description.clone(),
+            difficulty: self.difficulty,
+            min_crew: self.min_crew,
+            max_crew: self.max_crew,
+            join_policy: self.join_policy,
+            completion_mode: MissionCompletionMode::default(),
+            scheduled_start: Some(scheduled_start),
+            deadline: self
+                .duration_minutes
+                .map(|minutes| scheduled_start + Duration::minutes(minutes.into())),
+            series_id: Some(self.id),
+        }
+    }
+
+    pub fn to_model(&self) -> MissionSeriesModel {
+        MissionSeriesModel {
+            id: self.id,
+            chief_id: self.chief_id,
+            name: self.name.clone(),
+            description: self.description.clone(),
+            difficulty: self.difficulty,
+            min_crew: self.min_crew,
+            max_crew: self.max_crew,
+            join_policy: self.join_policy,
+            duration_minutes: self.duration_minutes,
+            frequency: self.frequency,
+            weekdays: self.weekdays.clone(),
+            starts_at: self.starts_at,
+            ends_on: self.ends_on,
+            occurrence_count: self.occurrence_count,
+            generated_count: self.generated_count,
+            next_occurrence: self.next_occurrence,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = mission_series)]
+pub struct AddMissionSeriesEntity {
+    pub chief_id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub difficulty: MissionDifficulty,
+    pub min_crew: i32,
+    pub max_crew: i32,
+    pub join_policy: MissionJoinPolicy,
+    pub duration_minutes: Option<i32>,
+    pub frequency: RecurrenceFrequency,
+    pub weekdays: RecurrenceWeekdays,
+    pub starts_at: NaiveDateTime,
+    pub ends_on: Option<NaiveDate>,
+    pub occurrence_count: Option<i32>,
+    pub next_occurrence: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, AsChangeset)]
+#[diesel(table_name = mission_series)]
+pub struct EditMissionSeriesEntity {
+    // Only used to scope the update to the series' chief, never written
+    #[diesel(skip_update)]
+    pub chief_id: i32,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub difficulty: Option<MissionDifficulty>,
+    pub min_crew: Option<i32>,
+    pub max_crew: Option<i32>,
+    pub join_policy: Option<MissionJoinPolicy>,
+    pub duration_minutes: Option<i32>,
+    pub ends_on: Option<NaiveDate>,
+    // Some(None) when a new end date leaves no occurrence to create
+    pub next_occurrence: Option<Option<NaiveDateTime>>,
+}
+
+impl EditMissionSeriesEntity {
+    // The same changes for the occurrences that have not started yet, None when there are
+    // none. Their deadline follows a new duration separately since it depends on each start.
+    pub fn occurrence_changes(&self) -> Option<EditMissionEntity> {
+        let changed = self.name.is_some()
+            || self.description.is_some()
+            || self.difficulty.is_some()
+            || self.min_crew.is_some()
+            || self.max_crew.is_some()
+            || self.join_policy.is_some();
+        if !changed {
+            return None;
+        }
+
+        Some(EditMissionEntity {
+            chief_id: self.chief_id,
+            name: self.name.clone(),
+            description: self.description.clone(),
+            min_crew: self.min_crew,
+            max_crew: self.max_crew,
+            join_policy: self.join_policy,
+            completion_mode: None,
+            difficulty: self.difficulty,
+            scheduled_start: None,
+            deadline: None,
+        })
+    }
+}
@@ -0,0 +1,16 @@
+use diesel::prelude::*;
+
+use crate::{
+    domain::value_objects::mission_statuses::MissionStatuses,
+    infrastructure::database::schema::mission_status_history,
+};
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = mission_status_history)]
+pub struct AddMissionStatusHistoryEntity {
+    pub mission_id: i32,
+    pub changed_by: i32,
+    pub from_status: Option<MissionStatuses>,
+    pub to_status: MissionStatuses,
+    pub reason: Option<String>,
+}
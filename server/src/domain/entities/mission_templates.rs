@@ -0,0 +1,73 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::value_objects::{
+        mission_difficulty::MissionDifficulty, mission_join_policy::MissionJoinPolicy,
+        mission_template::MissionTemplateModel,
+    },
+    infrastructure::database::schema::mission_templates,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(table_name = mission_templates)]
+pub struct MissionTemplateEntity {
+    pub id: i32,
+    pub owner_id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub difficulty: MissionDifficulty,
+    pub min_crew: i32,
+    pub max_crew: i32,
+    pub join_policy: MissionJoinPolicy,
+    pub duration_minutes: Option<i32>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl MissionTemplateEntity {
+    pub fn to_model(&self) -> MissionTemplateModel {
+        MissionTemplateModel {
+            id: self.id,
+            owner_id: self.owner_id,
+            name: self.name.clone(),
+            description: self.description.clone(),
+            difficulty: self.difficulty,
+            min_crew: self.min_crew,
+            max_crew: self.max_crew,
+            join_policy: self.join_policy,
+            duration_minutes: self.duration_minutes,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = mission_templates)]
+pub struct AddMissionTemplateEntity {
+    pub owner_id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub difficulty: MissionDifficulty,
+    pub min_crew: i32,
+    pub max_crew: i32,
+    pub join_policy: MissionJoinPolicy,
+    pub duration_minutes: Option<i32>,
+}
+
+#[derive(Debug, Clone, AsChangeset)]
+#[diesel(table_name = mission_templates)]
+pub struct EditMissionTemplateEntity {
+    // Only used to scope the update to the template's owner, never written
+    #[diesel(skip_update)]
+    pub owner_id: i32,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub difficulty: Option<MissionDifficulty>,
+    pub min_crew: Option<i32>,
+    pub max_crew: Option<i32>,
+    pub join_policy: Option<MissionJoinPolicy>,
+    pub duration_minutes: Option<i32>,
+}
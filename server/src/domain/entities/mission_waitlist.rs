@@ -0,0 +1,17 @@
+use diesel::prelude::*;
+
+use crate::infrastructure::database::schema::{mission_waitlist, waitlist_promotions};
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = mission_waitlist)]
+pub struct AddMissionWaitlistEntity {
+    pub mission_id: i32,
+    pub brawler_id: i32,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = waitlist_promotions)]
+pub struct AddWaitlistPromotionEntity {
+    pub mission_id: i32,
+    pub brawler_id: i32,
+}
@@ -2,7 +2,12 @@ use chrono::NaiveDateTime;
 use diesel::prelude::*;
 
 use crate::{
-    domain::value_objects::mission_model::MissionModel, infrastructure::database::schema::missions,
+    domain::value_objects::{
+        mission_completion_mode::MissionCompletionMode, mission_difficulty::MissionDifficulty,
+        mission_join_policy::MissionJoinPolicy, mission_model::MissionModel,
+        mission_statuses::MissionStatuses,
+    },
+    infrastructure::database::schema::missions,
 };
 
 #[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
@@ -12,27 +17,45 @@ pub struct MissionEntity {
     pub id: i32,
     pub chief_id: i32,
     pub name: String,
-    pub status: String,
+    pub status: MissionStatuses,
     pub description: Option<String>,
-    pub difficulty: String,
+    pub difficulty: MissionDifficulty,
     pub max_crew: i32,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
     pub deleted_at: Option<NaiveDateTime>,
+    pub join_policy: MissionJoinPolicy,
+    pub scheduled_start: Option<NaiveDateTime>,
+    pub deadline: Option<NaiveDateTime>,
+    pub min_crew: i32,
+    pub series_id: Option<i32>,
+    pub completion_mode: MissionCompletionMode,
 }
 
 impl MissionEntity {
-    pub fn to_model(&self, crew_count: i64, chief_display_name: String) -> MissionModel {
+    pub fn to_model(
+        &self,
+        crew_count: i64,
+        chief_display_name: String,
+        cover_url: Option<String>,
+    ) -> MissionModel {
         MissionModel {
             id: self.id,
             name: self.name.clone(),
             description: self.description.clone(),
-            status: self.status.clone(),
-            difficulty: self.difficulty.clone(),
+            status: self.status,
+            difficulty: self.difficulty,
             chief_id: self.chief_id,
             chief_display_name,
             crew_count,
             max_crew: self.max_crew,
+            min_crew: self.min_crew,
+            join_policy: self.join_policy,
+            completion_mode: self.completion_mode,
+            scheduled_start: self.scheduled_start,
+            deadline: self.deadline,
+            series_id: self.series_id,
+            cover_url,
             created_at: self.created_at,
             updated_at: self.updated_at,
         }
@@ -44,16 +67,31 @@ impl MissionEntity {
 pub struct AddMissionEntity {
     pub chief_id: i32,
     pub name: String,
-    pub status: String,
+    pub status: MissionStatuses,
     pub description: Option<String>,
-    pub difficulty: String,
+    pub difficulty: MissionDifficulty,
+    pub min_crew: i32,
+    pub max_crew: i32,
+    pub join_policy: MissionJoinPolicy,
+    pub completion_mode: MissionCompletionMode,
+    pub scheduled_start: Option<NaiveDateTime>,
+    pub deadline: Option<NaiveDateTime>,
+    pub series_id: Option<i32>,
 }
 
 #[derive(Debug, Clone, AsChangeset)]
 #[diesel(table_name = missions)]
 pub struct EditMissionEntity {
+    // Only used to scope the update to the mission's chief, never written
+    #[diesel(skip_update)]
     pub chief_id: i32,
     pub name: Option<String>,
     pub description: Option<String>,
+    pub min_crew: Option<i32>,
     pub max_crew: Option<i32>,
+    pub join_policy: Option<MissionJoinPolicy>,
+    pub completion_mode: Option<MissionCompletionMode>,
+    pub difficulty: Option<MissionDifficulty>,
+    pub scheduled_start: Option<NaiveDateTime>,
+    pub deadline: Option<NaiveDateTime>,
 }
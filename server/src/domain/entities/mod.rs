@@ -1,3 +1,15 @@
 pub mod brawlers;
 pub mod crew_memberships;
+pub mod crew_removals;
+pub mod join_requests;
+pub mod mission_attachments;
+pub mod mission_comments;
+pub mod mission_outcome_proposals;
+pub mod mission_ratings;
+pub mod mission_series;
+pub mod mission_status_history;
+pub mod mission_templates;
+pub mod mission_waitlist;
 pub mod missions;
+pub mod refresh_tokens;
+pub mod xp_events;
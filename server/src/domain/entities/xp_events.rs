@@ -0,0 +1,25 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::infrastructure::database::schema::xp_events;
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(table_name = xp_events)]
+pub struct XpEventEntity {
+    pub id: i32,
+    pub brawler_id: i32,
+    pub mission_id: Option<i32>,
+    pub reason: String,
+    pub amount: i32,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = xp_events)]
+pub struct AddXpEventEntity {
+    pub brawler_id: i32,
+    pub mission_id: Option<i32>,
+    pub reason: String,
+    pub amount: i32,
+}
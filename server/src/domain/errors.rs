@@ -0,0 +1,83 @@
+use diesel::result::{DatabaseErrorKind, Error as DieselError};
+use thiserror::Error;
+
+pub type AppResult<T> = std::result::Result<T, AppError>;
+
+#[derive(Debug, Error)]
+pub enum AppError {
+    #[error("{0}")]
+    NotFound(String),
+    #[error("{0}")]
+    Forbidden(String),
+    #[error("{0}")]
+    Conflict(String),
+    #[error("{0}")]
+    Validation(String),
+    #[error("{0}")]
+    InvalidStateTransition(String),
+    #[error("{0}")]
+    MissionFull(String),
+    #[error("{0}")]
+    Unauthorized(String),
+    #[error(transparent)]
+    Internal(#[from] anyhow::Error),
+}
+
+impl AppError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            AppError::NotFound(_) => "NOT_FOUND",
+            AppError::Forbidden(_) => "FORBIDDEN",
+            AppError::Conflict(_) => "CONFLICT",
+            AppError::Validation(_) => "VALIDATION_ERROR",
+            AppError::InvalidStateTransition(_) => "INVALID_STATE_TRANSITION",
+            AppError::MissionFull(_) => "MISSION_FULL",
+            AppError::Unauthorized(_) => "UNAUTHORIZED",
+            AppError::Internal(_) => "INTERNAL_ERROR",
+        }
+    }
+}
+
+impl From<DieselError> for AppError {
+    fn from(error: DieselError) -> Self {
+        match error {
+            DieselError::NotFound => AppError::NotFound("Resource not found".to_string()),
+            DieselError::DatabaseError(DatabaseErrorKind::UniqueViolation, info) => {
+                AppError::Conflict(unique_violation_message(info.constraint_name()).to_string())
+            }
+            e => AppError::Internal(e.into()),
+        }
+    }
+}
+
+// The raw Postgres message names tables and values, so only the constraint is looked at
+fn unique_violation_message(constraint_name: Option<&str>) -> &'static str {
+    match constraint_name {
+        Some("unique_username") => "Username is already taken",
+        Some("crew_memberships_pkey") => "Brawler is already in the crew",
+        Some("mission_waitlist_mission_id_brawler_id_key") => "Brawler is already on the waitlist",
+        Some("idx_join_requests_pending") => "A join request is already pending for this mission",
+        Some("idx_mission_invitations_pending") => {
+            "An invitation is already pending for this brawler"
+        }
+        Some("uq_mission_attachments_cover") => "Mission already has a cover image",
+        Some("uq_missions_series_occurrence") => "This occurrence of the series already exists",
+        Some("uq_rating_once") => "You already rated this brawler for this mission",
+        Some("uq_outcome_proposals_open") => "An outcome vote is already open for this mission",
+        Some("mission_outcome_votes_pkey") => "You already voted on this outcome",
+        Some("uq_xp_event_once") => "XP was already awarded for this",
+        _ => "Resource already exists",
+    }
+}
+
+impl From<diesel::r2d2::PoolError> for AppError {
+    fn from(error: diesel::r2d2::PoolError) -> Self {
+        AppError::Internal(error.into())
+    }
+}
+
+impl From<tokio::task::JoinError> for AppError {
+    fn from(error: tokio::task::JoinError) -> Self {
+        AppError::Internal(error.into())
+    }
+}
@@ -1,33 +1,39 @@
 use crate::{
     domain::{
         entities::brawlers::{BrawlerEntity, RegisterBrawlerEntity},
+        errors::AppResult,
         value_objects::{
             base64_img::Base64Img,
             mission_model::MissionModel,
-            profile_stats::ProfileStats,
+            pagination::{MissionPageQuery, Page},
+            profile_stats::ProfileStatsRow,
             uploaded_img::UploadedImg,
         },
     },
     infrastructure::{cloudinary::UploadImageOptions, jwt::jwt_model::Passport},
 };
-use anyhow::Result;
 use async_trait::async_trait;
 
 #[async_trait]
 pub trait BrawlerRepository {
-    async fn register(&self, register_brawler_entity: RegisterBrawlerEntity) -> Result<Passport>;
-    async fn find_by_username(&self, username: String) -> Result<BrawlerEntity>;
+    async fn register(&self, register_brawler_entity: RegisterBrawlerEntity)
+    -> AppResult<Passport>;
+    async fn find_by_username(&self, username: String) -> AppResult<BrawlerEntity>;
     async fn upload_base64img(
         &self,
         user_id: i32,
         base64img: Base64Img,
         opt: UploadImageOptions,
-    ) -> Result<UploadedImg>;
+    ) -> AppResult<UploadedImg>;
 
-    async fn get_missions(&self, brawler_id: i32) -> Result<Vec<MissionModel>>;
-    async fn crew_counting(&self, mission_id: i32) -> Result<u32>;
-    async fn get_profile_stats(&self, brawler_id: i32) -> Result<ProfileStats>;
-    async fn update_username(&self, brawler_id: i32, new_username: String) -> Result<()>;
-    async fn update_avatar_url(&self, brawler_id: i32, url: String) -> Result<()>;
-    async fn find_by_id(&self, brawler_id: i32) -> Result<BrawlerEntity>;
+    async fn get_missions(
+        &self,
+        brawler_id: i32,
+        page: &MissionPageQuery,
+    ) -> AppResult<Page<MissionModel>>;
+    async fn crew_counting(&self, mission_id: i32) -> AppResult<u32>;
+    async fn get_profile_stats(&self, brawler_id: i32) -> AppResult<ProfileStatsRow>;
+    async fn update_username(&self, brawler_id: i32, new_username: String) -> AppResult<()>;
+    async fn update_avatar_url(&self, brawler_id: i32, url: String) -> AppResult<()>;
+    async fn find_by_id(&self, brawler_id: i32) -> AppResult<BrawlerEntity>;
 }
@@ -1,10 +1,13 @@
-use anyhow::Result;
 use async_trait::async_trait;
 
-use crate::domain::entities::crew_memberships::CrewMemberShips;
+use crate::domain::{
+    entities::{crew_memberships::CrewMemberShips, crew_removals::AddCrewRemovalEntity},
+    errors::AppResult,
+};
 
 #[async_trait]
 pub trait CrewOperationRepository {
-    async fn join(&self, crew_member_ships: CrewMemberShips) -> Result<()>;
-    async fn leave(&self, crew_member_ships: CrewMemberShips) -> Result<()>;
+    async fn join(&self, crew_member_ships: CrewMemberShips) -> AppResult<()>;
+    async fn leave(&self, crew_member_ships: CrewMemberShips) -> AppResult<()>;
+    async fn kick(&self, add_crew_removal_entity: AddCrewRemovalEntity) -> AppResult<()>;
 }
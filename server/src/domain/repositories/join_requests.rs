@@ -0,0 +1,25 @@
+use async_trait::async_trait;
+
+use crate::domain::{
+    errors::AppResult,
+    value_objects::join_requests::{JoinRequestModel, MissionInvitationModel},
+};
+
+#[async_trait]
+pub trait JoinRequestRepository {
+    async fn request(&self, mission_id: i32, brawler_id: i32) -> AppResult<i32>;
+    async fn get_pending_requests(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+    ) -> AppResult<Vec<JoinRequestModel>>;
+    async fn approve(&self, request_id: i32, chief_id: i32) -> AppResult<()>;
+    async fn reject(&self, request_id: i32, chief_id: i32) -> AppResult<()>;
+    async fn invite(&self, mission_id: i32, chief_id: i32, brawler_id: i32) -> AppResult<i32>;
+    async fn get_pending_invitations(
+        &self,
+        brawler_id: i32,
+    ) -> AppResult<Vec<MissionInvitationModel>>;
+    async fn accept_invitation(&self, invitation_id: i32, brawler_id: i32) -> AppResult<()>;
+    async fn decline_invitation(&self, invitation_id: i32, brawler_id: i32) -> AppResult<()>;
+}
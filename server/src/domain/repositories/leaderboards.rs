@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+
+use crate::domain::{
+    errors::AppResult,
+    value_objects::leaderboards::{LeaderboardBoard, LeaderboardEntryModel, LeaderboardWindow},
+};
+
+#[async_trait]
+pub trait LeaderboardRepository {
+    async fn get_entries(
+        &self,
+        board: LeaderboardBoard,
+        window: LeaderboardWindow,
+        places: i32,
+    ) -> AppResult<Vec<LeaderboardEntryModel>>;
+    async fn refreshed_at(&self) -> AppResult<Option<NaiveDateTime>>;
+    async fn refresh(&self, refreshed_at: NaiveDateTime) -> AppResult<()>;
+}
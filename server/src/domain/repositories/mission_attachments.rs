@@ -0,0 +1,28 @@
+use async_trait::async_trait;
+
+use crate::{
+    domain::{
+        entities::mission_attachments::MissionAttachmentEntity,
+        errors::AppResult,
+        value_objects::{base64_img::Base64Img, mission_attachment::MissionAttachmentKind},
+    },
+    infrastructure::cloudinary::UploadImageOptions,
+};
+
+#[async_trait]
+pub trait MissionAttachmentRepository {
+    // Stores the file and records it. A new cover replaces the previous one.
+    async fn upload(
+        &self,
+        mission_id: i32,
+        uploaded_by: i32,
+        kind: MissionAttachmentKind,
+        file_name: String,
+        base64img: Base64Img,
+        opt: UploadImageOptions,
+    ) -> AppResult<MissionAttachmentEntity>;
+    async fn get_all(&self, mission_id: i32) -> AppResult<Vec<MissionAttachmentEntity>>;
+    async fn count_files(&self, mission_id: i32) -> AppResult<i64>;
+    async fn remove(&self, attachment_id: i32, mission_id: i32) -> AppResult<()>;
+    async fn remove_cover(&self, mission_id: i32) -> AppResult<()>;
+}
@@ -0,0 +1,32 @@
+use async_trait::async_trait;
+
+use crate::domain::{
+    entities::mission_comments::{AddMissionCommentEntity, MissionCommentEntity},
+    errors::AppResult,
+    value_objects::{
+        mission_comment::MissionCommentModel,
+        pagination::{CommentPageQuery, Page},
+    },
+};
+
+#[async_trait]
+pub trait MissionCommentRepository {
+    async fn add(&self, add_mission_comment_entity: AddMissionCommentEntity) -> AppResult<i32>;
+    async fn get_one(&self, comment_id: i32, mission_id: i32) -> AppResult<MissionCommentEntity>;
+    // Top-level comments when `parent_id` is None, otherwise the replies to that comment
+    async fn get_page(
+        &self,
+        mission_id: i32,
+        parent_id: Option<i32>,
+        page: &CommentPageQuery,
+    ) -> AppResult<Page<MissionCommentModel>>;
+    async fn edit(
+        &self,
+        comment_id: i32,
+        mission_id: i32,
+        author_id: i32,
+        body: String,
+    ) -> AppResult<()>;
+    async fn remove(&self, comment_id: i32, mission_id: i32) -> AppResult<()>;
+    async fn set_pinned(&self, comment_id: i32, mission_id: i32, pinned: bool) -> AppResult<()>;
+}
@@ -0,0 +1,41 @@
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+
+use crate::domain::{
+    entities::mission_outcome_proposals::{AddOutcomeProposalEntity, AddOutcomeVoteEntity},
+    errors::AppResult,
+    value_objects::{
+        mission_consensus::{OutcomeProposalModel, ProposalResolution},
+        mission_state_machine::MissionTransition,
+    },
+};
+
+#[async_trait]
+pub trait MissionConsensusRepository {
+    async fn propose(
+        &self,
+        add_outcome_proposal_entity: AddOutcomeProposalEntity,
+    ) -> AppResult<i32>;
+    // The most recent proposal of the mission, open or not
+    async fn get_latest(&self, mission_id: i32) -> AppResult<OutcomeProposalModel>;
+    async fn vote(&self, add_outcome_vote_entity: AddOutcomeVoteEntity) -> AppResult<()>;
+    // Open proposals whose voting window is over, on missions still waiting for them
+    async fn get_expired(&self, now: NaiveDateTime) -> AppResult<Vec<OutcomeProposalModel>>;
+    // Closes the proposal and moves the mission in one go. False when another request
+    // already closed it.
+    async fn settle(
+        &self,
+        proposal_id: i32,
+        mission_id: i32,
+        chief_id: i32,
+        resolution: ProposalResolution,
+        transition: MissionTransition,
+    ) -> AppResult<bool>;
+    async fn resolve_dispute(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        admin_id: i32,
+        transition: MissionTransition,
+    ) -> AppResult<i32>;
+}
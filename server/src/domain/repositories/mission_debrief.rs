@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+
+use crate::domain::{
+    entities::mission_ratings::AddMissionRatingEntity, errors::AppResult,
+    value_objects::mission_debrief::MissionRatingModel,
+};
+
+#[async_trait]
+pub trait MissionDebriefRepository {
+    async fn add_rating(&self, add_mission_rating_entity: AddMissionRatingEntity)
+    -> AppResult<i32>;
+    async fn get_ratings(&self, mission_id: i32) -> AppResult<Vec<MissionRatingModel>>;
+}
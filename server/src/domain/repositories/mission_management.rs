@@ -1,12 +1,55 @@
-use anyhow::Result;
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
 
-use crate::domain::entities::missions::{AddMissionEntity, EditMissionEntity};
+use crate::domain::{
+    entities::missions::{AddMissionEntity, EditMissionEntity},
+    errors::AppResult,
+    value_objects::{
+        mission_model::DeletedMissionModel, mission_retention::MissionRetention,
+        mission_state_machine::MissionTransition,
+    },
+};
 
 #[async_trait]
 pub trait MissionManagementRepository {
-    async fn add(&self, add_mission_entity: AddMissionEntity) -> Result<i32>;
-    async fn edit(&self, mission_id: i32, edit_mission_entity: EditMissionEntity) -> Result<i32>;
-    async fn start(&self, mission_id: i32) -> Result<()>;
-    async fn remove(&self, mission_id: i32, chief_id: i32) -> Result<()>;
+    async fn add(&self, add_mission_entity: AddMissionEntity) -> AppResult<i32>;
+    // Adds a copy of `source_mission_id`, inviting its current crew when `reinvite_crew` is set
+    async fn add_clone(
+        &self,
+        source_mission_id: i32,
+        add_mission_entity: AddMissionEntity,
+        reinvite_crew: bool,
+    ) -> AppResult<i32>;
+    async fn edit(&self, mission_id: i32, edit_mission_entity: EditMissionEntity)
+    -> AppResult<i32>;
+    async fn start(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        transition: MissionTransition,
+    ) -> AppResult<()>;
+    async fn remove(&self, mission_id: i32, chief_id: i32) -> AppResult<()>;
+    // Missions of `chief_id` that are still within the retention window, latest removal first
+    async fn get_deleted(
+        &self,
+        chief_id: i32,
+        retention: MissionRetention,
+        now: NaiveDateTime,
+    ) -> AppResult<Vec<DeletedMissionModel>>;
+    async fn restore(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        deleted_after: NaiveDateTime,
+    ) -> AppResult<()>;
+    // Deletes for good the missions removed before `deleted_before`, along with their crew
+    // and everything else attached to them
+    async fn purge(&self, deleted_before: NaiveDateTime) -> AppResult<Vec<i32>>;
+    async fn transfer_chief(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        new_chief_id: i32,
+        stay_in_crew: bool,
+    ) -> AppResult<()>;
 }
@@ -1,9 +1,13 @@
-use anyhow::Result;
 use async_trait::async_trait;
 
+use crate::domain::{errors::AppResult, value_objects::mission_state_machine::MissionTransition};
+
 #[async_trait]
 pub trait MissionOperationRepository {
-    async fn to_progress(&self, mission_id: i32, chief_id: i32) -> Result<i32>;
-    async fn to_completed(&self, mission_id: i32, chief_id: i32) -> Result<i32>;
-    async fn to_failed(&self, mission_id: i32, chief_id: i32) -> Result<i32>;
+    async fn transition(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        transition: MissionTransition,
+    ) -> AppResult<i32>;
 }
@@ -0,0 +1,9 @@
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+
+use crate::domain::{errors::AppResult, value_objects::mission_schedule::DueMissionModel};
+
+#[async_trait]
+pub trait MissionSchedulerRepository {
+    async fn get_due_missions(&self, now: NaiveDateTime) -> AppResult<Vec<DueMissionModel>>;
+}
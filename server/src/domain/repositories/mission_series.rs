@@ -0,0 +1,34 @@
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+
+use crate::domain::{
+    entities::mission_series::{
+        AddMissionSeriesEntity, EditMissionSeriesEntity, MissionSeriesEntity,
+    },
+    errors::AppResult,
+};
+
+#[async_trait]
+pub trait MissionSeriesRepository {
+    async fn add(&self, add_mission_series_entity: AddMissionSeriesEntity) -> AppResult<i32>;
+    // Also applies the changes to the occurrences that start after `now` and are still Open
+    async fn edit(
+        &self,
+        series_id: i32,
+        edit_mission_series_entity: EditMissionSeriesEntity,
+        now: NaiveDateTime,
+    ) -> AppResult<i32>;
+    // Also removes the occurrences that start after `now` and are still Open
+    async fn remove(&self, series_id: i32, chief_id: i32, now: NaiveDateTime) -> AppResult<()>;
+    async fn get_one(&self, series_id: i32, chief_id: i32) -> AppResult<MissionSeriesEntity>;
+    async fn get_all(&self, chief_id: i32) -> AppResult<Vec<MissionSeriesEntity>>;
+    // Series whose next occurrence starts before `horizon`
+    async fn get_due_series(&self, horizon: NaiveDateTime) -> AppResult<Vec<MissionSeriesEntity>>;
+    // Moves next_occurrence from `from` to `next`, false when another run already did
+    async fn advance(
+        &self,
+        series_id: i32,
+        from: NaiveDateTime,
+        next: Option<NaiveDateTime>,
+    ) -> AppResult<bool>;
+}
@@ -0,0 +1,10 @@
+use async_trait::async_trait;
+
+use crate::domain::{
+    errors::AppResult, value_objects::mission_status_history::MissionStatusHistoryModel,
+};
+
+#[async_trait]
+pub trait MissionStatusHistoryRepository {
+    async fn get_history(&self, mission_id: i32) -> AppResult<Vec<MissionStatusHistoryModel>>;
+}
@@ -0,0 +1,21 @@
+use async_trait::async_trait;
+
+use crate::domain::{
+    entities::mission_templates::{
+        AddMissionTemplateEntity, EditMissionTemplateEntity, MissionTemplateEntity,
+    },
+    errors::AppResult,
+};
+
+#[async_trait]
+pub trait MissionTemplateRepository {
+    async fn add(&self, add_mission_template_entity: AddMissionTemplateEntity) -> AppResult<i32>;
+    async fn edit(
+        &self,
+        template_id: i32,
+        edit_mission_template_entity: EditMissionTemplateEntity,
+    ) -> AppResult<i32>;
+    async fn remove(&self, template_id: i32, owner_id: i32) -> AppResult<()>;
+    async fn get_one(&self, template_id: i32, owner_id: i32) -> AppResult<MissionTemplateEntity>;
+    async fn get_all(&self, owner_id: i32) -> AppResult<Vec<MissionTemplateEntity>>;
+}
@@ -1,16 +1,26 @@
-use anyhow::Result;
 use async_trait::async_trait;
 
-use crate::domain::value_objects::{
-    brawler_model::BrawlerModel, mission_filter::MissionFilter, mission_model::MissionModel,
+use crate::domain::{
+    errors::AppResult,
+    value_objects::{
+        brawler_model::CrewMemberRow,
+        mission_filter::MissionFilter,
+        mission_model::{MissionListItem, MissionModel},
+        pagination::{MissionPageQuery, Page},
+    },
 };
 
 #[async_trait]
 pub trait MissionViewingRepository {
-    async fn crew_counting(&self, mission_id: i32) -> Result<i64>;
-    async fn get_one(&self, mission_id: i32) -> Result<MissionModel>;
-    async fn get_all(&self, mission_filter: &MissionFilter) -> Result<Vec<MissionModel>>;
-    async fn get_crew(&self, mission_id: i32) -> Result<Vec<BrawlerModel>>;
-    async fn is_crew_member(&self, mission_id: i32, brawler_id: i32) -> Result<bool>;
-    async fn get_my_memberships(&self, brawler_id: i32) -> Result<Vec<i32>>;
+    async fn crew_counting(&self, mission_id: i32) -> AppResult<i64>;
+    async fn get_one(&self, mission_id: i32) -> AppResult<MissionModel>;
+    async fn get_all(
+        &self,
+        mission_filter: &MissionFilter,
+        viewer_id: Option<i32>,
+        page: &MissionPageQuery,
+    ) -> AppResult<Page<MissionListItem>>;
+    async fn get_crew(&self, mission_id: i32) -> AppResult<Vec<CrewMemberRow>>;
+    async fn is_crew_member(&self, mission_id: i32, brawler_id: i32) -> AppResult<bool>;
+    async fn get_my_memberships(&self, brawler_id: i32) -> AppResult<Vec<i32>>;
 }
@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+
+use crate::domain::{
+    errors::AppResult,
+    value_objects::mission_waitlist::{WaitlistPositionModel, WaitlistPromotionModel},
+};
+
+#[async_trait]
+pub trait MissionWaitlistRepository {
+    async fn enqueue(&self, mission_id: i32, brawler_id: i32) -> AppResult<WaitlistPositionModel>;
+    async fn withdraw(&self, mission_id: i32, brawler_id: i32) -> AppResult<()>;
+    async fn position(&self, mission_id: i32, brawler_id: i32) -> AppResult<WaitlistPositionModel>;
+    async fn get_promotions(&self, brawler_id: i32) -> AppResult<Vec<WaitlistPromotionModel>>;
+    async fn mark_promotion_seen(&self, promotion_id: i32, brawler_id: i32) -> AppResult<()>;
+}
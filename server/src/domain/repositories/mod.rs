@@ -1,6 +1,19 @@
 pub mod brawlers;
 pub mod crew_operation;
+pub mod join_requests;
+pub mod leaderboards;
+pub mod mission_attachments;
+pub mod mission_comments;
+pub mod mission_consensus;
+pub mod mission_debrief;
 pub mod mission_management;
 pub mod mission_operation;
+pub mod mission_scheduler;
+pub mod mission_series;
+pub mod mission_status_history;
+pub mod mission_templates;
 pub mod mission_viewing;
+pub mod mission_waitlist;
+pub mod refresh_tokens;
 // pub mod transaction_provider;
+pub mod token_revocation;
@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+
+use crate::domain::{
+    entities::refresh_tokens::{AddRefreshTokenEntity, RefreshTokenEntity},
+    errors::AppResult,
+};
+
+#[async_trait]
+pub trait RefreshTokenRepository {
+    async fn store(&self, add_refresh_token_entity: AddRefreshTokenEntity) -> AppResult<()>;
+    async fn find_by_hash(&self, token_hash: String) -> AppResult<RefreshTokenEntity>;
+    /// Revokes `token_id` and stores its replacement in one transaction.
+    /// Returns `false` when the token was already revoked by someone else.
+    async fn rotate(
+        &self,
+        token_id: i32,
+        add_refresh_token_entity: AddRefreshTokenEntity,
+    ) -> AppResult<bool>;
+    async fn revoke_family(&self, family_id: String) -> AppResult<()>;
+}
@@ -0,0 +1,21 @@
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+
+use crate::domain::errors::AppResult;
+
+#[async_trait]
+pub trait TokenRevocationRepository {
+    async fn revoke(
+        &self,
+        jti: String,
+        brawler_id: i32,
+        expires_at: NaiveDateTime,
+    ) -> AppResult<()>;
+    async fn revoke_all(&self, brawler_id: i32) -> AppResult<()>;
+    async fn is_revoked(
+        &self,
+        jti: String,
+        brawler_id: i32,
+        token_generation: i32,
+    ) -> AppResult<bool>;
+}
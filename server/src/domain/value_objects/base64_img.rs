@@ -1,14 +1,35 @@
 use anyhow::Result;
 use base64::{Engine, engine::general_purpose};
+
+pub const IMAGE_TYPES: &[&str] = &["image/png", "image/jpeg"];
+
 #[derive(Debug, Clone)]
-pub struct Base64Img(String);
+pub struct Base64Img {
+    data: String,
+    mime_type: &'static str,
+    size: usize,
+}
 
 impl Base64Img {
     pub fn into_inner(self) -> String {
-        self.0
+        self.data
+    }
+
+    // The type sniffed from the decoded bytes, never the one the client claims
+    pub fn mime_type(&self) -> &'static str {
+        self.mime_type
+    }
+
+    // Size of the decoded file in bytes
+    pub fn size(&self) -> usize {
+        self.size
     }
 
     pub fn new(data: String) -> Result<Self> {
+        Self::with_types(data, IMAGE_TYPES)
+    }
+
+    pub fn with_types(data: String, allowed_types: &[&str]) -> Result<Self> {
         if data.is_empty() {
             return Err(anyhow::anyhow!("data can not be empty !!"));
         }
@@ -17,13 +38,15 @@ impl Base64Img {
             Err(_) => return Err(anyhow::anyhow!("invalid img data !!")),
         };
         let file_type = match infer::get(&bytes) {
-            Some(t) if t.mime_type() == "image/png" || t.mime_type() == "image/jpeg" => {
-                t.mime_type()
-            }
+            Some(t) if allowed_types.contains(&t.mime_type()) => t.mime_type(),
             _ => return Err(anyhow::anyhow!("un-support file type")),
         };
 
         let base64text = format!("data:{};base64,{}", file_type, data);
-        Ok(Self(base64text))
+        Ok(Self {
+            data: base64text,
+            mime_type: file_type,
+            size: bytes.len(),
+        })
     }
 }
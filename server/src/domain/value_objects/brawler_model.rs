@@ -1,10 +1,16 @@
 use diesel::{
     prelude::QueryableByName,
-    sql_types::{Integer, VarChar},
+    sql_types::{BigInt, Int4, VarChar},
 };
 use serde::{Deserialize, Serialize};
 
-use crate::domain::entities::brawlers::RegisterBrawlerEntity;
+use crate::domain::{
+    entities::brawlers::RegisterBrawlerEntity,
+    value_objects::{
+        brawler_progression::{Progression, XpRules},
+        mission_debrief::Reputation,
+    },
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RegisterBrawlerModel {
@@ -33,14 +39,44 @@ impl RegisterBrawlerModel {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, QueryableByName)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BrawlerModel {
+    pub display_name: String,
+    pub avatar_url: String,
+    pub mission_success_count: i64,
+    pub mission_joined_count: i64,
+    #[serde(flatten)]
+    pub reputation: Reputation,
+    #[serde(flatten)]
+    pub progression: Progression,
+}
+
+// A crew member as loaded, before the level and rank are worked out from the XP
+#[derive(Debug, Clone, QueryableByName)]
+pub struct CrewMemberRow {
     #[diesel(sql_type=VarChar)]
     pub display_name: String,
     #[diesel(sql_type=VarChar)]
     pub avatar_url: String,
-    #[diesel(sql_type=Integer)]
-    pub mission_success_count: i32,
-    #[diesel(sql_type=Integer)]
-    pub mission_join_count: i32,
+    #[diesel(sql_type=BigInt)]
+    pub mission_success_count: i64,
+    #[diesel(sql_type=BigInt)]
+    pub mission_joined_count: i64,
+    #[diesel(embed)]
+    pub reputation: Reputation,
+    #[diesel(sql_type=Int4)]
+    pub xp: i32,
+}
+
+impl CrewMemberRow {
+    pub fn to_model(&self, xp_rules: &XpRules) -> BrawlerModel {
+        BrawlerModel {
+            display_name: self.display_name.clone(),
+            avatar_url: self.avatar_url.clone(),
+            mission_success_count: self.mission_success_count,
+            mission_joined_count: self.mission_joined_count,
+            reputation: self.reputation.clone(),
+            progression: xp_rules.progression(self.xp),
+        }
+    }
 }
@@ -0,0 +1,138 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::value_objects::mission_difficulty::MissionDifficulty;
+
+// Why a brawler got XP, one ledger row per brawler, mission and reason
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum XpReason {
+    // Was on the crew of a mission that reached Completed
+    MissionCompleted,
+    // Was the chief of a mission that reached Completed
+    MissionLed,
+}
+
+impl Display for XpReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            XpReason::MissionCompleted => write!(f, "MissionCompleted"),
+            XpReason::MissionLed => write!(f, "MissionLed"),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RankTier {
+    #[default]
+    Bronze,
+    Silver,
+    Gold,
+    Platinum,
+    Diamond,
+}
+
+impl Display for RankTier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RankTier::Bronze => write!(f, "Bronze"),
+            RankTier::Silver => write!(f, "Silver"),
+            RankTier::Gold => write!(f, "Gold"),
+            RankTier::Platinum => write!(f, "Platinum"),
+            RankTier::Diamond => write!(f, "Diamond"),
+        }
+    }
+}
+
+// What a completed mission is worth and how XP turns into levels and ranks, set once
+// from the config
+#[derive(Debug, Clone, PartialEq)]
+pub struct XpRules {
+    pub base: i32,
+    // added for every crew member, a bigger crew is a bigger job
+    pub per_crew_member: i32,
+    // percent of the XP a mission of each difficulty is worth
+    pub easy_weight: i32,
+    pub medium_weight: i32,
+    pub hard_weight: i32,
+    // extra percent the chief gets for leading the mission
+    pub chief_bonus: i32,
+    // XP to go from level 1 to 2, every next level costs this much more than the last
+    pub level_step: i32,
+    // first level of Silver, Gold, Platinum and Diamond
+    pub rank_levels: [i32; 4],
+}
+
+impl XpRules {
+    pub fn award(&self, difficulty: MissionDifficulty, crew_size: i64, reason: XpReason) -> i32 {
+        let weight = match difficulty {
+            MissionDifficulty::Easy => self.easy_weight,
+            MissionDifficulty::Medium => self.medium_weight,
+            MissionDifficulty::Hard => self.hard_weight,
+        };
+        let bonus = match reason {
+            XpReason::MissionLed => 100 + self.chief_bonus,
+            XpReason::MissionCompleted => 100,
+        };
+
+        let xp = (i64::from(self.base) + i64::from(self.per_crew_member) * crew_size)
+            * i64::from(weight)
+            * i64::from(bonus)
+            / 10_000;
+        xp.clamp(0, i64::from(i32::MAX)) as i32
+    }
+
+    // Reaching level n takes level_step * n * (n - 1) / 2 XP in total
+    pub fn level(&self, xp: i32) -> i32 {
+        let step = i64::from(self.level_step);
+        let mut level: i64 = 1;
+        while step * level * (level + 1) / 2 <= i64::from(xp) {
+            level += 1;
+        }
+        level as i32
+    }
+
+    pub fn rank(&self, level: i32) -> RankTier {
+        let tiers = [
+            RankTier::Silver,
+            RankTier::Gold,
+            RankTier::Platinum,
+            RankTier::Diamond,
+        ];
+        self.rank_levels
+            .iter()
+            .zip(tiers)
+            .take_while(|(first_level, _)| level >= **first_level)
+            .last()
+            .map_or(RankTier::Bronze, |(_, tier)| tier)
+    }
+
+    pub fn progression(&self, xp: i32) -> Progression {
+        let level = self.level(xp);
+        Progression {
+            xp,
+            level,
+            rank: self.rank(level),
+        }
+    }
+}
+
+// Where a brawler stands. Only the XP is stored, the level and rank are worked out from
+// it on every read so a change to the rules applies to everyone at once.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct Progression {
+    pub xp: i32,
+    pub level: i32,
+    pub rank: RankTier,
+}
+
+// A brawler without any XP, level 1 and Bronze under any valid rules
+impl Default for Progression {
+    fn default() -> Self {
+        Self {
+            xp: 0,
+            level: 1,
+            rank: RankTier::default(),
+        }
+    }
+}
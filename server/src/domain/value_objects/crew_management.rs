@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::crew_removals::AddCrewRemovalEntity;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KickCrewMemberModel {
+    pub brawler_id: i32,
+    pub reason: Option<String>,
+    #[serde(default)]
+    pub ban: bool,
+}
+
+impl KickCrewMemberModel {
+    pub fn to_entity(&self, mission_id: i32, chief_id: i32) -> AddCrewRemovalEntity {
+        AddCrewRemovalEntity {
+            mission_id,
+            brawler_id: self.brawler_id,
+            removed_by: chief_id,
+            reason: self
+                .reason
+                .as_ref()
+                .map(|r| r.trim().to_string())
+                .filter(|r| !r.is_empty()),
+            banned: self.ban,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransferChiefModel {
+    pub new_chief_id: i32,
+    // The old chief stays in the crew unless they explicitly step out
+    pub stay_in_crew: Option<bool>,
+}
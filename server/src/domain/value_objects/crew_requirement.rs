@@ -0,0 +1,67 @@
+use crate::domain::errors::AppError;
+
+// Bounds every mission's crew size has to stay within, set once from the config
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CrewLimits {
+    pub min_crew: i32,
+    pub max_crew: i32,
+}
+
+impl CrewLimits {
+    pub fn new(min_crew: i32, max_crew: i32) -> Self {
+        Self { min_crew, max_crew }
+    }
+
+    // What a mission gets when its chief does not pick a crew size
+    pub fn default_requirement(&self) -> CrewRequirement {
+        CrewRequirement::new(self.min_crew, self.max_crew)
+    }
+
+    pub fn validate(&self, requirement: &CrewRequirement) -> Result<(), AppError> {
+        if requirement.min_crew < self.min_crew {
+            return Err(AppError::Validation(format!(
+                "min_crew must be at least {}",
+                self.min_crew
+            )));
+        }
+        if requirement.max_crew > self.max_crew {
+            return Err(AppError::Validation(format!(
+                "max_crew must be at most {}",
+                self.max_crew
+            )));
+        }
+        if requirement.min_crew > requirement.max_crew {
+            return Err(AppError::Validation(
+                "min_crew must not be greater than max_crew".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CrewRequirement {
+    pub min_crew: i32,
+    pub max_crew: i32,
+}
+
+impl CrewRequirement {
+    pub fn new(min_crew: i32, max_crew: i32) -> Self {
+        Self { min_crew, max_crew }
+    }
+
+    // A mission can start once its crew reaches min_crew, a full crew included
+    pub fn is_met_by(&self, crew_count: i64) -> bool {
+        (i64::from(self.min_crew)..=i64::from(self.max_crew)).contains(&crew_count)
+    }
+
+    pub fn ensure_met_by(&self, crew_count: i64) -> Result<(), AppError> {
+        if !self.is_met_by(crew_count) {
+            return Err(AppError::Validation(format!(
+                "The mission needs between {} and {} crew members to start, it has {}",
+                self.min_crew, self.max_crew, crew_count
+            )));
+        }
+        Ok(())
+    }
+}
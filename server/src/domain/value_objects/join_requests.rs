@@ -0,0 +1,93 @@
+use std::fmt::Display;
+
+use chrono::NaiveDateTime;
+use diesel::{
+    prelude::QueryableByName,
+    sql_types::{Int4, Nullable, Timestamp, Varchar},
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub enum JoinRequestStatuses {
+    #[default]
+    Pending,
+    Approved,
+    Rejected,
+}
+
+impl Display for JoinRequestStatuses {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JoinRequestStatuses::Pending => write!(f, "Pending"),
+            JoinRequestStatuses::Approved => write!(f, "Approved"),
+            JoinRequestStatuses::Rejected => write!(f, "Rejected"),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub enum InvitationStatuses {
+    #[default]
+    Pending,
+    Accepted,
+    Declined,
+}
+
+impl Display for InvitationStatuses {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvitationStatuses::Pending => write!(f, "Pending"),
+            InvitationStatuses::Accepted => write!(f, "Accepted"),
+            InvitationStatuses::Declined => write!(f, "Declined"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum JoinOutcome {
+    Joined,
+    Requested { request_id: i32 },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, QueryableByName)]
+pub struct JoinRequestModel {
+    #[diesel(sql_type = Int4)]
+    pub id: i32,
+    #[diesel(sql_type = Int4)]
+    pub mission_id: i32,
+    #[diesel(sql_type = Int4)]
+    pub brawler_id: i32,
+    #[diesel(sql_type = Varchar)]
+    pub brawler_display_name: String,
+    #[diesel(sql_type = Varchar)]
+    pub status: String,
+    #[diesel(sql_type = Timestamp)]
+    pub created_at: NaiveDateTime,
+    #[diesel(sql_type = Nullable<Timestamp>)]
+    pub decided_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, QueryableByName)]
+pub struct MissionInvitationModel {
+    #[diesel(sql_type = Int4)]
+    pub id: i32,
+    #[diesel(sql_type = Int4)]
+    pub mission_id: i32,
+    #[diesel(sql_type = Varchar)]
+    pub mission_name: String,
+    #[diesel(sql_type = Int4)]
+    pub invited_by: i32,
+    #[diesel(sql_type = Varchar)]
+    pub invited_by_display_name: String,
+    #[diesel(sql_type = Varchar)]
+    pub status: String,
+    #[diesel(sql_type = Timestamp)]
+    pub created_at: NaiveDateTime,
+    #[diesel(sql_type = Nullable<Timestamp>)]
+    pub responded_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InviteBrawlerModel {
+    pub brawler_id: i32,
+}
@@ -0,0 +1,117 @@
+use std::fmt::Display;
+
+use chrono::NaiveDateTime;
+use diesel::{
+    prelude::QueryableByName,
+    sql_types::{Double, Int4, VarChar},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{errors::AppError, value_objects::pagination::MAX_PAGE_SIZE};
+
+// How many places the chat post and a board without `limit` show
+pub const TOP_TEN: i32 = 10;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum LeaderboardBoard {
+    CompletedMissions,
+    // Percent of the finished missions that completed, from the third one on
+    SuccessRate,
+    Xp,
+    MissionsLed,
+}
+
+impl LeaderboardBoard {
+    pub const ALL: [LeaderboardBoard; 4] = [
+        LeaderboardBoard::CompletedMissions,
+        LeaderboardBoard::SuccessRate,
+        LeaderboardBoard::Xp,
+        LeaderboardBoard::MissionsLed,
+    ];
+}
+
+// Same names as the `board` column of the leaderboards view
+impl Display for LeaderboardBoard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LeaderboardBoard::CompletedMissions => write!(f, "completed_missions"),
+            LeaderboardBoard::SuccessRate => write!(f, "success_rate"),
+            LeaderboardBoard::Xp => write!(f, "xp"),
+            LeaderboardBoard::MissionsLed => write!(f, "missions_led"),
+        }
+    }
+}
+
+// The last_* windows are counted back from when the board was refreshed, the this_* ones
+// start on the first day of the calendar month or week (Monday)
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum LeaderboardWindow {
+    #[default]
+    AllTime,
+    #[serde(rename = "last_30_days")]
+    Last30Days,
+    #[serde(rename = "last_7_days")]
+    Last7Days,
+    ThisMonth,
+    ThisWeek,
+}
+
+// Same names as the `time_window` column of the leaderboards view
+impl Display for LeaderboardWindow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LeaderboardWindow::AllTime => write!(f, "all_time"),
+            LeaderboardWindow::Last30Days => write!(f, "last_30_days"),
+            LeaderboardWindow::Last7Days => write!(f, "last_7_days"),
+            LeaderboardWindow::ThisMonth => write!(f, "this_month"),
+            LeaderboardWindow::ThisWeek => write!(f, "this_week"),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct LeaderboardQuery {
+    #[serde(default)]
+    pub window: LeaderboardWindow,
+    // Places to show, brawlers tied on the last one are all included
+    pub limit: Option<i32>,
+}
+
+impl LeaderboardQuery {
+    pub fn places(&self) -> Result<i32, AppError> {
+        match self.limit {
+            None => Ok(TOP_TEN),
+            Some(limit) if (1..=MAX_PAGE_SIZE as i32).contains(&limit) => Ok(limit),
+            Some(_) => Err(AppError::Validation(format!(
+                "limit must be between 1 and {}",
+                MAX_PAGE_SIZE
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, QueryableByName)]
+pub struct LeaderboardEntryModel {
+    // Brawlers with the same score share a position
+    #[diesel(sql_type = Int4)]
+    pub position: i32,
+    #[diesel(sql_type = Int4)]
+    pub brawler_id: i32,
+    #[diesel(sql_type = VarChar)]
+    pub display_name: String,
+    #[diesel(sql_type = VarChar)]
+    pub avatar_url: String,
+    #[diesel(sql_type = Double)]
+    pub score: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LeaderboardModel {
+    pub board: LeaderboardBoard,
+    pub window: LeaderboardWindow,
+    // None until the scheduler ran once
+    pub refreshed_at: Option<NaiveDateTime>,
+    pub entries: Vec<LeaderboardEntryModel>,
+}
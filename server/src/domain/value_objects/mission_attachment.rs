@@ -0,0 +1,124 @@
+use std::{fmt::Display, io::Write, str::FromStr};
+
+use chrono::NaiveDateTime;
+use diesel::{
+    deserialize::{self, FromSql, FromSqlRow},
+    expression::AsExpression,
+    pg::{Pg, PgValue},
+    serialize::{self, IsNull, Output, ToSql},
+    sql_types::Varchar,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{errors::AppError, value_objects::base64_img::IMAGE_TYPES};
+
+// Decoded size, the base64 body the client sends is about a third bigger
+pub const MAX_ATTACHMENT_BYTES: usize = 5 * 1024 * 1024;
+// Files besides the cover image
+pub const MAX_ATTACHMENTS_PER_MISSION: i64 = 10;
+pub const MAX_FILE_NAME_LENGTH: usize = 255;
+
+pub fn ensure_room_for_file(file_count: i64) -> Result<(), AppError> {
+    if file_count >= MAX_ATTACHMENTS_PER_MISSION {
+        return Err(AppError::Conflict(format!(
+            "A mission can have at most {} attachments",
+            MAX_ATTACHMENTS_PER_MISSION
+        )));
+    }
+    Ok(())
+}
+
+// Maps and briefings come as images or PDFs
+pub const ATTACHMENT_TYPES: &[&str] = &["image/png", "image/jpeg", "application/pdf"];
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, AsExpression, FromSqlRow)]
+#[diesel(sql_type = Varchar)]
+pub enum MissionAttachmentKind {
+    Cover,
+    File,
+}
+
+impl MissionAttachmentKind {
+    pub fn allowed_types(&self) -> &'static [&'static str] {
+        match self {
+            MissionAttachmentKind::Cover => IMAGE_TYPES,
+            MissionAttachmentKind::File => ATTACHMENT_TYPES,
+        }
+    }
+}
+
+impl Display for MissionAttachmentKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MissionAttachmentKind::Cover => write!(f, "Cover"),
+            MissionAttachmentKind::File => write!(f, "File"),
+        }
+    }
+}
+
+impl FromStr for MissionAttachmentKind {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Cover" => Ok(MissionAttachmentKind::Cover),
+            "File" => Ok(MissionAttachmentKind::File),
+            _ => Err(AppError::Validation(format!(
+                "Unknown attachment kind: {}, expected Cover or File",
+                s
+            ))),
+        }
+    }
+}
+
+impl ToSql<Varchar, Pg> for MissionAttachmentKind {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        out.write_all(self.to_string().as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<Varchar, Pg> for MissionAttachmentKind {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let value = <String as FromSql<Varchar, Pg>>::from_sql(bytes)?;
+        value.parse().map_err(|e: AppError| e.to_string().into())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MissionAttachmentModel {
+    pub id: i32,
+    pub mission_id: i32,
+    pub uploaded_by: i32,
+    pub kind: MissionAttachmentKind,
+    pub file_name: String,
+    pub content_type: String,
+    pub size_bytes: i32,
+    pub url: String,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct UploadMissionAttachmentModel {
+    pub base64_string: String,
+    // Shown to the crew, defaults to "attachment"
+    pub file_name: Option<String>,
+}
+
+impl UploadMissionAttachmentModel {
+    pub fn file_name(&self) -> Result<String, AppError> {
+        let file_name = self
+            .file_name
+            .as_deref()
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .unwrap_or("attachment");
+        if file_name.chars().count() > MAX_FILE_NAME_LENGTH {
+            return Err(AppError::Validation(format!(
+                "file_name must be at most {} characters long",
+                MAX_FILE_NAME_LENGTH
+            )));
+        }
+        Ok(file_name.to_string())
+    }
+}
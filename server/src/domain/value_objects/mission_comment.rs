@@ -0,0 +1,152 @@
+use chrono::NaiveDateTime;
+use diesel::{
+    prelude::QueryableByName,
+    sql_types::{BigInt, Bool, Int4, Nullable, Text, Timestamp, Varchar},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{
+    entities::mission_comments::AddMissionCommentEntity,
+    errors::AppError,
+    value_objects::{
+        mission_join_policy::MissionJoinPolicy, mission_model::MissionModel,
+        mission_statuses::MissionStatuses,
+    },
+};
+
+pub const MAX_COMMENT_LENGTH: usize = 2000;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, QueryableByName)]
+pub struct MissionCommentModel {
+    #[diesel(sql_type = Int4)]
+    pub id: i32,
+    #[diesel(sql_type = Int4)]
+    pub mission_id: i32,
+    #[diesel(sql_type = Int4)]
+    pub author_id: i32,
+    #[diesel(sql_type = Varchar)]
+    pub author_display_name: String,
+    #[diesel(sql_type = Nullable<Int4>)]
+    pub parent_id: Option<i32>,
+    // None once the comment is deleted, its replies stay in the thread
+    #[diesel(sql_type = Nullable<Text>)]
+    pub body: Option<String>,
+    #[diesel(sql_type = Bool)]
+    pub pinned: bool,
+    #[diesel(sql_type = BigInt)]
+    pub reply_count: i64,
+    #[diesel(sql_type = Timestamp)]
+    pub created_at: NaiveDateTime,
+    #[diesel(sql_type = Nullable<Timestamp>)]
+    pub edited_at: Option<NaiveDateTime>,
+    #[diesel(sql_type = Bool)]
+    pub deleted: bool,
+}
+
+pub fn validate_body(body: &str) -> Result<String, AppError> {
+    let body = body.trim();
+    if body.is_empty() {
+        return Err(AppError::Validation(
+            "Comment must not be empty".to_string(),
+        ));
+    }
+    if body.chars().count() > MAX_COMMENT_LENGTH {
+        return Err(AppError::Validation(format!(
+            "Comment must be at most {} characters long",
+            MAX_COMMENT_LENGTH
+        )));
+    }
+    Ok(body.to_string())
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AddMissionCommentModel {
+    pub body: String,
+    // Reply to this comment instead of starting a new thread
+    pub parent_id: Option<i32>,
+}
+
+impl AddMissionCommentModel {
+    // `body` is the already validated body of this model
+    pub fn to_entity(
+        &self,
+        mission_id: i32,
+        author_id: i32,
+        body: String,
+    ) -> AddMissionCommentEntity {
+        AddMissionCommentEntity {
+            mission_id,
+            author_id,
+            parent_id: self.parent_id,
+            body,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct EditMissionCommentModel {
+    pub body: String,
+}
+
+// What the caller may do in the comment thread of one mission
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommentAccess {
+    pub viewer_id: Option<i32>,
+    pub is_chief: bool,
+    pub is_crew: bool,
+    pub status: MissionStatuses,
+    pub join_policy: MissionJoinPolicy,
+}
+
+impl CommentAccess {
+    pub fn new(mission: &MissionModel, viewer_id: Option<i32>, is_crew: bool) -> Self {
+        Self {
+            viewer_id,
+            is_chief: viewer_id == Some(mission.chief_id),
+            is_crew,
+            status: mission.status,
+            join_policy: mission.join_policy,
+        }
+    }
+
+    fn is_insider(&self) -> bool {
+        self.is_chief || self.is_crew
+    }
+
+    // Invite-only missions keep their discussion to the chief and crew
+    pub fn ensure_can_read(&self) -> Result<(), AppError> {
+        if self.join_policy == MissionJoinPolicy::InviteOnly && !self.is_insider() {
+            return Err(AppError::Forbidden(
+                "Only the chief and crew can read comments on this mission".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    // Anyone who can read an Open mission may join the discussion, once it is under way
+    // only the chief and crew can
+    pub fn ensure_can_post(&self) -> Result<(), AppError> {
+        if self.viewer_id.is_none() {
+            return Err(AppError::Unauthorized(
+                "Sign in to comment on missions".to_string(),
+            ));
+        }
+        self.ensure_can_read()?;
+        if self.status != MissionStatuses::Open && !self.is_insider() {
+            return Err(AppError::Forbidden(format!(
+                "Only the chief and crew can comment on a mission that is {}",
+                self.status
+            )));
+        }
+        Ok(())
+    }
+
+    pub fn ensure_can_moderate(&self) -> Result<(), AppError> {
+        if !self.is_chief {
+            return Err(AppError::Forbidden(
+                "Only the chief can moderate comments on this mission".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
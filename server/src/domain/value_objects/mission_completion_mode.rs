@@ -0,0 +1,62 @@
+use std::{fmt::Display, io::Write, str::FromStr};
+
+use diesel::{
+    deserialize::{self, FromSql, FromSqlRow},
+    expression::AsExpression,
+    pg::{Pg, PgValue},
+    serialize::{self, IsNull, Output, ToSql},
+    sql_types::Varchar,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::errors::AppError;
+
+// Who gets to say how a mission ended
+#[derive(
+    Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, AsExpression, FromSqlRow,
+)]
+#[diesel(sql_type = Varchar)]
+pub enum MissionCompletionMode {
+    #[default]
+    ChiefDecides,
+    // The chief proposes the outcome and the crew has to confirm it
+    Consensus,
+}
+
+impl Display for MissionCompletionMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MissionCompletionMode::ChiefDecides => write!(f, "ChiefDecides"),
+            MissionCompletionMode::Consensus => write!(f, "Consensus"),
+        }
+    }
+}
+
+impl FromStr for MissionCompletionMode {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ChiefDecides" => Ok(MissionCompletionMode::ChiefDecides),
+            "Consensus" => Ok(MissionCompletionMode::Consensus),
+            _ => Err(AppError::Validation(format!(
+                "Unknown completion mode: {}, expected ChiefDecides or Consensus",
+                s
+            ))),
+        }
+    }
+}
+
+impl ToSql<Varchar, Pg> for MissionCompletionMode {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        out.write_all(self.to_string().as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<Varchar, Pg> for MissionCompletionMode {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let value = <String as FromSql<Varchar, Pg>>::from_sql(bytes)?;
+        value.parse().map_err(|e: AppError| e.to_string().into())
+    }
+}
@@ -0,0 +1,209 @@
+use std::{fmt::Display, io::Write, str::FromStr};
+
+use chrono::{NaiveDateTime, TimeDelta};
+use diesel::{
+    deserialize::{self, FromSql, FromSqlRow},
+    expression::AsExpression,
+    pg::{Pg, PgValue},
+    prelude::QueryableByName,
+    serialize::{self, IsNull, Output, ToSql},
+    sql_types::{BigInt, Int4, Nullable, Text, Timestamp, Varchar},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{
+    entities::mission_outcome_proposals::AddOutcomeProposalEntity,
+    errors::AppError,
+    value_objects::mission_statuses::{MissionStatus, MissionStatuses},
+};
+
+// How many of the crew have to confirm an outcome and how long they have, set once from
+// the config
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConsensusRules {
+    // percent of the crew, 1 to 100
+    pub quorum: i64,
+    pub voting_window: TimeDelta,
+}
+
+impl ConsensusRules {
+    pub fn new(quorum: i64, voting_window_hours: i64) -> Self {
+        Self {
+            quorum,
+            voting_window: TimeDelta::hours(voting_window_hours),
+        }
+    }
+
+    // Rounded up, and never less than one vote so a chief can not settle it alone
+    pub fn required_votes(&self, eligible_voters: i64) -> i32 {
+        let required = (eligible_voters * self.quorum + 99) / 100;
+        required.max(1) as i32
+    }
+
+    pub fn closes_at(&self, now: NaiveDateTime) -> NaiveDateTime {
+        now + self.voting_window
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, AsExpression, FromSqlRow)]
+#[diesel(sql_type = Varchar)]
+pub enum ProposalResolution {
+    // The quorum confirmed it and the mission moved to the proposed outcome
+    Applied,
+    // The quorum was not reached, the mission waits for an admin
+    Disputed,
+}
+
+impl Display for ProposalResolution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProposalResolution::Applied => write!(f, "Applied"),
+            ProposalResolution::Disputed => write!(f, "Disputed"),
+        }
+    }
+}
+
+impl FromStr for ProposalResolution {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Applied" => Ok(ProposalResolution::Applied),
+            "Disputed" => Ok(ProposalResolution::Disputed),
+            _ => Err(AppError::Validation(format!(
+                "Unknown proposal resolution: {}, expected Applied or Disputed",
+                s
+            ))),
+        }
+    }
+}
+
+impl ToSql<Varchar, Pg> for ProposalResolution {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        out.write_all(self.to_string().as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<Varchar, Pg> for ProposalResolution {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let value = <String as FromSql<Varchar, Pg>>::from_sql(bytes)?;
+        value.parse().map_err(|e: AppError| e.to_string().into())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, QueryableByName)]
+pub struct OutcomeProposalModel {
+    #[diesel(sql_type = Int4)]
+    pub id: i32,
+    #[diesel(sql_type = Int4)]
+    pub mission_id: i32,
+    #[diesel(sql_type = Int4)]
+    pub proposed_by: i32,
+    #[diesel(sql_type = Varchar)]
+    pub proposed_by_display_name: String,
+    #[diesel(sql_type = MissionStatus)]
+    pub outcome: MissionStatuses,
+    #[diesel(sql_type = Nullable<Text>)]
+    pub reason: Option<String>,
+    #[diesel(sql_type = Int4)]
+    pub eligible_voters: i32,
+    #[diesel(sql_type = Int4)]
+    pub required_votes: i32,
+    #[diesel(sql_type = BigInt)]
+    pub agree_count: i64,
+    #[diesel(sql_type = BigInt)]
+    pub disagree_count: i64,
+    #[diesel(sql_type = Timestamp)]
+    pub closes_at: NaiveDateTime,
+    #[diesel(sql_type = Nullable<Varchar>)]
+    pub resolution: Option<ProposalResolution>,
+    #[diesel(sql_type = Nullable<Timestamp>)]
+    pub resolved_at: Option<NaiveDateTime>,
+    #[diesel(sql_type = Timestamp)]
+    pub created_at: NaiveDateTime,
+}
+
+impl OutcomeProposalModel {
+    pub fn is_open(&self) -> bool {
+        self.resolved_at.is_none()
+    }
+
+    // None while the vote can still go either way
+    pub fn verdict(&self, now: NaiveDateTime) -> Option<ProposalResolution> {
+        let required = i64::from(self.required_votes);
+        if self.agree_count >= required {
+            return Some(ProposalResolution::Applied);
+        }
+
+        let not_voted = i64::from(self.eligible_voters) - self.agree_count - self.disagree_count;
+        if self.agree_count + not_voted.max(0) < required || now >= self.closes_at {
+            return Some(ProposalResolution::Disputed);
+        }
+        None
+    }
+}
+
+// Only an outcome that ends the mission can be proposed or picked by an admin
+fn validate_outcome(outcome: MissionStatuses) -> Result<MissionStatuses, AppError> {
+    match outcome {
+        MissionStatuses::Completed | MissionStatuses::Failed => Ok(outcome),
+        outcome => Err(AppError::Validation(format!(
+            "Outcome must be Completed or Failed, got {}",
+            outcome
+        ))),
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ProposeOutcomeModel {
+    pub outcome: MissionStatuses,
+    pub reason: Option<String>,
+}
+
+impl ProposeOutcomeModel {
+    pub fn validate(&self) -> Result<MissionStatuses, AppError> {
+        validate_outcome(self.outcome)
+    }
+
+    pub fn to_entity(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        crew_count: i64,
+        rules: &ConsensusRules,
+        now: NaiveDateTime,
+    ) -> AddOutcomeProposalEntity {
+        AddOutcomeProposalEntity {
+            mission_id,
+            proposed_by: chief_id,
+            outcome: self.outcome,
+            reason: self
+                .reason
+                .as_deref()
+                .map(str::trim)
+                .filter(|r| !r.is_empty())
+                .map(str::to_string),
+            eligible_voters: crew_count as i32,
+            required_votes: rules.required_votes(crew_count),
+            closes_at: rules.closes_at(now),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct OutcomeVoteModel {
+    pub agree: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ResolveDisputeModel {
+    pub outcome: MissionStatuses,
+    pub reason: Option<String>,
+}
+
+impl ResolveDisputeModel {
+    pub fn validate(&self) -> Result<MissionStatuses, AppError> {
+        validate_outcome(self.outcome)
+    }
+}
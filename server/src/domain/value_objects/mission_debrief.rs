@@ -0,0 +1,162 @@
+use chrono::NaiveDateTime;
+use diesel::{
+    prelude::QueryableByName,
+    sql_types::{BigInt, Double, Int4, Nullable, Text, Timestamp, Varchar},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{
+    entities::mission_ratings::AddMissionRatingEntity,
+    errors::AppError,
+    value_objects::{mission_model::MissionModel, mission_statuses::MissionStatuses},
+};
+
+pub const MIN_RATING_SCORE: i32 = 1;
+pub const MAX_RATING_SCORE: i32 = 5;
+pub const MAX_RATING_COMMENT_LENGTH: usize = 1000;
+
+// What everyone who rated a brawler thought of them, across all of their missions
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, QueryableByName)]
+pub struct Reputation {
+    // Average score on the 1-5 scale, None until someone rated the brawler
+    #[diesel(sql_type = Nullable<Double>)]
+    pub reputation: Option<f64>,
+    #[diesel(sql_type = BigInt)]
+    pub rating_count: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, QueryableByName)]
+pub struct MissionRatingModel {
+    #[diesel(sql_type = Int4)]
+    pub id: i32,
+    #[diesel(sql_type = Int4)]
+    pub rater_id: i32,
+    #[diesel(sql_type = Varchar)]
+    pub rater_display_name: String,
+    #[diesel(sql_type = Int4)]
+    pub ratee_id: i32,
+    #[diesel(sql_type = Varchar)]
+    pub ratee_display_name: String,
+    #[diesel(sql_type = Int4)]
+    pub score: i32,
+    #[diesel(sql_type = Nullable<Int4>)]
+    pub mission_score: Option<i32>,
+    #[diesel(sql_type = Nullable<Text>)]
+    pub comment: Option<String>,
+    #[diesel(sql_type = Timestamp)]
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MissionDebriefModel {
+    pub mission_id: i32,
+    pub status: MissionStatuses,
+    // Average of the mission scores the crew gave, None until someone rated
+    pub mission_score: Option<f64>,
+    pub ratings: Vec<MissionRatingModel>,
+}
+
+impl MissionDebriefModel {
+    pub fn new(mission: &MissionModel, ratings: Vec<MissionRatingModel>) -> Self {
+        let mission_scores: Vec<i32> = ratings.iter().filter_map(|r| r.mission_score).collect();
+        let mission_score = if mission_scores.is_empty() {
+            None
+        } else {
+            let sum: i32 = mission_scores.iter().sum();
+            Some(sum as f64 / mission_scores.len() as f64)
+        };
+
+        Self {
+            mission_id: mission.id,
+            status: mission.status,
+            mission_score,
+            ratings,
+        }
+    }
+}
+
+fn validate_score(label: &str, score: i32) -> Result<i32, AppError> {
+    if !(MIN_RATING_SCORE..=MAX_RATING_SCORE).contains(&score) {
+        return Err(AppError::Validation(format!(
+            "{} must be between {} and {}",
+            label, MIN_RATING_SCORE, MAX_RATING_SCORE
+        )));
+    }
+    Ok(score)
+}
+
+// A blank comment is the same as leaving it out
+fn validate_comment(comment: &Option<String>) -> Result<Option<String>, AppError> {
+    let comment = match comment.as_deref().map(str::trim) {
+        Some(comment) if !comment.is_empty() => comment,
+        _ => return Ok(None),
+    };
+    if comment.chars().count() > MAX_RATING_COMMENT_LENGTH {
+        return Err(AppError::Validation(format!(
+            "Comment must be at most {} characters long",
+            MAX_RATING_COMMENT_LENGTH
+        )));
+    }
+    Ok(Some(comment.to_string()))
+}
+
+// What a crew member hands in for the chief and the mission itself
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RateChiefModel {
+    pub chief_score: i32,
+    pub mission_score: i32,
+    pub comment: Option<String>,
+}
+
+impl RateChiefModel {
+    pub fn to_entity(
+        &self,
+        mission_id: i32,
+        rater_id: i32,
+        chief_id: i32,
+    ) -> Result<AddMissionRatingEntity, AppError> {
+        Ok(AddMissionRatingEntity {
+            mission_id,
+            rater_id,
+            ratee_id: chief_id,
+            score: validate_score("Chief score", self.chief_score)?,
+            mission_score: Some(validate_score("Mission score", self.mission_score)?),
+            comment: validate_comment(&self.comment)?,
+        })
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RateCrewMemberModel {
+    pub score: i32,
+    pub comment: Option<String>,
+}
+
+impl RateCrewMemberModel {
+    pub fn to_entity(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        brawler_id: i32,
+    ) -> Result<AddMissionRatingEntity, AppError> {
+        Ok(AddMissionRatingEntity {
+            mission_id,
+            rater_id: chief_id,
+            ratee_id: brawler_id,
+            score: validate_score("Score", self.score)?,
+            mission_score: None,
+            comment: validate_comment(&self.comment)?,
+        })
+    }
+}
+
+// The debrief opens once the mission has an outcome and stays open from then on
+pub fn ensure_debrief_open(mission: &MissionModel) -> Result<(), AppError> {
+    match mission.status {
+        MissionStatuses::Completed | MissionStatuses::Failed => Ok(()),
+        status => Err(AppError::InvalidStateTransition(format!(
+            "Cannot debrief a mission that is {}",
+            status
+        ))),
+    }
+}
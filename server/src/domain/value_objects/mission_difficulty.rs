@@ -0,0 +1,81 @@
+use std::{fmt::Display, io::Write, str::FromStr};
+
+use diesel::{
+    deserialize::{self, FromSql, FromSqlRow},
+    expression::AsExpression,
+    pg::{Pg, PgValue},
+    serialize::{self, IsNull, Output, ToSql},
+    sql_types::Varchar,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::errors::AppError;
+
+#[derive(
+    Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, AsExpression, FromSqlRow,
+)]
+#[diesel(sql_type = Varchar)]
+pub enum MissionDifficulty {
+    Easy,
+    #[default]
+    Medium,
+    Hard,
+}
+
+impl MissionDifficulty {
+    pub const ALL: [MissionDifficulty; 3] = [
+        MissionDifficulty::Easy,
+        MissionDifficulty::Medium,
+        MissionDifficulty::Hard,
+    ];
+
+    // Sorting by difficulty goes by this, the names would sort Hard before Medium
+    pub fn level(&self) -> i32 {
+        match self {
+            MissionDifficulty::Easy => 1,
+            MissionDifficulty::Medium => 2,
+            MissionDifficulty::Hard => 3,
+        }
+    }
+}
+
+impl Display for MissionDifficulty {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MissionDifficulty::Easy => write!(f, "Easy"),
+            MissionDifficulty::Medium => write!(f, "Medium"),
+            MissionDifficulty::Hard => write!(f, "Hard"),
+        }
+    }
+}
+
+// Case-insensitive so "hard" and "HARD" from older clients end up as the same level
+impl FromStr for MissionDifficulty {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "easy" => Ok(MissionDifficulty::Easy),
+            "medium" => Ok(MissionDifficulty::Medium),
+            "hard" => Ok(MissionDifficulty::Hard),
+            _ => Err(AppError::Validation(format!(
+                "Unknown difficulty: {}, expected Easy, Medium or Hard",
+                s
+            ))),
+        }
+    }
+}
+
+impl ToSql<Varchar, Pg> for MissionDifficulty {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        out.write_all(self.to_string().as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<Varchar, Pg> for MissionDifficulty {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let value = <String as FromSql<Varchar, Pg>>::from_sql(bytes)?;
+        value.parse().map_err(|e: AppError| e.to_string().into())
+    }
+}
@@ -1,9 +1,84 @@
-use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 
-use crate::domain::value_objects::mission_statuses::MissionStatuses;
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Deserializer, Serialize};
 
+use crate::domain::{
+    errors::AppError,
+    value_objects::{mission_difficulty::MissionDifficulty, mission_statuses::MissionStatuses},
+};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum MissionMembership {
+    // Missions the caller is a crew member of
+    Joined,
+    NotJoined,
+}
+
+// Multi-select fields are comma separated in the query string, e.g. `status=Open,InProgress`
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct MissionFilter {
+    // Full-text search over name and description, e.g. `q=escort convoy north`
+    pub q: Option<String>,
     pub name: Option<String>,
-    pub status: Option<MissionStatuses>,
+    #[serde(default, deserialize_with = "comma_separated")]
+    pub status: Option<Vec<MissionStatuses>>,
+    #[serde(default, deserialize_with = "comma_separated")]
+    pub difficulty: Option<Vec<MissionDifficulty>>,
+    pub chief_id: Option<i32>,
+    pub created_after: Option<NaiveDateTime>,
+    pub created_before: Option<NaiveDateTime>,
+    pub has_open_slots: Option<bool>,
+    pub membership: Option<MissionMembership>,
+}
+
+impl MissionFilter {
+    // A blank `q` is ignored instead of matching nothing
+    pub fn search_terms(&self) -> Option<&str> {
+        self.q.as_deref().map(str::trim).filter(|q| !q.is_empty())
+    }
+
+    pub fn validate(&self, viewer_id: Option<i32>) -> Result<(), AppError> {
+        if let (Some(after), Some(before)) = (self.created_after, self.created_before) {
+            if after > before {
+                return Err(AppError::Validation(
+                    "created_after must not be later than created_before".to_string(),
+                ));
+            }
+        }
+
+        if self.membership.is_some() && viewer_id.is_none() {
+            return Err(AppError::Unauthorized(
+                "Sign in to filter missions by membership".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+fn comma_separated<'de, D, T>(deserializer: D) -> Result<Option<Vec<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+    T::Err: std::fmt::Display,
+{
+    let Some(raw) = Option::<String>::deserialize(deserializer)? else {
+        return Ok(None);
+    };
+
+    let values = raw
+        .split(',')
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(|value| value.parse::<T>().map_err(serde::de::Error::custom))
+        .collect::<Result<Vec<T>, _>>()?;
+
+    // `status=` means no status filter rather than "match nothing"
+    Ok(if values.is_empty() {
+        None
+    } else {
+        Some(values)
+    })
 }
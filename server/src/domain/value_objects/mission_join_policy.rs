@@ -0,0 +1,63 @@
+use std::{fmt::Display, io::Write, str::FromStr};
+
+use diesel::{
+    deserialize::{self, FromSql, FromSqlRow},
+    expression::AsExpression,
+    pg::{Pg, PgValue},
+    serialize::{self, IsNull, Output, ToSql},
+    sql_types::Varchar,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::errors::AppError;
+
+#[derive(
+    Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, AsExpression, FromSqlRow,
+)]
+#[diesel(sql_type = Varchar)]
+pub enum MissionJoinPolicy {
+    #[default]
+    Open,
+    ApprovalRequired,
+    InviteOnly,
+}
+
+impl Display for MissionJoinPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MissionJoinPolicy::Open => write!(f, "Open"),
+            MissionJoinPolicy::ApprovalRequired => write!(f, "ApprovalRequired"),
+            MissionJoinPolicy::InviteOnly => write!(f, "InviteOnly"),
+        }
+    }
+}
+
+impl FromStr for MissionJoinPolicy {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Open" => Ok(MissionJoinPolicy::Open),
+            "ApprovalRequired" => Ok(MissionJoinPolicy::ApprovalRequired),
+            "InviteOnly" => Ok(MissionJoinPolicy::InviteOnly),
+            _ => Err(AppError::Validation(format!(
+                "Unknown join policy: {}, expected Open, ApprovalRequired or InviteOnly",
+                s
+            ))),
+        }
+    }
+}
+
+impl ToSql<Varchar, Pg> for MissionJoinPolicy {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        out.write_all(self.to_string().as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<Varchar, Pg> for MissionJoinPolicy {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let value = <String as FromSql<Varchar, Pg>>::from_sql(bytes)?;
+        value.parse().map_err(|e: AppError| e.to_string().into())
+    }
+}
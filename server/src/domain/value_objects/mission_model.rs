@@ -1,10 +1,18 @@
 use crate::domain::{
     entities::missions::{AddMissionEntity, EditMissionEntity},
-    value_objects::mission_statuses::MissionStatuses,
+    errors::AppError,
+    value_objects::{
+        crew_requirement::CrewRequirement,
+        mission_completion_mode::MissionCompletionMode,
+        mission_difficulty::MissionDifficulty,
+        mission_join_policy::MissionJoinPolicy,
+        mission_statuses::{MissionStatus, MissionStatuses},
+    },
 };
 use chrono::NaiveDateTime;
 use diesel::prelude::QueryableByName;
-use diesel::sql_types::{BigInt, Int4, Nullable, Text, Timestamp, Varchar};
+use diesel::sql_types::{BigInt, Float4, Int4, Nullable, Text, Timestamp, Varchar};
+
 use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, QueryableByName)]
 pub struct MissionModel {
@@ -14,10 +22,10 @@ pub struct MissionModel {
     pub name: String,
     #[diesel(sql_type = Nullable<Text>)]
     pub description: Option<String>,
+    #[diesel(sql_type = MissionStatus)]
+    pub status: MissionStatuses,
     #[diesel(sql_type = Varchar)]
-    pub status: String,
-    #[diesel(sql_type = Varchar)]
-    pub difficulty: String,
+    pub difficulty: MissionDifficulty,
     #[diesel(sql_type = Int4)]
     pub chief_id: i32,
     #[diesel(sql_type = Varchar)]
@@ -26,26 +34,108 @@ pub struct MissionModel {
     pub crew_count: i64,
     #[diesel(sql_type = Int4)]
     pub max_crew: i32,
+    #[diesel(sql_type = Int4)]
+    pub min_crew: i32,
+    #[diesel(sql_type = Varchar)]
+    pub join_policy: MissionJoinPolicy,
+    #[diesel(sql_type = Varchar)]
+    pub completion_mode: MissionCompletionMode,
+    #[diesel(sql_type = Nullable<Timestamp>)]
+    pub scheduled_start: Option<NaiveDateTime>,
+    #[diesel(sql_type = Nullable<Timestamp>)]
+    pub deadline: Option<NaiveDateTime>,
+    // The recurring series this mission is an occurrence of
+    #[diesel(sql_type = Nullable<Int4>)]
+    pub series_id: Option<i32>,
+    #[diesel(sql_type = Nullable<Varchar>)]
+    pub cover_url: Option<String>,
     #[diesel(sql_type = Timestamp)]
     pub created_at: NaiveDateTime,
     #[diesel(sql_type = Timestamp)]
     pub updated_at: NaiveDateTime,
 }
 
+impl MissionModel {
+    pub fn crew_requirement(&self) -> CrewRequirement {
+        CrewRequirement::new(self.min_crew, self.max_crew)
+    }
+}
+
+pub const MIN_MISSION_NAME_LENGTH: usize = 3;
+
+// The one name rule for missions and everything that creates them, templates and series
+pub fn validate_mission_name(name: &str) -> Result<(), AppError> {
+    if name.trim().chars().count() < MIN_MISSION_NAME_LENGTH {
+        return Err(AppError::Validation(format!(
+            "Mission name must be at least {} characters long",
+            MIN_MISSION_NAME_LENGTH
+        )));
+    }
+    Ok(())
+}
+
+// A row of a mission listing. `rank` and `snippet` are only filled in for a full-text search.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, QueryableByName)]
+pub struct MissionListItem {
+    #[serde(flatten)]
+    #[diesel(embed)]
+    pub mission: MissionModel,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[diesel(sql_type = Nullable<Float4>)]
+    pub rank: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[diesel(sql_type = Nullable<Text>)]
+    pub snippet: Option<String>,
+}
+
+// A mission its chief removed, listed while it can still be restored
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, QueryableByName)]
+pub struct DeletedMissionModel {
+    #[serde(flatten)]
+    #[diesel(embed)]
+    pub mission: MissionModel,
+    #[diesel(sql_type = Timestamp)]
+    pub deleted_at: NaiveDateTime,
+    // After this the mission is deleted for good
+    #[diesel(sql_type = Timestamp)]
+    pub restorable_until: NaiveDateTime,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct AddMissionModel {
     pub name: String,
     pub description: Option<String>,
     pub difficulty: String,
+    pub min_crew: Option<i32>,
+    pub max_crew: Option<i32>,
+    #[serde(default)]
+    pub join_policy: MissionJoinPolicy,
+    #[serde(default)]
+    pub completion_mode: MissionCompletionMode,
+    pub scheduled_start: Option<NaiveDateTime>,
+    pub deadline: Option<NaiveDateTime>,
 }
 
 impl AddMissionModel {
-    pub fn to_entity(&self, chief_id: i32) -> AddMissionEntity {
+    // `difficulty` and `crew` are the already validated values of this model
+    pub fn to_entity(
+        &self,
+        chief_id: i32,
+        difficulty: MissionDifficulty,
+        crew: CrewRequirement,
+    ) -> AddMissionEntity {
         AddMissionEntity {
             name: self.name.clone(),
             description: self.description.clone(),
-            difficulty: self.difficulty.clone(),
-            status: MissionStatuses::Open.to_string(),
+            difficulty,
+            min_crew: crew.min_crew,
+            max_crew: crew.max_crew,
+            status: MissionStatuses::Open,
+            join_policy: self.join_policy,
+            completion_mode: self.completion_mode,
+            scheduled_start: self.scheduled_start,
+            deadline: self.deadline,
+            series_id: None,
             chief_id,
         }
     }
@@ -55,16 +145,78 @@ impl AddMissionModel {
 pub struct EditMissionModel {
     pub name: Option<String>,
     pub description: Option<String>,
+    pub min_crew: Option<i32>,
     pub max_crew: Option<i32>,
+    pub join_policy: Option<MissionJoinPolicy>,
+    pub completion_mode: Option<MissionCompletionMode>,
+    pub difficulty: Option<String>,
+    pub scheduled_start: Option<NaiveDateTime>,
+    pub deadline: Option<NaiveDateTime>,
 }
 
 impl EditMissionModel {
-    pub fn to_entity(&self, chief_id: i32) -> EditMissionEntity {
+    pub fn to_entity(
+        &self,
+        chief_id: i32,
+        difficulty: Option<MissionDifficulty>,
+    ) -> EditMissionEntity {
         EditMissionEntity {
             name: self.name.clone(),
             description: self.description.clone(),
+            min_crew: self.min_crew,
             max_crew: self.max_crew,
+            join_policy: self.join_policy,
+            completion_mode: self.completion_mode,
+            difficulty,
+            scheduled_start: self.scheduled_start,
+            deadline: self.deadline,
+            chief_id,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct CloneMissionModel {
+    pub name: Option<String>,
+    pub scheduled_start: Option<NaiveDateTime>,
+    // Defaults to the new start plus as long as the original mission had
+    pub deadline: Option<NaiveDateTime>,
+    // Invite everyone who was in the original crew
+    #[serde(default)]
+    pub reinvite_crew: bool,
+}
+
+impl CloneMissionModel {
+    pub fn to_entity(&self, source: &MissionModel, chief_id: i32) -> AddMissionEntity {
+        let duration = source
+            .scheduled_start
+            .zip(source.deadline)
+            .map(|(start, deadline)| deadline - start);
+        let deadline = self.deadline.or_else(|| {
+            self.scheduled_start
+                .zip(duration)
+                .map(|(start, duration)| start + duration)
+        });
+
+        AddMissionEntity {
             chief_id,
+            name: self
+                .name
+                .as_deref()
+                .map(str::trim)
+                .filter(|name| !name.is_empty())
+                .unwrap_or(&source.name)
+                .to_string(),
+            status: MissionStatuses::Open,
+            description: source.description.clone(),
+            difficulty: source.difficulty,
+            min_crew: source.min_crew,
+            max_crew: source.max_crew,
+            join_policy: source.join_policy,
+            completion_mode: source.completion_mode,
+            scheduled_start: self.scheduled_start,
+            deadline,
+            series_id: None,
         }
     }
 }
@@ -0,0 +1,199 @@
+use std::{fmt::Display, io::Write, str::FromStr};
+
+use chrono::{Datelike, NaiveDate, NaiveDateTime, Weekday};
+use diesel::{
+    deserialize::{self, FromSql, FromSqlRow},
+    expression::AsExpression,
+    pg::{Pg, PgValue},
+    serialize::{self, IsNull, Output, ToSql},
+    sql_types::Varchar,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::errors::AppError;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, AsExpression, FromSqlRow)]
+#[diesel(sql_type = Varchar)]
+pub enum RecurrenceFrequency {
+    Daily,
+    // On each of the series' weekdays
+    Weekly,
+    // On the day of the month the series starts on, months without that day are skipped
+    Monthly,
+}
+
+impl Display for RecurrenceFrequency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RecurrenceFrequency::Daily => write!(f, "Daily"),
+            RecurrenceFrequency::Weekly => write!(f, "Weekly"),
+            RecurrenceFrequency::Monthly => write!(f, "Monthly"),
+        }
+    }
+}
+
+impl FromStr for RecurrenceFrequency {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Daily" => Ok(RecurrenceFrequency::Daily),
+            "Weekly" => Ok(RecurrenceFrequency::Weekly),
+            "Monthly" => Ok(RecurrenceFrequency::Monthly),
+            _ => Err(AppError::Validation(format!(
+                "Unknown frequency: {}, expected Daily, Weekly or Monthly",
+                s
+            ))),
+        }
+    }
+}
+
+impl ToSql<Varchar, Pg> for RecurrenceFrequency {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        out.write_all(self.to_string().as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<Varchar, Pg> for RecurrenceFrequency {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let value = <String as FromSql<Varchar, Pg>>::from_sql(bytes)?;
+        value.parse().map_err(|e: AppError| e.to_string().into())
+    }
+}
+
+// Stored as "Tue,Thu", sent as ["Tue", "Thu"]
+#[derive(
+    Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq, AsExpression, FromSqlRow,
+)]
+#[serde(transparent)]
+#[diesel(sql_type = Varchar)]
+pub struct RecurrenceWeekdays(pub Vec<Weekday>);
+
+impl RecurrenceWeekdays {
+    pub fn contains(&self, weekday: Weekday) -> bool {
+        self.0.contains(&weekday)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl Display for RecurrenceWeekdays {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let names = self.0.iter().map(|d| d.to_string()).collect::<Vec<_>>();
+        write!(f, "{}", names.join(","))
+    }
+}
+
+impl FromStr for RecurrenceWeekdays {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let weekdays = s
+            .split(',')
+            .map(str::trim)
+            .filter(|day| !day.is_empty())
+            .map(|day| {
+                day.parse::<Weekday>()
+                    .map_err(|_| AppError::Validation(format!("Unknown weekday: {}", day)))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(RecurrenceWeekdays(weekdays))
+    }
+}
+
+impl ToSql<Varchar, Pg> for RecurrenceWeekdays {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        out.write_all(self.to_string().as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<Varchar, Pg> for RecurrenceWeekdays {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let value = <String as FromSql<Varchar, Pg>>::from_sql(bytes)?;
+        value.parse().map_err(|e: AppError| e.to_string().into())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecurrenceRule {
+    pub frequency: RecurrenceFrequency,
+    pub weekdays: RecurrenceWeekdays,
+    // The first day the series may occur on, and the time of day of every occurrence
+    pub starts_at: NaiveDateTime,
+    pub ends_on: Option<NaiveDate>,
+    pub occurrence_count: Option<i32>,
+}
+
+impl RecurrenceRule {
+    pub fn validate(&self, now: NaiveDateTime) -> Result<(), AppError> {
+        if self.starts_at <= now {
+            return Err(AppError::Validation(
+                "starts_at must be in the future".to_string(),
+            ));
+        }
+        match self.frequency {
+            RecurrenceFrequency::Weekly if self.weekdays.is_empty() => {
+                return Err(AppError::Validation(
+                    "A Weekly series needs at least one weekday".to_string(),
+                ));
+            }
+            RecurrenceFrequency::Daily | RecurrenceFrequency::Monthly
+                if !self.weekdays.is_empty() =>
+            {
+                return Err(AppError::Validation(
+                    "weekdays only apply to a Weekly series".to_string(),
+                ));
+            }
+            _ => {}
+        }
+        if self.occurrence_count.is_some_and(|count| count <= 0) {
+            return Err(AppError::Validation(
+                "occurrence_count must be greater than 0".to_string(),
+            ));
+        }
+        if self.occurrences().next().is_none() {
+            return Err(AppError::Validation(
+                "The series ends before its first occurrence".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn occurs_on(&self, date: NaiveDate) -> bool {
+        match self.frequency {
+            RecurrenceFrequency::Daily => true,
+            RecurrenceFrequency::Weekly => self.weekdays.contains(date.weekday()),
+            RecurrenceFrequency::Monthly => date.day() == self.starts_at.day(),
+        }
+    }
+
+    // Every occurrence start in order, bounded by ends_on and occurrence_count
+    pub fn occurrences(&self) -> impl Iterator<Item = NaiveDateTime> + '_ {
+        let time = self.starts_at.time();
+        let count = self
+            .occurrence_count
+            .map_or(usize::MAX, |count| count.max(0) as usize);
+        // A Weekly rule without weekdays would never match and loop forever
+        let first_date =
+            if self.frequency == RecurrenceFrequency::Weekly && self.weekdays.is_empty() {
+                None
+            } else {
+                Some(self.starts_at.date())
+            };
+
+        std::iter::successors(first_date, |date| date.succ_opt())
+            .take_while(move |date| self.ends_on.is_none_or(|ends_on| *date <= ends_on))
+            .filter(move |date| self.occurs_on(*date))
+            .map(move |date| date.and_time(time))
+            .take(count)
+    }
+
+    // The start of the occurrence after the first `generated` ones
+    pub fn nth_occurrence(&self, generated: i32) -> Option<NaiveDateTime> {
+        self.occurrences().nth(generated.max(0) as usize)
+    }
+}
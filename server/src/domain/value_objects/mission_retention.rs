@@ -0,0 +1,25 @@
+use chrono::{NaiveDateTime, TimeDelta};
+
+// How long a removed mission can still be restored, set once from the config. Past that
+// the scheduler deletes it for good.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MissionRetention {
+    pub window: TimeDelta,
+}
+
+impl MissionRetention {
+    pub fn new(days: i64) -> Self {
+        Self {
+            window: TimeDelta::days(days),
+        }
+    }
+
+    // Missions removed before this can no longer be restored
+    pub fn cutoff(&self, now: NaiveDateTime) -> NaiveDateTime {
+        now - self.window
+    }
+
+    pub fn restorable_until(&self, deleted_at: NaiveDateTime) -> NaiveDateTime {
+        deleted_at + self.window
+    }
+}
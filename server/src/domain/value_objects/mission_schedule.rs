@@ -0,0 +1,126 @@
+use chrono::NaiveDateTime;
+use diesel::{
+    prelude::QueryableByName,
+    sql_types::{BigInt, Int4, Nullable, Timestamp},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{
+    errors::AppError,
+    value_objects::{
+        crew_requirement::CrewRequirement,
+        mission_statuses::{MissionStatus, MissionStatuses},
+    },
+};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct MissionSchedule {
+    pub scheduled_start: Option<NaiveDateTime>,
+    pub deadline: Option<NaiveDateTime>,
+}
+
+impl MissionSchedule {
+    pub fn new(scheduled_start: Option<NaiveDateTime>, deadline: Option<NaiveDateTime>) -> Self {
+        Self {
+            scheduled_start,
+            deadline,
+        }
+    }
+
+    // Only the parts being set are checked against `now`, so editing the deadline of a
+    // mission whose start has already passed is still possible
+    pub fn validate(&self, changed: &MissionSchedule, now: NaiveDateTime) -> Result<(), AppError> {
+        if changed.scheduled_start.is_some_and(|start| start <= now) {
+            return Err(AppError::Validation(
+                "scheduled_start must be in the future".to_string(),
+            ));
+        }
+        if changed.deadline.is_some_and(|deadline| deadline <= now) {
+            return Err(AppError::Validation(
+                "deadline must be in the future".to_string(),
+            ));
+        }
+        if let (Some(start), Some(deadline)) = (self.scheduled_start, self.deadline) {
+            if deadline <= start {
+                return Err(AppError::Validation(
+                    "deadline must be later than scheduled_start".to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+// A mission the scheduler has to look at: an Open one whose start or deadline has come,
+// or an InProgress one past its deadline.
+#[derive(Debug, Clone, PartialEq, QueryableByName)]
+pub struct DueMissionModel {
+    #[diesel(sql_type = Int4)]
+    pub id: i32,
+    #[diesel(sql_type = Int4)]
+    pub chief_id: i32,
+    #[diesel(sql_type = MissionStatus)]
+    pub status: MissionStatuses,
+    #[diesel(sql_type = BigInt)]
+    pub crew_count: i64,
+    #[diesel(sql_type = Int4)]
+    pub min_crew: i32,
+    #[diesel(sql_type = Int4)]
+    pub max_crew: i32,
+    #[diesel(sql_type = Nullable<Timestamp>)]
+    pub scheduled_start: Option<NaiveDateTime>,
+    #[diesel(sql_type = Nullable<Timestamp>)]
+    pub deadline: Option<NaiveDateTime>,
+}
+
+impl DueMissionModel {
+    pub fn has_enough_crew(&self) -> bool {
+        CrewRequirement::new(self.min_crew, self.max_crew).is_met_by(self.crew_count)
+    }
+
+    // The status the mission has to move to at `now`, with the reason kept in its history
+    pub fn next_status(&self, now: NaiveDateTime) -> Option<(MissionStatuses, &'static str)> {
+        let overdue = self.deadline.is_some_and(|deadline| deadline <= now);
+        let start_due = self.scheduled_start.is_some_and(|start| start <= now);
+
+        match self.status {
+            MissionStatuses::InProgress if overdue => {
+                Some((MissionStatuses::Failed, "Deadline passed"))
+            }
+            MissionStatuses::Open if overdue => Some((
+                MissionStatuses::Cancelled,
+                "Deadline passed before the mission started",
+            )),
+            MissionStatuses::Open if start_due && self.has_enough_crew() => {
+                Some((MissionStatuses::InProgress, "Scheduled start"))
+            }
+            MissionStatuses::Open if start_due => Some((
+                MissionStatuses::Cancelled,
+                "Not enough crew at the scheduled start",
+            )),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct SchedulerReport {
+    pub started: Vec<i32>,
+    pub failed: Vec<i32>,
+    pub cancelled: Vec<i32>,
+}
+
+impl SchedulerReport {
+    pub fn record(&mut self, mission_id: i32, status: MissionStatuses) {
+        match status {
+            MissionStatuses::InProgress => self.started.push(mission_id),
+            MissionStatuses::Failed => self.failed.push(mission_id),
+            MissionStatuses::Cancelled => self.cancelled.push(mission_id),
+            _ => {}
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.started.is_empty() && self.failed.is_empty() && self.cancelled.is_empty()
+    }
+}
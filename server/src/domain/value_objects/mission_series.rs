@@ -0,0 +1,127 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{
+    entities::mission_series::{AddMissionSeriesEntity, EditMissionSeriesEntity},
+    value_objects::{
+        crew_requirement::CrewRequirement,
+        mission_difficulty::MissionDifficulty,
+        mission_join_policy::MissionJoinPolicy,
+        mission_recurrence::{RecurrenceFrequency, RecurrenceRule, RecurrenceWeekdays},
+    },
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MissionSeriesModel {
+    pub id: i32,
+    pub chief_id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub difficulty: MissionDifficulty,
+    pub min_crew: i32,
+    pub max_crew: i32,
+    pub join_policy: MissionJoinPolicy,
+    pub duration_minutes: Option<i32>,
+    pub frequency: RecurrenceFrequency,
+    pub weekdays: RecurrenceWeekdays,
+    pub starts_at: NaiveDateTime,
+    pub ends_on: Option<NaiveDate>,
+    pub occurrence_count: Option<i32>,
+    pub generated_count: i32,
+    pub next_occurrence: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AddMissionSeriesModel {
+    pub name: String,
+    pub description: Option<String>,
+    pub difficulty: String,
+    pub min_crew: Option<i32>,
+    pub max_crew: Option<i32>,
+    #[serde(default)]
+    pub join_policy: MissionJoinPolicy,
+    pub duration_minutes: Option<i32>,
+    pub frequency: RecurrenceFrequency,
+    #[serde(default)]
+    pub weekdays: RecurrenceWeekdays,
+    // UTC, the date of the first possible occurrence and the time of all of them
+    pub starts_at: NaiveDateTime,
+    pub ends_on: Option<NaiveDate>,
+    pub occurrence_count: Option<i32>,
+}
+
+impl AddMissionSeriesModel {
+    pub fn rule(&self) -> RecurrenceRule {
+        RecurrenceRule {
+            frequency: self.frequency,
+            weekdays: self.weekdays.clone(),
+            starts_at: self.starts_at,
+            ends_on: self.ends_on,
+            occurrence_count: self.occurrence_count,
+        }
+    }
+
+    // `difficulty` and `crew` are the already validated values of this model
+    pub fn to_entity(
+        &self,
+        chief_id: i32,
+        difficulty: MissionDifficulty,
+        crew: CrewRequirement,
+    ) -> AddMissionSeriesEntity {
+        AddMissionSeriesEntity {
+            chief_id,
+            name: self.name.trim().to_string(),
+            description: self.description.clone(),
+            difficulty,
+            min_crew: crew.min_crew,
+            max_crew: crew.max_crew,
+            join_policy: self.join_policy,
+            duration_minutes: self.duration_minutes,
+            frequency: self.frequency,
+            weekdays: self.weekdays.clone(),
+            starts_at: self.starts_at,
+            ends_on: self.ends_on,
+            occurrence_count: self.occurrence_count,
+            next_occurrence: self.rule().nth_occurrence(0),
+        }
+    }
+}
+
+// The recurrence itself is fixed, only its end date can move. Changes also apply to the
+// occurrences that have not started yet.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct EditMissionSeriesModel {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub difficulty: Option<String>,
+    pub min_crew: Option<i32>,
+    pub max_crew: Option<i32>,
+    pub join_policy: Option<MissionJoinPolicy>,
+    pub duration_minutes: Option<i32>,
+    pub ends_on: Option<NaiveDate>,
+}
+
+impl EditMissionSeriesModel {
+    // `next_occurrence` is only set when a new end date moves it
+    pub fn to_entity(
+        &self,
+        chief_id: i32,
+        difficulty: Option<MissionDifficulty>,
+        next_occurrence: Option<Option<NaiveDateTime>>,
+    ) -> EditMissionSeriesEntity {
+        EditMissionSeriesEntity {
+            chief_id,
+            name: self.name.clone(),
+            description: self.description.clone(),
+            difficulty,
+            min_crew: self.min_crew,
+            max_crew: self.max_crew,
+            join_policy: self.join_policy,
+            duration_minutes: self.duration_minutes,
+            ends_on: self.ends_on,
+            next_occurrence,
+        }
+    }
+}
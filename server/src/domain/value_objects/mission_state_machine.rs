@@ -0,0 +1,92 @@
+use crate::domain::{
+    errors::{AppError, AppResult},
+    value_objects::{mission_model::MissionModel, mission_statuses::MissionStatuses},
+};
+
+/// A transition the state machine has approved. Repositories apply it with a
+/// compare-and-set on `from`, so a stale transition can never be written.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MissionTransition {
+    pub from: MissionStatuses,
+    pub to: MissionStatuses,
+    pub reason: Option<String>,
+}
+
+impl MissionTransition {
+    pub fn with_reason(mut self, reason: Option<String>) -> Self {
+        self.reason = reason
+            .map(|r| r.trim().to_string())
+            .filter(|r| !r.is_empty());
+        self
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MissionStateMachine {
+    status: MissionStatuses,
+    chief_id: i32,
+}
+
+impl MissionStateMachine {
+    pub fn new(status: MissionStatuses, chief_id: i32) -> Self {
+        Self { status, chief_id }
+    }
+
+    pub fn from_model(mission: &MissionModel) -> Self {
+        Self::new(mission.status, mission.chief_id)
+    }
+
+    pub fn status(&self) -> &MissionStatuses {
+        &self.status
+    }
+
+    pub fn can_transition_to(&self, to: &MissionStatuses) -> bool {
+        use MissionStatuses::*;
+
+        matches!(
+            (&self.status, to),
+            (Open, InProgress)
+                | (Open, Cancelled)
+                | (InProgress, Completed)
+                | (InProgress, Failed)
+                | (Failed, InProgress)
+                | (InProgress, Disputed)
+                | (Disputed, Completed)
+                | (Disputed, Failed)
+        )
+    }
+
+    pub fn transition(&self, actor_id: i32, to: MissionStatuses) -> AppResult<MissionTransition> {
+        if actor_id != self.chief_id {
+            return Err(AppError::Forbidden(
+                "Only the Chief can change the mission status!".to_string(),
+            ));
+        }
+
+        // Disputes are opened by a consensus vote and closed by an admin, never by the chief
+        if self.status == MissionStatuses::Disputed || to == MissionStatuses::Disputed {
+            return Err(AppError::InvalidStateTransition(
+                "Only an admin can settle a disputed mission".to_string(),
+            ));
+        }
+
+        self.settle(to)
+    }
+
+    /// A transition made on behalf of the crew or an admin rather than the chief, only
+    /// the move itself is checked.
+    pub fn settle(&self, to: MissionStatuses) -> AppResult<MissionTransition> {
+        if !self.can_transition_to(&to) {
+            return Err(AppError::InvalidStateTransition(format!(
+                "Mission can not move from {} to {}",
+                self.status, to
+            )));
+        }
+
+        Ok(MissionTransition {
+            from: self.status,
+            to,
+            reason: None,
+        })
+    }
+}
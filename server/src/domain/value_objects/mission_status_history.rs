@@ -0,0 +1,33 @@
+use chrono::NaiveDateTime;
+use diesel::{
+    prelude::QueryableByName,
+    sql_types::{Int4, Nullable, Text, Timestamp, Varchar},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::value_objects::mission_statuses::{MissionStatus, MissionStatuses};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, QueryableByName)]
+pub struct MissionStatusHistoryModel {
+    #[diesel(sql_type = Int4)]
+    pub id: i32,
+    #[diesel(sql_type = Int4)]
+    pub mission_id: i32,
+    #[diesel(sql_type = Int4)]
+    pub changed_by: i32,
+    #[diesel(sql_type = Varchar)]
+    pub changed_by_display_name: String,
+    #[diesel(sql_type = Nullable<MissionStatus>)]
+    pub from_status: Option<MissionStatuses>,
+    #[diesel(sql_type = MissionStatus)]
+    pub to_status: MissionStatuses,
+    #[diesel(sql_type = Nullable<Text>)]
+    pub reason: Option<String>,
+    #[diesel(sql_type = Timestamp)]
+    pub changed_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MissionTransitionModel {
+    pub reason: Option<String>,
+}
@@ -1,14 +1,34 @@
-use std::fmt::Display;
+use std::{fmt::Display, io::Write, str::FromStr};
 
+use diesel::{
+    deserialize::{self, FromSql, FromSqlRow},
+    expression::AsExpression,
+    pg::{Pg, PgValue},
+    serialize::{self, IsNull, Output, ToSql},
+};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+use crate::domain::errors::AppError;
+
+// The `mission_status` Postgres enum, imported by the diesel schema through diesel.toml
+#[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+#[diesel(postgres_type(name = "mission_status"))]
+pub struct MissionStatus;
+
+// Stored as the `mission_status` Postgres enum, whose labels match `Display`
+#[derive(
+    Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, AsExpression, FromSqlRow,
+)]
+#[diesel(sql_type = MissionStatus)]
 pub enum MissionStatuses {
     #[default]
     Open,
     InProgress,
     Completed,
     Failed,
+    Cancelled,
+    // A consensus vote that did not reach its quorum, an admin picks the outcome
+    Disputed,
 }
 
 impl Display for MissionStatuses {
@@ -18,6 +38,41 @@ impl Display for MissionStatuses {
             MissionStatuses::InProgress => write!(f, "InProgress"),
             MissionStatuses::Completed => write!(f, "Completed"),
             MissionStatuses::Failed => write!(f, "Failed"),
+            MissionStatuses::Cancelled => write!(f, "Cancelled"),
+            MissionStatuses::Disputed => write!(f, "Disputed"),
+        }
+    }
+}
+
+impl FromStr for MissionStatuses {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Open" => Ok(MissionStatuses::Open),
+            "InProgress" => Ok(MissionStatuses::InProgress),
+            "Completed" => Ok(MissionStatuses::Completed),
+            "Failed" => Ok(MissionStatuses::Failed),
+            "Cancelled" => Ok(MissionStatuses::Cancelled),
+            "Disputed" => Ok(MissionStatuses::Disputed),
+            _ => Err(AppError::Validation(format!(
+                "Unknown mission status: {}, expected Open, InProgress, Completed, Failed, Cancelled or Disputed",
+                s
+            ))),
         }
     }
 }
+
+impl ToSql<MissionStatus, Pg> for MissionStatuses {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        out.write_all(self.to_string().as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<MissionStatus, Pg> for MissionStatuses {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let label = std::str::from_utf8(bytes.as_bytes())?;
+        label.parse().map_err(|e: AppError| e.to_string().into())
+    }
+}
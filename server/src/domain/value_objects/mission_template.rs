@@ -0,0 +1,144 @@
+use chrono::{Duration, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{
+    entities::{
+        mission_templates::{
+            AddMissionTemplateEntity, EditMissionTemplateEntity, MissionTemplateEntity,
+        },
+        missions::AddMissionEntity,
+    },
+    errors::AppError,
+    value_objects::{
+        crew_requirement::CrewRequirement, mission_completion_mode::MissionCompletionMode,
+        mission_difficulty::MissionDifficulty, mission_join_policy::MissionJoinPolicy,
+        mission_statuses::MissionStatuses,
+    },
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MissionTemplateModel {
+    pub id: i32,
+    pub owner_id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub difficulty: MissionDifficulty,
+    pub min_crew: i32,
+    pub max_crew: i32,
+    pub join_policy: MissionJoinPolicy,
+    pub duration_minutes: Option<i32>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+pub fn validate_duration(duration_minutes: Option<i32>) -> Result<(), AppError> {
+    if duration_minutes.is_some_and(|minutes| minutes <= 0) {
+        return Err(AppError::Validation(
+            "duration_minutes must be greater than 0".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AddMissionTemplateModel {
+    pub name: String,
+    pub description: Option<String>,
+    pub difficulty: String,
+    pub min_crew: Option<i32>,
+    pub max_crew: Option<i32>,
+    #[serde(default)]
+    pub join_policy: MissionJoinPolicy,
+    pub duration_minutes: Option<i32>,
+}
+
+impl AddMissionTemplateModel {
+    // `difficulty` and `crew` are the already validated values of this model
+    pub fn to_entity(
+        &self,
+        owner_id: i32,
+        difficulty: MissionDifficulty,
+        crew: CrewRequirement,
+    ) -> AddMissionTemplateEntity {
+        AddMissionTemplateEntity {
+            owner_id,
+            name: self.name.trim().to_string(),
+            description: self.description.clone(),
+            difficulty,
+            min_crew: crew.min_crew,
+            max_crew: crew.max_crew,
+            join_policy: self.join_policy,
+            duration_minutes: self.duration_minutes,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct EditMissionTemplateModel {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub difficulty: Option<String>,
+    pub min_crew: Option<i32>,
+    pub max_crew: Option<i32>,
+    pub join_policy: Option<MissionJoinPolicy>,
+    pub duration_minutes: Option<i32>,
+}
+
+impl EditMissionTemplateModel {
+    pub fn to_entity(
+        &self,
+        owner_id: i32,
+        difficulty: Option<MissionDifficulty>,
+    ) -> EditMissionTemplateEntity {
+        EditMissionTemplateEntity {
+            owner_id,
+            name: self.name.clone(),
+            description: self.description.clone(),
+            difficulty,
+            min_crew: self.min_crew,
+            max_crew: self.max_crew,
+            join_policy: self.join_policy,
+            duration_minutes: self.duration_minutes,
+        }
+    }
+}
+
+// What may differ between two missions made from the same template
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct MissionFromTemplateModel {
+    pub name: Option<String>,
+    pub scheduled_start: Option<NaiveDateTime>,
+    // Defaults to scheduled_start + the template's duration
+    pub deadline: Option<NaiveDateTime>,
+}
+
+impl MissionFromTemplateModel {
+    pub fn to_entity(&self, template: &MissionTemplateEntity, chief_id: i32) -> AddMissionEntity {
+        let deadline = self.deadline.or_else(|| {
+            self.scheduled_start
+                .zip(template.duration_minutes)
+                .map(|(start, minutes)| start + Duration::minutes(minutes.into()))
+        });
+
+        AddMissionEntity {
+            chief_id,
+            name: self
+                .name
+                .as_deref()
+                .map(str::trim)
+                .filter(|name| !name.is_empty())
+                .unwrap_or(&template.name)
+                .to_string(),
+            status: MissionStatuses::Open,
+            description: template.description.clone(),
+            difficulty: template.difficulty,
+            min_crew: template.min_crew,
+            max_crew: template.max_crew,
+            join_policy: template.join_policy,
+            completion_mode: MissionCompletionMode::default(),
+            scheduled_start: self.scheduled_start,
+            deadline,
+            series_id: None,
+        }
+    }
+}
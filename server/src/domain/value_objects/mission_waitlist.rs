@@ -0,0 +1,27 @@
+use chrono::NaiveDateTime;
+use diesel::{
+    prelude::QueryableByName,
+    sql_types::{Int4, Nullable, Timestamp, Varchar},
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct WaitlistPositionModel {
+    pub mission_id: i32,
+    pub position: i64,
+    pub waiting: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, QueryableByName)]
+pub struct WaitlistPromotionModel {
+    #[diesel(sql_type = Int4)]
+    pub id: i32,
+    #[diesel(sql_type = Int4)]
+    pub mission_id: i32,
+    #[diesel(sql_type = Varchar)]
+    pub mission_name: String,
+    #[diesel(sql_type = Timestamp)]
+    pub promoted_at: NaiveDateTime,
+    #[diesel(sql_type = Nullable<Timestamp>)]
+    pub seen_at: Option<NaiveDateTime>,
+}
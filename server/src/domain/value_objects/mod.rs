@@ -1,7 +1,28 @@
 pub mod base64_img;
 pub mod brawler_model;
+pub mod brawler_progression;
+pub mod crew_management;
+pub mod crew_requirement;
+pub mod join_requests;
+pub mod leaderboards;
+pub mod mission_attachment;
+pub mod mission_comment;
+pub mod mission_completion_mode;
+pub mod mission_consensus;
+pub mod mission_debrief;
+pub mod mission_difficulty;
 pub mod mission_filter;
+pub mod mission_join_policy;
 pub mod mission_model;
+pub mod mission_recurrence;
+pub mod mission_retention;
+pub mod mission_schedule;
+pub mod mission_series;
+pub mod mission_state_machine;
+pub mod mission_status_history;
 pub mod mission_statuses;
+pub mod mission_template;
+pub mod mission_waitlist;
+pub mod pagination;
 pub mod profile_stats;
 pub mod uploaded_img;
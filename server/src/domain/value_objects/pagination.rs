@@ -0,0 +1,207 @@
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+
+use crate::domain::{
+    errors::AppError,
+    value_objects::{mission_filter::MissionFilter, mission_model::MissionListItem},
+};
+
+pub const DEFAULT_PAGE_SIZE: i64 = 20;
+pub const MAX_PAGE_SIZE: i64 = 100;
+
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum MissionSortKey {
+    #[default]
+    CreatedAt,
+    Name,
+    CrewCount,
+    Difficulty,
+    // Full-text search rank, only available together with `q`
+    Relevance,
+}
+
+impl MissionSortKey {
+    // The value a keyset cursor has to remember to resume right after `mission`
+    pub fn cursor_value(&self, item: &MissionListItem) -> String {
+        let mission = &item.mission;
+        match self {
+            MissionSortKey::CreatedAt => mission
+                .created_at
+                .format("%Y-%m-%d %H:%M:%S%.f")
+                .to_string(),
+            MissionSortKey::Name => mission.name.clone(),
+            MissionSortKey::CrewCount => mission.crew_count.to_string(),
+            MissionSortKey::Difficulty => mission.difficulty.level().to_string(),
+            MissionSortKey::Relevance => item.rank.unwrap_or_default().to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum SortDirection {
+    Asc,
+    #[default]
+    Desc,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MissionCursor {
+    pub sort: MissionSortKey,
+    pub value: String,
+    pub id: i32,
+}
+
+// Cursors are opaque to clients: the JSON of the cursor, base64 encoded
+fn encode_cursor<T: Serialize>(cursor: &T) -> String {
+    // Serializing a struct of plain strings and integers can not fail
+    let json = serde_json::to_vec(cursor).unwrap_or_default();
+    URL_SAFE_NO_PAD.encode(json)
+}
+
+fn decode_cursor<T: DeserializeOwned>(cursor: &str) -> Result<T, AppError> {
+    URL_SAFE_NO_PAD
+        .decode(cursor)
+        .ok()
+        .and_then(|json| serde_json::from_slice::<T>(&json).ok())
+        .ok_or_else(|| AppError::Validation("Invalid cursor".to_string()))
+}
+
+fn page_size(limit: Option<i64>) -> Result<i64, AppError> {
+    match limit {
+        None => Ok(DEFAULT_PAGE_SIZE),
+        Some(limit) if (1..=MAX_PAGE_SIZE).contains(&limit) => Ok(limit),
+        Some(_) => Err(AppError::Validation(format!(
+            "limit must be between 1 and {}",
+            MAX_PAGE_SIZE
+        ))),
+    }
+}
+
+impl MissionCursor {
+    pub fn encode(&self) -> String {
+        encode_cursor(self)
+    }
+
+    pub fn decode(cursor: &str) -> Result<Self, AppError> {
+        decode_cursor(cursor)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct MissionPageQuery {
+    pub sort: Option<MissionSortKey>,
+    pub order: Option<SortDirection>,
+    pub limit: Option<i64>,
+    pub cursor: Option<String>,
+}
+
+impl MissionPageQuery {
+    pub fn sort_key(&self) -> MissionSortKey {
+        self.sort.unwrap_or_default()
+    }
+
+    pub fn direction(&self) -> SortDirection {
+        self.order.unwrap_or_default()
+    }
+
+    pub fn page_size(&self) -> Result<i64, AppError> {
+        page_size(self.limit)
+    }
+
+    pub fn decode_cursor(&self) -> Result<Option<MissionCursor>, AppError> {
+        let Some(cursor) = self.cursor.as_deref() else {
+            return Ok(None);
+        };
+
+        let cursor = MissionCursor::decode(cursor)?;
+        if cursor.sort != self.sort_key() {
+            return Err(AppError::Validation(
+                "Cursor was issued for a different sort key".to_string(),
+            ));
+        }
+        Ok(Some(cursor))
+    }
+
+    // Search results read best by relevance, so that is the default order while searching
+    pub fn resolve_for(&self, filter: &MissionFilter) -> Result<Self, AppError> {
+        let searching = filter.search_terms().is_some();
+        if self.sort == Some(MissionSortKey::Relevance) && !searching {
+            return Err(AppError::Validation(
+                "sort=relevance needs a search query in q".to_string(),
+            ));
+        }
+
+        let mut page = self.clone();
+        if searching && page.sort.is_none() {
+            page.sort = Some(MissionSortKey::Relevance);
+        }
+        Ok(page)
+    }
+
+    pub fn validate(&self) -> Result<(), AppError> {
+        self.page_size()?;
+        self.decode_cursor()?;
+        Ok(())
+    }
+}
+
+// Comments read pinned first, then oldest first, so a cursor remembers both
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CommentCursor {
+    pub pinned: bool,
+    pub id: i32,
+}
+
+impl CommentCursor {
+    pub fn encode(&self) -> String {
+        encode_cursor(self)
+    }
+
+    pub fn decode(cursor: &str) -> Result<Self, AppError> {
+        decode_cursor(cursor)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CommentPageQuery {
+    pub limit: Option<i64>,
+    pub cursor: Option<String>,
+}
+
+impl CommentPageQuery {
+    pub fn page_size(&self) -> Result<i64, AppError> {
+        page_size(self.limit)
+    }
+
+    pub fn decode_cursor(&self) -> Result<Option<CommentCursor>, AppError> {
+        self.cursor
+            .as_deref()
+            .map(CommentCursor::decode)
+            .transpose()
+    }
+
+    pub fn validate(&self) -> Result<(), AppError> {
+        self.page_size()?;
+        self.decode_cursor()?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub next_cursor: Option<String>,
+    pub total: i64,
+}
+
+impl<T> Page<T> {
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> Page<U> {
+        Page {
+            items: self.items.into_iter().map(f).collect(),
+            next_cursor: self.next_cursor,
+            total: self.total,
+        }
+    }
+}
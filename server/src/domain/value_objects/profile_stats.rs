@@ -1,8 +1,39 @@
 use serde::{Deserialize, Serialize};
 
+use crate::domain::value_objects::{
+    brawler_progression::{Progression, XpRules},
+    mission_debrief::Reputation,
+};
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProfileStats {
     pub created_count: i64,
     pub joined_count: i64,
     pub completed_count: i64,
+    #[serde(flatten)]
+    pub reputation: Reputation,
+    #[serde(flatten)]
+    pub progression: Progression,
+}
+
+// The stats as loaded, before the level and rank are worked out from the XP
+#[derive(Debug, Clone)]
+pub struct ProfileStatsRow {
+    pub created_count: i64,
+    pub joined_count: i64,
+    pub completed_count: i64,
+    pub reputation: Reputation,
+    pub xp: i32,
+}
+
+impl ProfileStatsRow {
+    pub fn to_model(&self, xp_rules: &XpRules) -> ProfileStats {
+        ProfileStats {
+            created_count: self.created_count,
+            joined_count: self.joined_count,
+            completed_count: self.completed_count,
+            reputation: self.reputation.clone(),
+            progression: xp_rules.progression(self.xp),
+        }
+    }
 }
@@ -56,20 +56,19 @@ fn form_builder(option: UploadImageOptions, cloud_env: &CloudinaryEnv) -> Result
     Ok(form)
 }
 
-pub async fn upload(base64_image: Base64Img, option: UploadImageOptions) -> Result<UploadedImg> {
-    let cloud_env = get_cloudinary_env();
-
-    // Fallback to local storage if Cloudinary environment variables are missing OR if they are placeholder values
-    let is_configured = match &cloud_env {
-        std::result::Result::Ok(env) => env.cloud_name != "demo" && env.api_key != "123456789",
-        Err(_) => false,
-    };
+// None when Cloudinary environment variables are missing OR if they are placeholder values
+fn configured_cloud_env() -> Option<CloudinaryEnv> {
+    get_cloudinary_env()
+        .ok()
+        .filter(|env| env.cloud_name != "demo" && env.api_key != "123456789")
+}
 
-    if !is_configured {
+pub async fn upload(base64_image: Base64Img, option: UploadImageOptions) -> Result<UploadedImg> {
+    // Fallback to local storage if Cloudinary is not configured
+    let Some(cloud_env) = configured_cloud_env() else {
         return super::local_storage::save_locally(base64_image).await;
-    }
+    };
 
-    let cloud_env = cloud_env.unwrap(); // Safe due to is_configured check
     let file = Part::text(base64_image.into_inner());
     let form = form_builder(option, &cloud_env)?;
     let multipart = form.part("file", file);
@@ -91,3 +90,39 @@ pub async fn upload(base64_image: Base64Img, option: UploadImageOptions) -> Resu
         serde_json::from_str(&text).context(format!("failed to parse:\n\n {}", text))?;
     Ok(json)
 }
+
+// Deletes a file `upload` stored, from wherever it was stored
+pub async fn destroy(public_id: &str) -> Result<()> {
+    let Some(cloud_env) = configured_cloud_env() else {
+        return super::local_storage::remove_locally(public_id).await;
+    };
+
+    let timestamp = Utc::now().timestamp_millis().to_string();
+    let mut hasher = Sha1::new();
+    hasher.update(format!(
+        "public_id={}&timestamp={}{}",
+        public_id, timestamp, cloud_env.api_secret
+    ));
+    let signature = format!("{:x}", hasher.finalize());
+
+    let form = Form::new()
+        .text("public_id", public_id.to_string())
+        .text("timestamp", timestamp)
+        .text("signature", signature)
+        .text("api_key", cloud_env.api_key.clone());
+    let client = reqwest::Client::new();
+    let url = format!(
+        "https://api.cloudinary.com/v1_1/{}/image/destroy",
+        cloud_env.cloud_name
+    );
+
+    client
+        .post(&url)
+        .multipart(form)
+        .send()
+        .await
+        .context(format!("destroy on {}", url))?
+        .error_for_status()
+        .context(format!("destroy on {}", url))?;
+    Ok(())
+}
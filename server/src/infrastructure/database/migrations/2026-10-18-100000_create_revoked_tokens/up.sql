@@ -0,0 +1,11 @@
+ALTER TABLE brawlers ADD COLUMN token_generation INT NOT NULL DEFAULT 0;
+
+CREATE TABLE revoked_tokens (
+    jti VARCHAR(36) PRIMARY KEY,
+    brawler_id INTEGER NOT NULL,
+    expires_at TIMESTAMP NOT NULL,
+    revoked_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+ALTER TABLE revoked_tokens
+ADD CONSTRAINT fk_revoked_token_brawler FOREIGN KEY (brawler_id) REFERENCES brawlers(id);
@@ -0,0 +1,19 @@
+CREATE TABLE mission_status_history (
+    id SERIAL PRIMARY KEY,
+    mission_id INTEGER NOT NULL,
+    changed_by INTEGER NOT NULL,
+    from_status VARCHAR(255),
+    to_status VARCHAR(255) NOT NULL,
+    reason TEXT,
+    changed_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+ALTER TABLE mission_status_history
+ADD CONSTRAINT fk_status_history_mission FOREIGN KEY (mission_id) REFERENCES missions(id),
+ADD CONSTRAINT fk_status_history_brawler FOREIGN KEY (changed_by) REFERENCES brawlers(id);
+
+CREATE INDEX idx_mission_status_history_mission_id ON mission_status_history (mission_id, changed_at);
+
+-- Existing missions only know their current status
+INSERT INTO mission_status_history (mission_id, changed_by, from_status, to_status, changed_at)
+SELECT id, chief_id, NULL, status, created_at FROM missions;
@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS waitlist_promotions;
+DROP TABLE IF EXISTS mission_waitlist;
@@ -0,0 +1,25 @@
+CREATE TABLE mission_waitlist (
+    id SERIAL PRIMARY KEY,
+    mission_id INTEGER NOT NULL,
+    brawler_id INTEGER NOT NULL,
+    enqueued_at TIMESTAMP NOT NULL DEFAULT now(),
+    UNIQUE (mission_id, brawler_id)
+);
+
+CREATE TABLE waitlist_promotions (
+    id SERIAL PRIMARY KEY,
+    mission_id INTEGER NOT NULL,
+    brawler_id INTEGER NOT NULL,
+    promoted_at TIMESTAMP NOT NULL DEFAULT now(),
+    seen_at TIMESTAMP
+);
+
+ALTER TABLE mission_waitlist
+ADD CONSTRAINT fk_waitlist_mission FOREIGN KEY (mission_id) REFERENCES missions(id),
+ADD CONSTRAINT fk_waitlist_brawler FOREIGN KEY (brawler_id) REFERENCES brawlers(id);
+
+ALTER TABLE waitlist_promotions
+ADD CONSTRAINT fk_promotion_mission FOREIGN KEY (mission_id) REFERENCES missions(id),
+ADD CONSTRAINT fk_promotion_brawler FOREIGN KEY (brawler_id) REFERENCES brawlers(id);
+
+CREATE INDEX idx_waitlist_promotions_brawler_id ON waitlist_promotions (brawler_id, seen_at);
@@ -0,0 +1,4 @@
+DROP TABLE IF EXISTS mission_invitations;
+DROP TABLE IF EXISTS join_requests;
+
+ALTER TABLE missions DROP COLUMN join_policy;
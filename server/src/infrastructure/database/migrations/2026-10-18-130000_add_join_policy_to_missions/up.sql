@@ -0,0 +1,33 @@
+ALTER TABLE missions ADD COLUMN join_policy VARCHAR(32) NOT NULL DEFAULT 'Open';
+
+CREATE TABLE join_requests (
+    id SERIAL PRIMARY KEY,
+    mission_id INTEGER NOT NULL,
+    brawler_id INTEGER NOT NULL,
+    "status" VARCHAR(32) NOT NULL DEFAULT 'Pending',
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    decided_at TIMESTAMP
+);
+
+CREATE TABLE mission_invitations (
+    id SERIAL PRIMARY KEY,
+    mission_id INTEGER NOT NULL,
+    brawler_id INTEGER NOT NULL,
+    invited_by INTEGER NOT NULL,
+    "status" VARCHAR(32) NOT NULL DEFAULT 'Pending',
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    responded_at TIMESTAMP
+);
+
+ALTER TABLE join_requests
+ADD CONSTRAINT fk_join_request_mission FOREIGN KEY (mission_id) REFERENCES missions(id),
+ADD CONSTRAINT fk_join_request_brawler FOREIGN KEY (brawler_id) REFERENCES brawlers(id);
+
+ALTER TABLE mission_invitations
+ADD CONSTRAINT fk_invitation_mission FOREIGN KEY (mission_id) REFERENCES missions(id),
+ADD CONSTRAINT fk_invitation_brawler FOREIGN KEY (brawler_id) REFERENCES brawlers(id),
+ADD CONSTRAINT fk_invitation_invited_by FOREIGN KEY (invited_by) REFERENCES brawlers(id);
+
+-- Only one open request / invitation per brawler and mission
+CREATE UNIQUE INDEX idx_join_requests_pending ON join_requests (mission_id, brawler_id) WHERE "status" = 'Pending';
+CREATE UNIQUE INDEX idx_mission_invitations_pending ON mission_invitations (mission_id, brawler_id) WHERE "status" = 'Pending';
@@ -0,0 +1,16 @@
+CREATE TABLE crew_removals (
+    id SERIAL PRIMARY KEY,
+    mission_id INTEGER NOT NULL,
+    brawler_id INTEGER NOT NULL,
+    removed_by INTEGER NOT NULL,
+    reason TEXT,
+    banned BOOLEAN NOT NULL DEFAULT FALSE,
+    removed_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+ALTER TABLE crew_removals
+ADD CONSTRAINT fk_crew_removal_mission FOREIGN KEY (mission_id) REFERENCES missions(id),
+ADD CONSTRAINT fk_crew_removal_brawler FOREIGN KEY (brawler_id) REFERENCES brawlers(id),
+ADD CONSTRAINT fk_crew_removal_removed_by FOREIGN KEY (removed_by) REFERENCES brawlers(id);
+
+CREATE INDEX idx_crew_removals_mission_id ON crew_removals (mission_id, brawler_id);
@@ -0,0 +1,2 @@
+-- Normalized values are kept, only the constraint is dropped
+ALTER TABLE missions DROP CONSTRAINT IF EXISTS chk_missions_difficulty;
@@ -0,0 +1,13 @@
+-- Fold casing and stray whitespace into the known levels, anything unrecognisable falls
+-- back to the column default
+UPDATE missions
+SET difficulty = CASE lower(trim(difficulty))
+    WHEN 'easy' THEN 'Easy'
+    WHEN 'medium' THEN 'Medium'
+    WHEN 'hard' THEN 'Hard'
+    ELSE 'Medium'
+END
+WHERE difficulty NOT IN ('Easy', 'Medium', 'Hard');
+
+ALTER TABLE missions
+ADD CONSTRAINT chk_missions_difficulty CHECK (difficulty IN ('Easy', 'Medium', 'Hard'));
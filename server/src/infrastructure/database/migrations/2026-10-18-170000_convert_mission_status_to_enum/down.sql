@@ -0,0 +1,8 @@
+ALTER TABLE mission_status_history
+ALTER COLUMN from_status TYPE VARCHAR(255) USING from_status::text,
+ALTER COLUMN to_status TYPE VARCHAR(255) USING to_status::text;
+
+ALTER TABLE missions
+ALTER COLUMN "status" TYPE VARCHAR(255) USING "status"::text;
+
+DROP TYPE IF EXISTS mission_status;
@@ -0,0 +1,45 @@
+CREATE TYPE mission_status AS ENUM ('Open', 'InProgress', 'Completed', 'Failed');
+
+-- Fold legacy spellings into the enum labels. Anything else is left alone so the casts
+-- below fail loudly instead of guessing a status.
+UPDATE missions
+SET "status" = CASE lower(replace(trim("status"), '_', ''))
+    WHEN 'open' THEN 'Open'
+    WHEN 'inprogress' THEN 'InProgress'
+    WHEN 'completed' THEN 'Completed'
+    WHEN 'success' THEN 'Completed'
+    WHEN 'failed' THEN 'Failed'
+    ELSE "status"
+END
+WHERE "status" NOT IN ('Open', 'InProgress', 'Completed', 'Failed');
+
+ALTER TABLE missions
+ALTER COLUMN "status" TYPE mission_status USING "status"::mission_status;
+
+-- The history was backfilled from the raw missions.status, so it carries the same
+-- legacy spellings
+UPDATE mission_status_history
+SET from_status = CASE lower(replace(trim(from_status), '_', ''))
+    WHEN 'open' THEN 'Open'
+    WHEN 'inprogress' THEN 'InProgress'
+    WHEN 'completed' THEN 'Completed'
+    WHEN 'success' THEN 'Completed'
+    WHEN 'failed' THEN 'Failed'
+    ELSE from_status
+END
+WHERE from_status NOT IN ('Open', 'InProgress', 'Completed', 'Failed');
+
+UPDATE mission_status_history
+SET to_status = CASE lower(replace(trim(to_status), '_', ''))
+    WHEN 'open' THEN 'Open'
+    WHEN 'inprogress' THEN 'InProgress'
+    WHEN 'completed' THEN 'Completed'
+    WHEN 'success' THEN 'Completed'
+    WHEN 'failed' THEN 'Failed'
+    ELSE to_status
+END
+WHERE to_status NOT IN ('Open', 'InProgress', 'Completed', 'Failed');
+
+ALTER TABLE mission_status_history
+ALTER COLUMN from_status TYPE mission_status USING from_status::mission_status,
+ALTER COLUMN to_status TYPE mission_status USING to_status::mission_status;
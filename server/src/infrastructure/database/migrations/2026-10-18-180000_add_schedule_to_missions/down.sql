@@ -0,0 +1,25 @@
+DROP INDEX IF EXISTS idx_missions_deadline;
+DROP INDEX IF EXISTS idx_missions_scheduled_start;
+
+ALTER TABLE missions DROP CONSTRAINT IF EXISTS chk_missions_schedule;
+
+ALTER TABLE missions
+DROP COLUMN IF EXISTS deadline,
+DROP COLUMN IF EXISTS scheduled_start;
+
+-- Postgres can not drop an enum label, so rebuild the type without 'Cancelled'
+UPDATE missions SET "status" = 'Failed' WHERE "status" = 'Cancelled';
+UPDATE mission_status_history SET to_status = 'Failed' WHERE to_status = 'Cancelled';
+UPDATE mission_status_history SET from_status = 'Failed' WHERE from_status = 'Cancelled';
+
+ALTER TYPE mission_status RENAME TO mission_status_old;
+CREATE TYPE mission_status AS ENUM ('Open', 'InProgress', 'Completed', 'Failed');
+
+ALTER TABLE missions
+ALTER COLUMN "status" TYPE mission_status USING "status"::text::mission_status;
+
+ALTER TABLE mission_status_history
+ALTER COLUMN from_status TYPE mission_status USING from_status::text::mission_status,
+ALTER COLUMN to_status TYPE mission_status USING to_status::text::mission_status;
+
+DROP TYPE mission_status_old;
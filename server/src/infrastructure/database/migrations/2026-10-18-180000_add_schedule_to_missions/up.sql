@@ -0,0 +1,14 @@
+-- Scheduled missions that never gather a crew are cancelled rather than failed
+ALTER TYPE mission_status ADD VALUE IF NOT EXISTS 'Cancelled';
+
+ALTER TABLE missions
+ADD COLUMN scheduled_start TIMESTAMP,
+ADD COLUMN deadline TIMESTAMP;
+
+ALTER TABLE missions
+ADD CONSTRAINT chk_missions_schedule CHECK (
+    scheduled_start IS NULL OR deadline IS NULL OR deadline > scheduled_start
+);
+
+CREATE INDEX idx_missions_scheduled_start ON missions (scheduled_start) WHERE deleted_at IS NULL;
+CREATE INDEX idx_missions_deadline ON missions (deadline) WHERE deleted_at IS NULL;
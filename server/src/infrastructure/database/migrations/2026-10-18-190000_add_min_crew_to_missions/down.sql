@@ -0,0 +1,3 @@
+ALTER TABLE missions DROP CONSTRAINT IF EXISTS chk_missions_crew_size;
+
+ALTER TABLE missions DROP COLUMN IF EXISTS min_crew;
@@ -0,0 +1,7 @@
+ALTER TABLE missions ADD COLUMN min_crew INT NOT NULL DEFAULT 1;
+
+-- A mission that was shrunk below one seat can never reach the new minimum
+UPDATE missions SET min_crew = max_crew WHERE max_crew < min_crew;
+
+ALTER TABLE missions
+    ADD CONSTRAINT chk_missions_crew_size CHECK (min_crew >= 0 AND min_crew <= max_crew);
@@ -0,0 +1,24 @@
+CREATE TABLE mission_templates (
+    id SERIAL PRIMARY KEY,
+    owner_id INTEGER NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    description TEXT,
+    difficulty VARCHAR(50) NOT NULL DEFAULT 'Medium',
+    min_crew INTEGER NOT NULL DEFAULT 1,
+    max_crew INTEGER NOT NULL DEFAULT 5,
+    join_policy VARCHAR(32) NOT NULL DEFAULT 'Open',
+    -- How long a mission made from the template runs, its deadline is scheduled_start + duration
+    duration_minutes INTEGER,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+ALTER TABLE mission_templates
+ADD CONSTRAINT fk_mission_template_owner FOREIGN KEY (owner_id) REFERENCES brawlers(id),
+ADD CONSTRAINT chk_mission_templates_difficulty CHECK (difficulty IN ('Easy', 'Medium', 'Hard')),
+ADD CONSTRAINT chk_mission_templates_crew_size CHECK (min_crew >= 0 AND min_crew <= max_crew),
+ADD CONSTRAINT chk_mission_templates_duration CHECK (duration_minutes IS NULL OR duration_minutes > 0);
+
+CREATE INDEX idx_mission_templates_owner_id ON mission_templates (owner_id);
+
+SELECT diesel_manage_updated_at('mission_templates');
@@ -0,0 +1,7 @@
+DROP INDEX IF EXISTS uq_missions_series_occurrence;
+
+ALTER TABLE missions DROP CONSTRAINT IF EXISTS fk_missions_series;
+
+ALTER TABLE missions DROP COLUMN IF EXISTS series_id;
+
+DROP TABLE IF EXISTS mission_series;
@@ -0,0 +1,44 @@
+CREATE TABLE mission_series (
+    id SERIAL PRIMARY KEY,
+    chief_id INTEGER NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    description TEXT,
+    difficulty VARCHAR(50) NOT NULL DEFAULT 'Medium',
+    min_crew INTEGER NOT NULL DEFAULT 1,
+    max_crew INTEGER NOT NULL DEFAULT 5,
+    join_policy VARCHAR(32) NOT NULL DEFAULT 'Open',
+    duration_minutes INTEGER,
+    frequency VARCHAR(16) NOT NULL,
+    -- Comma separated, e.g. 'Tue,Thu', only used by Weekly series
+    weekdays VARCHAR(32) NOT NULL DEFAULT '',
+    starts_at TIMESTAMP NOT NULL,
+    ends_on DATE,
+    occurrence_count INTEGER,
+    generated_count INTEGER NOT NULL DEFAULT 0,
+    -- Start of the next occurrence to create, NULL once the series is over
+    next_occurrence TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+ALTER TABLE mission_series
+ADD CONSTRAINT fk_mission_series_chief FOREIGN KEY (chief_id) REFERENCES brawlers(id),
+ADD CONSTRAINT chk_mission_series_frequency CHECK (frequency IN ('Daily', 'Weekly', 'Monthly')),
+ADD CONSTRAINT chk_mission_series_difficulty CHECK (difficulty IN ('Easy', 'Medium', 'Hard')),
+ADD CONSTRAINT chk_mission_series_crew_size CHECK (min_crew >= 0 AND min_crew <= max_crew),
+ADD CONSTRAINT chk_mission_series_duration CHECK (duration_minutes IS NULL OR duration_minutes > 0),
+ADD CONSTRAINT chk_mission_series_count CHECK (occurrence_count IS NULL OR occurrence_count > 0);
+
+CREATE INDEX idx_mission_series_chief_id ON mission_series (chief_id);
+CREATE INDEX idx_mission_series_next_occurrence ON mission_series (next_occurrence);
+
+SELECT diesel_manage_updated_at('mission_series');
+
+ALTER TABLE missions ADD COLUMN series_id INTEGER;
+
+ALTER TABLE missions
+ADD CONSTRAINT fk_missions_series FOREIGN KEY (series_id) REFERENCES mission_series(id) ON DELETE SET NULL;
+
+-- One mission per occurrence, so two runs of the job can not both create it
+CREATE UNIQUE INDEX uq_missions_series_occurrence ON missions (series_id, scheduled_start)
+WHERE series_id IS NOT NULL AND deleted_at IS NULL;
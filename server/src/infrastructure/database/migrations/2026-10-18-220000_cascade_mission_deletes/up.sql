@@ -0,0 +1,31 @@
+-- Purging a removed mission takes everything that hangs off it along
+ALTER TABLE crew_memberships
+DROP CONSTRAINT fk_mission,
+ADD CONSTRAINT fk_mission FOREIGN KEY (mission_id) REFERENCES missions(id) ON DELETE CASCADE;
+
+ALTER TABLE mission_status_history
+DROP CONSTRAINT fk_status_history_mission,
+ADD CONSTRAINT fk_status_history_mission FOREIGN KEY (mission_id) REFERENCES missions(id) ON DELETE CASCADE;
+
+ALTER TABLE mission_waitlist
+DROP CONSTRAINT fk_waitlist_mission,
+ADD CONSTRAINT fk_waitlist_mission FOREIGN KEY (mission_id) REFERENCES missions(id) ON DELETE CASCADE;
+
+ALTER TABLE waitlist_promotions
+DROP CONSTRAINT fk_promotion_mission,
+ADD CONSTRAINT fk_promotion_mission FOREIGN KEY (mission_id) REFERENCES missions(id) ON DELETE CASCADE;
+
+ALTER TABLE join_requests
+DROP CONSTRAINT fk_join_request_mission,
+ADD CONSTRAINT fk_join_request_mission FOREIGN KEY (mission_id) REFERENCES missions(id) ON DELETE CASCADE;
+
+ALTER TABLE mission_invitations
+DROP CONSTRAINT fk_invitation_mission,
+ADD CONSTRAINT fk_invitation_mission FOREIGN KEY (mission_id) REFERENCES missions(id) ON DELETE CASCADE;
+
+ALTER TABLE crew_removals
+DROP CONSTRAINT fk_crew_removal_mission,
+ADD CONSTRAINT fk_crew_removal_mission FOREIGN KEY (mission_id) REFERENCES missions(id) ON DELETE CASCADE;
+
+-- The retention job looks up removed missions by when they were removed
+CREATE INDEX idx_missions_deleted_at ON missions (deleted_at) WHERE deleted_at IS NOT NULL;
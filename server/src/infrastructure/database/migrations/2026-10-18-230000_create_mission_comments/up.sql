@@ -0,0 +1,24 @@
+CREATE TABLE mission_comments (
+    id SERIAL PRIMARY KEY,
+    mission_id INTEGER NOT NULL,
+    author_id INTEGER NOT NULL,
+    -- The comment this one replies to, NULL for a top-level comment
+    parent_id INTEGER,
+    body TEXT NOT NULL,
+    pinned BOOLEAN NOT NULL DEFAULT false,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP NOT NULL DEFAULT now(),
+    edited_at TIMESTAMP,
+    -- A deleted comment keeps its place in the thread with its body hidden
+    deleted_at TIMESTAMP
+);
+
+ALTER TABLE mission_comments
+ADD CONSTRAINT fk_comment_mission FOREIGN KEY (mission_id) REFERENCES missions(id) ON DELETE CASCADE,
+ADD CONSTRAINT fk_comment_author FOREIGN KEY (author_id) REFERENCES brawlers(id),
+ADD CONSTRAINT fk_comment_parent FOREIGN KEY (parent_id) REFERENCES mission_comments(id) ON DELETE CASCADE;
+
+CREATE INDEX idx_mission_comments_thread ON mission_comments (mission_id, parent_id, pinned DESC, id);
+CREATE INDEX idx_mission_comments_parent_id ON mission_comments (parent_id) WHERE parent_id IS NOT NULL;
+
+SELECT diesel_manage_updated_at('mission_comments');
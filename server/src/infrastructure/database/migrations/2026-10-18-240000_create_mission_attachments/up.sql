@@ -0,0 +1,24 @@
+CREATE TABLE mission_attachments (
+    id SERIAL PRIMARY KEY,
+    mission_id INTEGER NOT NULL,
+    uploaded_by INTEGER NOT NULL,
+    kind VARCHAR(16) NOT NULL,
+    file_name VARCHAR(255) NOT NULL,
+    content_type VARCHAR(64) NOT NULL,
+    size_bytes INTEGER NOT NULL,
+    url VARCHAR(512) NOT NULL,
+    -- Where the file lives in Cloudinary or local storage, used to delete it
+    public_id VARCHAR(255) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+ALTER TABLE mission_attachments
+ADD CONSTRAINT fk_attachment_mission FOREIGN KEY (mission_id) REFERENCES missions(id) ON DELETE CASCADE,
+ADD CONSTRAINT fk_attachment_uploader FOREIGN KEY (uploaded_by) REFERENCES brawlers(id),
+ADD CONSTRAINT chk_mission_attachments_kind CHECK (kind IN ('Cover', 'File'));
+
+CREATE INDEX idx_mission_attachments_mission_id ON mission_attachments (mission_id);
+
+-- A mission has at most one cover image
+CREATE UNIQUE INDEX uq_mission_attachments_cover ON mission_attachments (mission_id)
+WHERE kind = 'Cover';
@@ -0,0 +1,23 @@
+CREATE TABLE mission_ratings (
+    id SERIAL PRIMARY KEY,
+    mission_id INTEGER NOT NULL,
+    rater_id INTEGER NOT NULL,
+    -- The chief when a crew member rates, otherwise the crew member the chief rates
+    ratee_id INTEGER NOT NULL,
+    score INTEGER NOT NULL,
+    -- Only set on the rating a crew member gives the chief
+    mission_score INTEGER,
+    comment TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+ALTER TABLE mission_ratings
+ADD CONSTRAINT fk_rating_mission FOREIGN KEY (mission_id) REFERENCES missions(id) ON DELETE CASCADE,
+ADD CONSTRAINT fk_rating_rater FOREIGN KEY (rater_id) REFERENCES brawlers(id),
+ADD CONSTRAINT fk_rating_ratee FOREIGN KEY (ratee_id) REFERENCES brawlers(id),
+ADD CONSTRAINT chk_rating_score CHECK (score BETWEEN 1 AND 5),
+ADD CONSTRAINT chk_rating_mission_score CHECK (mission_score IS NULL OR mission_score BETWEEN 1 AND 5),
+ADD CONSTRAINT chk_rating_not_self CHECK (rater_id <> ratee_id),
+ADD CONSTRAINT uq_rating_once UNIQUE (mission_id, rater_id, ratee_id);
+
+CREATE INDEX idx_mission_ratings_ratee_id ON mission_ratings (ratee_id);
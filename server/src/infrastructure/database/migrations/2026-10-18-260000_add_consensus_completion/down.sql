@@ -0,0 +1,22 @@
+DROP TABLE IF EXISTS mission_outcome_votes;
+DROP TABLE IF EXISTS mission_outcome_proposals;
+
+ALTER TABLE brawlers DROP COLUMN IF EXISTS is_admin;
+ALTER TABLE missions DROP COLUMN IF EXISTS completion_mode;
+
+-- Postgres can not drop an enum label, so rebuild the type without 'Disputed'
+UPDATE missions SET "status" = 'InProgress' WHERE "status" = 'Disputed';
+UPDATE mission_status_history SET to_status = 'InProgress' WHERE to_status = 'Disputed';
+UPDATE mission_status_history SET from_status = 'InProgress' WHERE from_status = 'Disputed';
+
+ALTER TYPE mission_status RENAME TO mission_status_old;
+CREATE TYPE mission_status AS ENUM ('Open', 'InProgress', 'Completed', 'Failed', 'Cancelled');
+
+ALTER TABLE missions
+ALTER COLUMN "status" TYPE mission_status USING "status"::text::mission_status;
+
+ALTER TABLE mission_status_history
+ALTER COLUMN from_status TYPE mission_status USING from_status::text::mission_status,
+ALTER COLUMN to_status TYPE mission_status USING to_status::text::mission_status;
+
+DROP TYPE mission_status_old;
@@ -0,0 +1,46 @@
+-- A consensus mission whose crew could not agree on the outcome waits here for an admin
+ALTER TYPE mission_status ADD VALUE IF NOT EXISTS 'Disputed';
+
+ALTER TABLE missions ADD COLUMN completion_mode VARCHAR(32) NOT NULL DEFAULT 'ChiefDecides';
+
+ALTER TABLE brawlers ADD COLUMN is_admin BOOLEAN NOT NULL DEFAULT false;
+
+CREATE TABLE mission_outcome_proposals (
+    id SERIAL PRIMARY KEY,
+    mission_id INTEGER NOT NULL,
+    proposed_by INTEGER NOT NULL,
+    outcome mission_status NOT NULL,
+    reason TEXT,
+    -- Both are fixed when the vote opens, joining or leaving later changes nothing
+    eligible_voters INTEGER NOT NULL,
+    required_votes INTEGER NOT NULL,
+    closes_at TIMESTAMP NOT NULL,
+    -- 'Applied' or 'Disputed' once the vote is over
+    resolution VARCHAR(32),
+    resolved_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+ALTER TABLE mission_outcome_proposals
+ADD CONSTRAINT fk_outcome_proposal_mission FOREIGN KEY (mission_id) REFERENCES missions(id) ON DELETE CASCADE,
+ADD CONSTRAINT fk_outcome_proposal_brawler FOREIGN KEY (proposed_by) REFERENCES brawlers(id),
+ADD CONSTRAINT chk_outcome_proposal_outcome CHECK (outcome IN ('Completed', 'Failed')),
+ADD CONSTRAINT chk_outcome_proposal_votes CHECK (required_votes >= 1);
+
+-- At most one vote open per mission
+CREATE UNIQUE INDEX uq_outcome_proposals_open ON mission_outcome_proposals (mission_id)
+WHERE resolved_at IS NULL;
+CREATE INDEX idx_outcome_proposals_closes_at ON mission_outcome_proposals (closes_at)
+WHERE resolved_at IS NULL;
+
+CREATE TABLE mission_outcome_votes (
+    proposal_id INTEGER NOT NULL,
+    brawler_id INTEGER NOT NULL,
+    agree BOOLEAN NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    PRIMARY KEY (proposal_id, brawler_id)
+);
+
+ALTER TABLE mission_outcome_votes
+ADD CONSTRAINT fk_outcome_vote_proposal FOREIGN KEY (proposal_id) REFERENCES mission_outcome_proposals(id) ON DELETE CASCADE,
+ADD CONSTRAINT fk_outcome_vote_brawler FOREIGN KEY (brawler_id) REFERENCES brawlers(id);
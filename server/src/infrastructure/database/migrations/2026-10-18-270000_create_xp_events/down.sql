@@ -0,0 +1,4 @@
+DROP TABLE IF EXISTS xp_events;
+
+ALTER TABLE brawlers
+DROP COLUMN IF EXISTS xp;
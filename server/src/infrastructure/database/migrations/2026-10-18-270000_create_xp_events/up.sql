@@ -0,0 +1,22 @@
+-- Running total so profiles and the crew list do not sum the ledger on every read. The
+-- level and rank are not stored, they follow from the XP and the configured rules.
+ALTER TABLE brawlers
+ADD COLUMN xp INTEGER NOT NULL DEFAULT 0;
+
+CREATE TABLE xp_events (
+    id SERIAL PRIMARY KEY,
+    brawler_id INTEGER NOT NULL,
+    -- Kept when the mission is purged so the totals still add up
+    mission_id INTEGER,
+    reason VARCHAR(32) NOT NULL,
+    amount INTEGER NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+ALTER TABLE xp_events
+ADD CONSTRAINT fk_xp_event_brawler FOREIGN KEY (brawler_id) REFERENCES brawlers(id) ON DELETE CASCADE,
+ADD CONSTRAINT fk_xp_event_mission FOREIGN KEY (mission_id) REFERENCES missions(id) ON DELETE SET NULL,
+ADD CONSTRAINT chk_xp_event_amount CHECK (amount >= 0),
+ADD CONSTRAINT uq_xp_event_once UNIQUE (brawler_id, mission_id, reason);
+
+CREATE INDEX idx_xp_events_mission_id ON xp_events (mission_id);
@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS leaderboard_refreshes;
+DROP MATERIALIZED VIEW IF EXISTS leaderboards;
@@ -0,0 +1,79 @@
+-- Every board and time window ranked at once, rebuilt by the scheduler with
+-- REFRESH MATERIALIZED VIEW CONCURRENTLY so readers never wait on it.
+-- The last_* windows roll with the refresh, the this_* ones start on the first day of the
+-- current calendar week (Monday) or month. changed_at and created_at are filled by
+-- DEFAULT now() into zoneless columns, so the bounds come from now() the same way.
+CREATE MATERIALIZED VIEW leaderboards AS
+WITH windows (time_window, since) AS (
+    VALUES ('all_time', NULL::timestamp),
+           ('last_30_days', now()::timestamp - INTERVAL '30 days'),
+           ('last_7_days', now()::timestamp - INTERVAL '7 days'),
+           ('this_month', date_trunc('month', now()::timestamp)),
+           ('this_week', date_trunc('week', now()::timestamp))
+),
+-- When each ended mission reached the status it still has
+finished AS (
+    SELECT DISTINCT ON (m.id) m.id AS mission_id, m.chief_id, m.status, h.changed_at AS finished_at
+    FROM missions m
+    INNER JOIN mission_status_history h ON h.mission_id = m.id AND h.to_status = m.status
+    WHERE m.status IN ('Completed', 'Failed')
+        AND m.deleted_at IS NULL
+    ORDER BY m.id, h.changed_at DESC
+),
+participations AS (
+    SELECT f.mission_id, f.chief_id AS brawler_id, true AS is_chief, f.status, f.finished_at
+    FROM finished f
+    UNION ALL
+    SELECT f.mission_id, cm.brawler_id, false AS is_chief, f.status, f.finished_at
+    FROM finished f
+    INNER JOIN crew_memberships cm ON cm.mission_id = f.mission_id
+),
+mission_stats AS (
+    SELECT w.time_window,
+            p.brawler_id,
+            COUNT(*) FILTER (WHERE p.status = 'Completed') AS completed_count,
+            COUNT(*) AS finished_count,
+            COUNT(*) FILTER (WHERE p.is_chief) AS led_count
+    FROM windows w
+    INNER JOIN participations p ON w.since IS NULL OR p.finished_at >= w.since
+    GROUP BY w.time_window, p.brawler_id
+),
+scores (board, time_window, brawler_id, score) AS (
+    SELECT 'completed_missions', time_window, brawler_id, completed_count::float8
+    FROM mission_stats
+    WHERE completed_count > 0
+    UNION ALL
+    -- A couple of lucky missions should not top the board
+    SELECT 'success_rate', time_window, brawler_id,
+            ROUND(completed_count * 100.0 / finished_count, 2)::float8
+    FROM mission_stats
+    WHERE finished_count >= 3
+    UNION ALL
+    SELECT 'missions_led', time_window, brawler_id, led_count::float8
+    FROM mission_stats
+    WHERE led_count > 0
+    UNION ALL
+    SELECT 'xp', w.time_window, e.brawler_id, SUM(e.amount)::float8
+    FROM windows w
+    INNER JOIN xp_events e ON w.since IS NULL OR e.created_at >= w.since
+    GROUP BY w.time_window, e.brawler_id
+    HAVING SUM(e.amount) > 0
+)
+SELECT board,
+        time_window,
+        brawler_id,
+        score,
+        RANK() OVER (PARTITION BY board, time_window ORDER BY score DESC)::int4 AS position
+FROM scores;
+
+-- Needed by REFRESH ... CONCURRENTLY, and serves the reads of one board
+CREATE UNIQUE INDEX uq_leaderboards_entry ON leaderboards (board, time_window, brawler_id);
+CREATE INDEX idx_leaderboards_position ON leaderboards (board, time_window, position);
+
+-- When the boards were last rebuilt, a single row written in the same transaction as the
+-- refresh so it is known even when every board is empty
+CREATE TABLE leaderboard_refreshes (
+    id BOOLEAN PRIMARY KEY DEFAULT true,
+    refreshed_at TIMESTAMP NOT NULL,
+    CONSTRAINT chk_leaderboard_refreshes_single_row CHECK (id)
+);
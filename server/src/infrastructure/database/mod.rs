@@ -1,3 +1,4 @@
 pub mod postgresql_connection;
 pub mod repositories;
 pub mod schema;
+pub mod sql_types;
@@ -1,23 +1,23 @@
-use anyhow::{Ok, Result};
 use async_trait::async_trait;
-use chrono::{Duration, Utc};
 // use diesel::{
 //     ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper, insert_into,
 //     query_dsl::methods::{FilterDsl, SelectDsl},
 // };
 use diesel::{dsl::insert_into, prelude::*};
-use diesel::result::{Error, DatabaseErrorKind};
 use std::sync::Arc;
 
 use crate::{
-    config::config_loader::get_jwt_env,
     domain::{
         entities::brawlers::{BrawlerEntity, RegisterBrawlerEntity},
+        errors::{AppError, AppResult},
         repositories::brawlers::BrawlerRepository,
         value_objects::{
             base64_img::Base64Img,
+            brawler_progression::Progression,
+            mission_filter::MissionFilter,
             mission_model::MissionModel,
-            profile_stats::ProfileStats,
+            pagination::{MissionPageQuery, Page},
+            profile_stats::ProfileStatsRow,
             uploaded_img::UploadedImg,
         },
     },
@@ -25,12 +25,12 @@ use crate::{
         cloudinary::{self, UploadImageOptions},
         database::{
             postgresql_connection::PgPoolSquad,
+            repositories::{
+                mission_debrief::load_reputation, mission_listing::load_mission_page,
+            },
             schema::{brawlers, crew_memberships, missions},
         },
-        jwt::{
-            generate_token,
-            jwt_model::{Claims, Passport},
-        },
+        jwt::jwt_model::Passport,
     },
 };
 
@@ -46,54 +46,45 @@ impl BrawlerPostgres {
 
 #[async_trait]
 impl BrawlerRepository for BrawlerPostgres {
-    async fn register(&self, register_brawler_entity: RegisterBrawlerEntity) -> Result<Passport> {
+    async fn register(&self, register_brawler_entity: RegisterBrawlerEntity) -> AppResult<Passport> {
         let pool = Arc::clone(&self.db_pool);
 
-        let (user_id, display_name) = tokio::task::spawn_blocking(move || -> Result<(i32, String)> {
-            let mut connection = pool.get().map_err(|e| anyhow::anyhow!(e))?;
+        let (user_id, display_name) = tokio::task::spawn_blocking(move || -> AppResult<(i32, String)> {
+            let mut connection = pool.get()?;
             let display_name = register_brawler_entity.display_name.clone();
-            
-            let id = match insert_into(brawlers::table)
+
+            let id = insert_into(brawlers::table)
                 .values(&register_brawler_entity)
                 .returning(brawlers::id)
                 .get_result::<i32>(&mut connection)
-            {
-                std::result::Result::Ok(id) => id,
-                Err(Error::DatabaseError(DatabaseErrorKind::UniqueViolation, _)) => {
-                    return Err(anyhow::anyhow!("Username already exists"));
-                }
-                Err(e) => return Err(e.into()),
-            };
-            
+                .map_err(|e| match AppError::from(e) {
+                    AppError::Conflict(_) => AppError::Conflict("Username already exists".to_string()),
+                    e => e,
+                })?;
+
             Ok((id, display_name))
         })
         .await??;
 
-        let jwt_env = tokio::task::spawn_blocking(get_jwt_env).await??;
-        let claims = Claims {
-            sub: user_id.to_string(),
-            exp: (Utc::now() + Duration::days(jwt_env.ttl)).timestamp() as usize,
-            iat: Utc::now().timestamp() as usize,
-        };
-        let token = generate_token(jwt_env.secret, &claims)?;
-        Ok(Passport {
-            token,
-            display_name,
-            avatar_url: None,
-            id: user_id,
+        let passport = tokio::task::spawn_blocking(move || {
+            Passport::new(user_id, 0, display_name, None, Progression::default())
         })
+        .await??;
+        Ok(passport)
     }
 
-    async fn find_by_username(&self, username: String) -> Result<BrawlerEntity> {
+    async fn find_by_username(&self, username: String) -> AppResult<BrawlerEntity> {
         let pool = Arc::clone(&self.db_pool);
 
-        let result = tokio::task::spawn_blocking(move || -> Result<BrawlerEntity> {
-            let mut connection = pool.get().map_err(|e| anyhow::anyhow!(e))?;
-            brawlers::table
+        let result = tokio::task::spawn_blocking(move || -> AppResult<BrawlerEntity> {
+            let mut connection = pool.get()?;
+            let brawler = brawlers::table
                 .filter(brawlers::username.eq(username))
                 .select(BrawlerEntity::as_select())
                 .first::<BrawlerEntity>(&mut connection)
-                .map_err(|e| anyhow::anyhow!(e))
+                .optional()?
+                .ok_or_else(|| AppError::NotFound("Brawler not found".to_string()))?;
+            Ok(brawler)
         })
         .await??;
 
@@ -105,7 +96,7 @@ impl BrawlerRepository for BrawlerPostgres {
         user_id: i32,
         base64img: Base64Img,
         opt: UploadImageOptions,
-    ) -> Result<UploadedImg> {
+    ) -> AppResult<UploadedImg> {
         let uploaded_img = cloudinary::upload(base64img, opt).await?;
 
         let mut conn = Arc::clone(&self.db_pool).get()?;
@@ -121,66 +112,51 @@ impl BrawlerRepository for BrawlerPostgres {
         Ok(uploaded_img)
     }
 
-    async fn get_missions(&self, brawler_id: i32) -> Result<Vec<MissionModel>> {
+    async fn get_missions(
+        &self,
+        brawler_id: i32,
+        page: &MissionPageQuery,
+    ) -> AppResult<Page<MissionModel>> {
         let pool = Arc::clone(&self.db_pool);
+        let page = page.clone();
 
-        let results = tokio::task::spawn_blocking(move || -> Result<Vec<MissionModel>> {
-            let mut conn = pool.get().map_err(|e| anyhow::anyhow!(e))?;
-            // Use a raw SQL query to select the MissionModel fields including
-            // the chief's display name and the crew count.
-            let sql = r#"
-    SELECT
-        missions.id,
-        missions.name,
-        missions.description,
-        missions.status,
-        missions.difficulty,
-        missions.chief_id,
-        brawlers.display_name AS chief_display_name,
-        (SELECT COUNT(*) FROM crew_memberships WHERE crew_memberships.mission_id = missions.id) AS crew_count,
-        missions.max_crew,
-        missions.created_at,
-        missions.updated_at
-    FROM missions
-    LEFT JOIN brawlers ON brawlers.id = missions.chief_id
-    WHERE missions.deleted_at IS NULL
-        AND missions.chief_id = $1
-    ORDER BY missions.created_at DESC
-            "#;
-
-            diesel::sql_query(sql)
-                .bind::<diesel::sql_types::Int4, _>(brawler_id)
-                .load::<MissionModel>(&mut conn)
-                .map_err(|e| anyhow::anyhow!(e))
+        let results = tokio::task::spawn_blocking(move || -> AppResult<Page<MissionModel>> {
+            let mut conn = pool.get()?;
+            let filter = MissionFilter {
+                chief_id: Some(brawler_id),
+                ..MissionFilter::default()
+            };
+            let page = load_mission_page(&mut conn, &filter, None, &page)?;
+            Ok(page.map(|item| item.mission))
         })
         .await??;
 
         Ok(results)
     }
 
-    async fn crew_counting(&self, mission_id: i32) -> Result<u32> {
+    async fn crew_counting(&self, mission_id: i32) -> AppResult<u32> {
         let pool = Arc::clone(&self.db_pool);
 
-        let result = tokio::task::spawn_blocking(move || -> Result<i64> {
-            let mut conn = pool.get().map_err(|e| anyhow::anyhow!(e))?;
-            crew_memberships::table
+        let result = tokio::task::spawn_blocking(move || -> AppResult<i64> {
+            let mut conn = pool.get()?;
+            let count = crew_memberships::table
                 .filter(crew_memberships::mission_id.eq(mission_id))
                 .count()
-                .first::<i64>(&mut conn)
-                .map_err(|e| anyhow::anyhow!(e))
+                .first::<i64>(&mut conn)?;
+            Ok(count)
         })
         .await??;
 
-        let count = u32::try_from(result)?;
+        let count = u32::try_from(result).map_err(anyhow::Error::from)?;
 
         Ok(count)
     }
 
-    async fn get_profile_stats(&self, brawler_id: i32) -> Result<ProfileStats> {
+    async fn get_profile_stats(&self, brawler_id: i32) -> AppResult<ProfileStatsRow> {
         let pool = Arc::clone(&self.db_pool);
 
-        let stats = tokio::task::spawn_blocking(move || -> Result<ProfileStats> {
-            let mut conn = pool.get().map_err(|e| anyhow::anyhow!(e))?;
+        let stats = tokio::task::spawn_blocking(move || -> AppResult<ProfileStatsRow> {
+            let mut conn = pool.get()?;
             use crate::domain::value_objects::mission_statuses::MissionStatuses;
 
             // Count missions created by the user
@@ -198,7 +174,7 @@ impl BrawlerRepository for BrawlerPostgres {
 
             let completed_as_chief = missions::table
                 .filter(missions::chief_id.eq(brawler_id))
-                .filter(missions::status.eq(MissionStatuses::Completed.to_string()))
+                .filter(missions::status.eq(MissionStatuses::Completed))
                 .filter(missions::deleted_at.is_null())
                 .count()
                 .get_result::<i64>(&mut conn)?;
@@ -206,15 +182,24 @@ impl BrawlerRepository for BrawlerPostgres {
             let completed_as_crew = crew_memberships::table
                 .inner_join(missions::table)
                 .filter(crew_memberships::brawler_id.eq(brawler_id))
-                .filter(missions::status.eq(MissionStatuses::Completed.to_string()))
+                .filter(missions::status.eq(MissionStatuses::Completed))
                 .filter(missions::deleted_at.is_null())
                 .count()
                 .get_result::<i64>(&mut conn)?;
 
-            Ok(ProfileStats {
+            let reputation = load_reputation(&mut conn, brawler_id)?;
+
+            let xp = brawlers::table
+                .filter(brawlers::id.eq(brawler_id))
+                .select(brawlers::xp)
+                .first::<i32>(&mut conn)?;
+
+            Ok(ProfileStatsRow {
                 created_count,
                 joined_count,
                 completed_count: completed_as_chief + completed_as_crew,
+                reputation,
+                xp,
             })
         })
         .await??;
@@ -222,11 +207,11 @@ impl BrawlerRepository for BrawlerPostgres {
         Ok(stats)
     }
 
-    async fn update_username(&self, brawler_id: i32, new_username: String) -> Result<()> {
+    async fn update_username(&self, brawler_id: i32, new_username: String) -> AppResult<()> {
         let pool = Arc::clone(&self.db_pool);
 
-        tokio::task::spawn_blocking(move || -> Result<()> {
-            let mut conn = pool.get().map_err(|e| anyhow::anyhow!(e))?;
+        tokio::task::spawn_blocking(move || -> AppResult<()> {
+            let mut conn = pool.get()?;
             diesel::update(brawlers::table)
                 .filter(brawlers::id.eq(brawler_id))
                 .set(brawlers::display_name.eq(new_username))
@@ -238,11 +223,11 @@ impl BrawlerRepository for BrawlerPostgres {
         Ok(())
     }
 
-    async fn update_avatar_url(&self, brawler_id: i32, url: String) -> Result<()> {
+    async fn update_avatar_url(&self, brawler_id: i32, url: String) -> AppResult<()> {
         let pool = Arc::clone(&self.db_pool);
 
-        tokio::task::spawn_blocking(move || -> Result<()> {
-            let mut conn = pool.get().map_err(|e| anyhow::anyhow!(e))?;
+        tokio::task::spawn_blocking(move || -> AppResult<()> {
+            let mut conn = pool.get()?;
             diesel::update(brawlers::table)
                 .filter(brawlers::id.eq(brawler_id))
                 .set(brawlers::avatar_url.eq(url))
@@ -254,15 +239,17 @@ impl BrawlerRepository for BrawlerPostgres {
         Ok(())
     }
 
-    async fn find_by_id(&self, brawler_id: i32) -> Result<BrawlerEntity> {
+    async fn find_by_id(&self, brawler_id: i32) -> AppResult<BrawlerEntity> {
         let pool = Arc::clone(&self.db_pool);
 
-        let brawler = tokio::task::spawn_blocking(move || -> Result<BrawlerEntity> {
-            let mut conn = pool.get().map_err(|e| anyhow::anyhow!(e))?;
-            brawlers::table
+        let brawler = tokio::task::spawn_blocking(move || -> AppResult<BrawlerEntity> {
+            let mut conn = pool.get()?;
+            let brawler = brawlers::table
                 .filter(brawlers::id.eq(brawler_id))
                 .first::<BrawlerEntity>(&mut conn)
-                .map_err(|e| anyhow::anyhow!(e))
+                .optional()?
+                .ok_or_else(|| AppError::NotFound("Brawler not found".to_string()))?;
+            Ok(brawler)
         })
         .await??;
 
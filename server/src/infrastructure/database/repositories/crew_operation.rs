@@ -1,16 +1,165 @@
-use anyhow::{Ok, Result};
 use async_trait::async_trait;
-use diesel::{ExpressionMethods, RunQueryDsl, dsl::delete, insert_into};
+use diesel::{
+    Connection, ExpressionMethods, OptionalExtension, PgConnection, QueryDsl, Queryable,
+    RunQueryDsl, dsl::delete, insert_into,
+};
 use std::sync::Arc;
 
 use crate::{
     domain::{
-        entities::crew_memberships::CrewMemberShips,
+        entities::{crew_memberships::CrewMemberShips, crew_removals::AddCrewRemovalEntity},
+        errors::{AppError, AppResult},
         repositories::crew_operation::CrewOperationRepository,
+        value_objects::{
+            crew_requirement::CrewRequirement, mission_join_policy::MissionJoinPolicy,
+            mission_statuses::MissionStatuses,
+        },
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        repositories::mission_waitlist::promote_from_waitlist,
+        schema::{crew_memberships, crew_removals, mission_waitlist, missions},
     },
-    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::crew_memberships},
 };
 
+#[derive(Debug, Clone, Queryable)]
+pub struct LockedMission {
+    pub chief_id: i32,
+    pub status: MissionStatuses,
+    pub min_crew: i32,
+    pub max_crew: i32,
+    pub join_policy: MissionJoinPolicy,
+}
+
+// Locking the mission row serializes everything that changes its crew, so capacity checks
+// made afterwards in the same transaction can not be invalidated by a concurrent request.
+pub fn lock_mission(conn: &mut PgConnection, mission_id: i32) -> AppResult<LockedMission> {
+    let mission = missions::table
+        .filter(missions::id.eq(mission_id))
+        .filter(missions::deleted_at.is_null())
+        .select((
+            missions::chief_id,
+            missions::status,
+            missions::min_crew,
+            missions::max_crew,
+            missions::join_policy,
+        ))
+        .for_update()
+        .first::<LockedMission>(conn)
+        .optional()?
+        .ok_or_else(|| AppError::NotFound(format!("Mission {} not found", mission_id)))?;
+    Ok(mission)
+}
+
+pub fn is_banned(conn: &mut PgConnection, mission_id: i32, brawler_id: i32) -> AppResult<bool> {
+    let bans = crew_removals::table
+        .filter(crew_removals::mission_id.eq(mission_id))
+        .filter(crew_removals::brawler_id.eq(brawler_id))
+        .filter(crew_removals::banned.eq(true))
+        .count()
+        .get_result::<i64>(conn)?;
+    Ok(bans > 0)
+}
+
+pub fn ensure_not_banned(
+    conn: &mut PgConnection,
+    mission_id: i32,
+    brawler_id: i32,
+) -> AppResult<()> {
+    if is_banned(conn, mission_id, brawler_id)? {
+        return Err(AppError::Forbidden(
+            "You have been banned from this mission".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+// Kicks and chief transfers are only allowed while the mission is still running
+pub fn ensure_crew_can_change(mission: &LockedMission) -> AppResult<()> {
+    if !matches!(
+        mission.status,
+        MissionStatuses::Open | MissionStatuses::InProgress
+    ) {
+        return Err(AppError::Conflict(format!(
+            "Crew can not be changed once the mission is {}",
+            mission.status
+        )));
+    }
+    Ok(())
+}
+
+pub fn count_crew(conn: &mut PgConnection, mission_id: i32) -> AppResult<i64> {
+    let count = crew_memberships::table
+        .filter(crew_memberships::mission_id.eq(mission_id))
+        .count()
+        .get_result::<i64>(conn)?;
+    Ok(count)
+}
+
+// Shared by the chief's start and the in-progress operation so both agree on when a mission
+// is ready to go. The caller must hold the lock from `lock_mission` for `mission`, so nobody
+// can leave between the count and the status change.
+pub fn ensure_crew_can_start(
+    conn: &mut PgConnection,
+    mission_id: i32,
+    mission: &LockedMission,
+) -> AppResult<()> {
+    let crew_count = count_crew(conn, mission_id)?;
+    CrewRequirement::new(mission.min_crew, mission.max_crew).ensure_met_by(crew_count)
+}
+
+// Shared by direct joins, approved requests and accepted invitations. The caller must
+// hold the lock from `lock_mission` for `mission`.
+pub fn add_crew_member(
+    conn: &mut PgConnection,
+    mission: &LockedMission,
+    crew_member_ships: CrewMemberShips,
+) -> AppResult<()> {
+    let mission_id = crew_member_ships.mission_id;
+    let brawler_id = crew_member_ships.brawler_id;
+
+    if mission.chief_id == brawler_id {
+        return Err(AppError::Forbidden(
+            "The Chief can not join in his own mission as a crew member!!".to_string(),
+        ));
+    }
+
+    let already_joined = crew_memberships::table
+        .filter(crew_memberships::mission_id.eq(mission_id))
+        .filter(crew_memberships::brawler_id.eq(brawler_id))
+        .count()
+        .get_result::<i64>(conn)?;
+    if already_joined > 0 {
+        return Err(AppError::Conflict(
+            "You have already joined this mission!!".to_string(),
+        ));
+    }
+
+    if mission.status != MissionStatuses::Open {
+        return Err(AppError::Conflict("Mission is not joinable".to_string()));
+    }
+
+    ensure_not_banned(conn, mission_id, brawler_id)?;
+
+    if count_crew(conn, mission_id)? >= mission.max_crew as i64 {
+        return Err(AppError::MissionFull(
+            "Mission is full, join its waitlist instead".to_string(),
+        ));
+    }
+
+    insert_into(crew_memberships::table)
+        .values(crew_member_ships)
+        .execute(conn)?;
+
+    // A brawler who got in directly no longer needs a place in the queue
+    delete(mission_waitlist::table)
+        .filter(mission_waitlist::mission_id.eq(mission_id))
+        .filter(mission_waitlist::brawler_id.eq(brawler_id))
+        .execute(conn)?;
+
+    Ok(())
+}
+
 pub struct CrewOperationPostgres {
     db_pool: Arc<PgPoolSquad>,
 }
@@ -23,20 +172,91 @@ impl CrewOperationPostgres {
 
 #[async_trait]
 impl CrewOperationRepository for CrewOperationPostgres {
-    async fn join(&self, crew_member_ships: CrewMemberShips) -> Result<()> {
-        let mut conn = Arc::clone(&self.db_pool).get()?;
-        insert_into(crew_memberships::table)
-            .values(crew_member_ships)
-            .execute(&mut conn)?;
+    async fn join(&self, crew_member_ships: CrewMemberShips) -> AppResult<()> {
+        let pool = Arc::clone(&self.db_pool);
+
+        tokio::task::spawn_blocking(move || -> AppResult<()> {
+            let mut conn = pool.get()?;
+            conn.transaction::<_, AppError, _>(|conn| {
+                let mission_id = crew_member_ships.mission_id;
+
+                let mission = lock_mission(conn, mission_id)?;
+
+                if mission.join_policy != MissionJoinPolicy::Open {
+                    return Err(AppError::Conflict(
+                        "Mission is no longer open to join, it was changed by another request"
+                            .to_string(),
+                    ));
+                }
+
+                add_crew_member(conn, &mission, crew_member_ships)
+            })
+        })
+        .await??;
+
         Ok(())
     }
 
-    async fn leave(&self, crew_member_ships: CrewMemberShips) -> Result<()> {
-        let mut conn = Arc::clone(&self.db_pool).get()?;
-        delete(crew_memberships::table)
-            .filter(crew_memberships::brawler_id.eq(crew_member_ships.brawler_id))
-            .filter(crew_memberships::mission_id.eq(crew_member_ships.mission_id))
-            .execute(&mut conn)?;
+    async fn leave(&self, crew_member_ships: CrewMemberShips) -> AppResult<()> {
+        let pool = Arc::clone(&self.db_pool);
+
+        tokio::task::spawn_blocking(move || -> AppResult<()> {
+            let mut conn = pool.get()?;
+            conn.transaction::<_, AppError, _>(|conn| {
+                lock_mission(conn, crew_member_ships.mission_id)?;
+
+                let removed = delete(crew_memberships::table)
+                    .filter(crew_memberships::brawler_id.eq(crew_member_ships.brawler_id))
+                    .filter(crew_memberships::mission_id.eq(crew_member_ships.mission_id))
+                    .execute(conn)?;
+                if removed > 0 {
+                    promote_from_waitlist(conn, crew_member_ships.mission_id)?;
+                }
+                Ok(())
+            })
+        })
+        .await??;
+
+        Ok(())
+    }
+
+    async fn kick(&self, add_crew_removal_entity: AddCrewRemovalEntity) -> AppResult<()> {
+        let pool = Arc::clone(&self.db_pool);
+
+        tokio::task::spawn_blocking(move || -> AppResult<()> {
+            let mut conn = pool.get()?;
+            conn.transaction::<_, AppError, _>(|conn| {
+                let mission_id = add_crew_removal_entity.mission_id;
+                let mission = lock_mission(conn, mission_id)?;
+
+                if mission.chief_id != add_crew_removal_entity.removed_by {
+                    return Err(AppError::Forbidden(
+                        "Only the chief can remove crew members".to_string(),
+                    ));
+                }
+
+                ensure_crew_can_change(&mission)?;
+
+                let removed = delete(crew_memberships::table)
+                    .filter(crew_memberships::mission_id.eq(mission_id))
+                    .filter(crew_memberships::brawler_id.eq(add_crew_removal_entity.brawler_id))
+                    .execute(conn)?;
+                if removed == 0 {
+                    return Err(AppError::NotFound(
+                        "Brawler is not a crew member of this mission".to_string(),
+                    ));
+                }
+
+                insert_into(crew_removals::table)
+                    .values(add_crew_removal_entity)
+                    .execute(conn)?;
+
+                promote_from_waitlist(conn, mission_id)?;
+                Ok(())
+            })
+        })
+        .await??;
+
         Ok(())
     }
 }
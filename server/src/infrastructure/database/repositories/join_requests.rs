@@ -0,0 +1,429 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use diesel::{dsl::now, prelude::*};
+
+use crate::{
+    domain::{
+        entities::{
+            crew_memberships::CrewMemberShips,
+            join_requests::{AddJoinRequestEntity, AddMissionInvitationEntity},
+        },
+        errors::{AppError, AppResult},
+        repositories::join_requests::JoinRequestRepository,
+        value_objects::{
+            join_requests::{
+                InvitationStatuses, JoinRequestModel, JoinRequestStatuses, MissionInvitationModel,
+            },
+            mission_join_policy::MissionJoinPolicy,
+            mission_statuses::MissionStatuses,
+        },
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        repositories::crew_operation::{
+            add_crew_member, ensure_not_banned, is_banned, lock_mission,
+        },
+        schema::{brawlers, crew_memberships, join_requests, mission_invitations, missions},
+    },
+};
+
+pub struct JoinRequestPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl JoinRequestPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+fn ensure_chief(conn: &mut PgConnection, mission_id: i32, chief_id: i32) -> AppResult<()> {
+    let mission_chief_id = missions::table
+        .filter(missions::id.eq(mission_id))
+        .filter(missions::deleted_at.is_null())
+        .select(missions::chief_id)
+        .first::<i32>(conn)
+        .optional()?
+        .ok_or_else(|| AppError::NotFound(format!("Mission {} not found", mission_id)))?;
+
+    if mission_chief_id != chief_id {
+        return Err(AppError::Forbidden(
+            "Only the chief can manage who joins this mission".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+fn find_pending_request(conn: &mut PgConnection, request_id: i32) -> AppResult<(i32, i32)> {
+    let (mission_id, brawler_id, status) = join_requests::table
+        .filter(join_requests::id.eq(request_id))
+        .select((
+            join_requests::mission_id,
+            join_requests::brawler_id,
+            join_requests::status,
+        ))
+        .first::<(i32, i32, String)>(conn)
+        .optional()?
+        .ok_or_else(|| AppError::NotFound("Join request not found".to_string()))?;
+
+    if status != JoinRequestStatuses::Pending.to_string() {
+        return Err(AppError::Conflict(format!(
+            "Join request was already {}",
+            status.to_lowercase()
+        )));
+    }
+    Ok((mission_id, brawler_id))
+}
+
+// Compare-and-set on Pending so a request can only be decided once
+fn decide_request(
+    conn: &mut PgConnection,
+    request_id: i32,
+    decision: JoinRequestStatuses,
+) -> AppResult<()> {
+    let updated = diesel::update(join_requests::table)
+        .filter(join_requests::id.eq(request_id))
+        .filter(join_requests::status.eq(JoinRequestStatuses::Pending.to_string()))
+        .set((
+            join_requests::status.eq(decision.to_string()),
+            join_requests::decided_at.eq(now),
+        ))
+        .execute(conn)?;
+
+    if updated == 0 {
+        return Err(AppError::Conflict(
+            "Join request was already decided by another request".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl JoinRequestRepository for JoinRequestPostgres {
+    async fn request(&self, mission_id: i32, brawler_id: i32) -> AppResult<i32> {
+        let pool = Arc::clone(&self.db_pool);
+
+        let result = tokio::task::spawn_blocking(move || -> AppResult<i32> {
+            let mut conn = pool.get()?;
+            conn.transaction::<_, AppError, _>(|conn| {
+                let mission = lock_mission(conn, mission_id)?;
+
+                if mission.chief_id == brawler_id {
+                    return Err(AppError::Forbidden(
+                        "The Chief can not join in his own mission as a crew member!!".to_string(),
+                    ));
+                }
+
+                let already_joined = crew_memberships::table
+                    .filter(crew_memberships::mission_id.eq(mission_id))
+                    .filter(crew_memberships::brawler_id.eq(brawler_id))
+                    .count()
+                    .get_result::<i64>(conn)?;
+                if already_joined > 0 {
+                    return Err(AppError::Conflict(
+                        "You have already joined this mission!!".to_string(),
+                    ));
+                }
+
+                if mission.status != MissionStatuses::Open {
+                    return Err(AppError::Conflict("Mission is not joinable".to_string()));
+                }
+
+                if mission.join_policy != MissionJoinPolicy::ApprovalRequired {
+                    return Err(AppError::Conflict(
+                        "Mission does not take join requests".to_string(),
+                    ));
+                }
+
+                ensure_not_banned(conn, mission_id, brawler_id)?;
+
+                let id = diesel::insert_into(join_requests::table)
+                    .values(AddJoinRequestEntity {
+                        mission_id,
+                        brawler_id,
+                    })
+                    .returning(join_requests::id)
+                    .get_result::<i32>(conn)
+                    .map_err(|e| match AppError::from(e) {
+                        AppError::Conflict(_) => AppError::Conflict(
+                            "You already have a pending request for this mission".to_string(),
+                        ),
+                        e => e,
+                    })?;
+                Ok(id)
+            })
+        })
+        .await??;
+
+        Ok(result)
+    }
+
+    async fn get_pending_requests(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+    ) -> AppResult<Vec<JoinRequestModel>> {
+        let pool = Arc::clone(&self.db_pool);
+
+        let results = tokio::task::spawn_blocking(move || -> AppResult<Vec<JoinRequestModel>> {
+            let mut conn = pool.get()?;
+            ensure_chief(&mut conn, mission_id, chief_id)?;
+
+            let sql = r#"
+SELECT r.id,
+        r.mission_id,
+        r.brawler_id,
+        COALESCE(b.display_name, '') AS brawler_display_name,
+        r.status,
+        r.created_at,
+        r.decided_at
+FROM join_requests r
+LEFT JOIN brawlers b ON b.id = r.brawler_id
+WHERE r.mission_id = $1
+    AND r.status = 'Pending'
+ORDER BY r.created_at ASC, r.id ASC
+            "#;
+
+            let requests = diesel::sql_query(sql)
+                .bind::<diesel::sql_types::Int4, _>(mission_id)
+                .load::<JoinRequestModel>(&mut conn)?;
+            Ok(requests)
+        })
+        .await??;
+
+        Ok(results)
+    }
+
+    async fn approve(&self, request_id: i32, chief_id: i32) -> AppResult<()> {
+        let pool = Arc::clone(&self.db_pool);
+
+        tokio::task::spawn_blocking(move || -> AppResult<()> {
+            let mut conn = pool.get()?;
+            conn.transaction::<_, AppError, _>(|conn| {
+                let (mission_id, brawler_id) = find_pending_request(conn, request_id)?;
+
+                let mission = lock_mission(conn, mission_id)?;
+                if mission.chief_id != chief_id {
+                    return Err(AppError::Forbidden(
+                        "Only the chief can manage who joins this mission".to_string(),
+                    ));
+                }
+
+                add_crew_member(
+                    conn,
+                    &mission,
+                    CrewMemberShips {
+                        mission_id,
+                        brawler_id,
+                    },
+                )?;
+
+                decide_request(conn, request_id, JoinRequestStatuses::Approved)
+            })
+        })
+        .await??;
+
+        Ok(())
+    }
+
+    async fn reject(&self, request_id: i32, chief_id: i32) -> AppResult<()> {
+        let pool = Arc::clone(&self.db_pool);
+
+        tokio::task::spawn_blocking(move || -> AppResult<()> {
+            let mut conn = pool.get()?;
+            conn.transaction::<_, AppError, _>(|conn| {
+                let (mission_id, _) = find_pending_request(conn, request_id)?;
+                ensure_chief(conn, mission_id, chief_id)?;
+
+                decide_request(conn, request_id, JoinRequestStatuses::Rejected)
+            })
+        })
+        .await??;
+
+        Ok(())
+    }
+
+    async fn invite(&self, mission_id: i32, chief_id: i32, brawler_id: i32) -> AppResult<i32> {
+        let pool = Arc::clone(&self.db_pool);
+
+        let result = tokio::task::spawn_blocking(move || -> AppResult<i32> {
+            let mut conn = pool.get()?;
+            conn.transaction::<_, AppError, _>(|conn| {
+                let mission = lock_mission(conn, mission_id)?;
+
+                if mission.chief_id != chief_id {
+                    return Err(AppError::Forbidden(
+                        "Only the chief can manage who joins this mission".to_string(),
+                    ));
+                }
+
+                if brawler_id == chief_id {
+                    return Err(AppError::Validation(
+                        "The Chief can not invite himself".to_string(),
+                    ));
+                }
+
+                if mission.status != MissionStatuses::Open {
+                    return Err(AppError::Conflict("Mission is not joinable".to_string()));
+                }
+
+                let brawler_exists = brawlers::table
+                    .filter(brawlers::id.eq(brawler_id))
+                    .count()
+                    .get_result::<i64>(conn)?;
+                if brawler_exists == 0 {
+                    return Err(AppError::NotFound("Brawler not found".to_string()));
+                }
+
+                let already_joined = crew_memberships::table
+                    .filter(crew_memberships::mission_id.eq(mission_id))
+                    .filter(crew_memberships::brawler_id.eq(brawler_id))
+                    .count()
+                    .get_result::<i64>(conn)?;
+                if already_joined > 0 {
+                    return Err(AppError::Conflict(
+                        "Brawler is already a crew member of this mission".to_string(),
+                    ));
+                }
+
+                if is_banned(conn, mission_id, brawler_id)? {
+                    return Err(AppError::Conflict(
+                        "Brawler is banned from this mission".to_string(),
+                    ));
+                }
+
+                let id = diesel::insert_into(mission_invitations::table)
+                    .values(AddMissionInvitationEntity {
+                        mission_id,
+                        brawler_id,
+                        invited_by: chief_id,
+                    })
+                    .returning(mission_invitations::id)
+                    .get_result::<i32>(conn)
+                    .map_err(|e| match AppError::from(e) {
+                        AppError::Conflict(_) => AppError::Conflict(
+                            "Brawler already has a pending invitation for this mission".to_string(),
+                        ),
+                        e => e,
+                    })?;
+                Ok(id)
+            })
+        })
+        .await??;
+
+        Ok(result)
+    }
+
+    async fn get_pending_invitations(
+        &self,
+        brawler_id: i32,
+    ) -> AppResult<Vec<MissionInvitationModel>> {
+        let pool = Arc::clone(&self.db_pool);
+
+        let results =
+            tokio::task::spawn_blocking(move || -> AppResult<Vec<MissionInvitationModel>> {
+                let mut conn = pool.get()?;
+
+                let sql = r#"
+SELECT i.id,
+        i.mission_id,
+        m.name AS mission_name,
+        i.invited_by,
+        COALESCE(b.display_name, '') AS invited_by_display_name,
+        i.status,
+        i.created_at,
+        i.responded_at
+FROM mission_invitations i
+INNER JOIN missions m ON m.id = i.mission_id
+LEFT JOIN brawlers b ON b.id = i.invited_by
+WHERE i.brawler_id = $1
+    AND i.status = 'Pending'
+    AND m.deleted_at IS NULL
+ORDER BY i.created_at DESC, i.id DESC
+                "#;
+
+                let invitations = diesel::sql_query(sql)
+                    .bind::<diesel::sql_types::Int4, _>(brawler_id)
+                    .load::<MissionInvitationModel>(&mut conn)?;
+                Ok(invitations)
+            })
+            .await??;
+
+        Ok(results)
+    }
+
+    async fn accept_invitation(&self, invitation_id: i32, brawler_id: i32) -> AppResult<()> {
+        let pool = Arc::clone(&self.db_pool);
+
+        tokio::task::spawn_blocking(move || -> AppResult<()> {
+            let mut conn = pool.get()?;
+            conn.transaction::<_, AppError, _>(|conn| {
+                let mission_id = mission_invitations::table
+                    .filter(mission_invitations::id.eq(invitation_id))
+                    .filter(mission_invitations::brawler_id.eq(brawler_id))
+                    .filter(mission_invitations::status.eq(InvitationStatuses::Pending.to_string()))
+                    .select(mission_invitations::mission_id)
+                    .first::<i32>(conn)
+                    .optional()?
+                    .ok_or_else(|| {
+                        AppError::NotFound("Pending invitation not found".to_string())
+                    })?;
+
+                let mission = lock_mission(conn, mission_id)?;
+                add_crew_member(
+                    conn,
+                    &mission,
+                    CrewMemberShips {
+                        mission_id,
+                        brawler_id,
+                    },
+                )?;
+
+                let updated = diesel::update(mission_invitations::table)
+                    .filter(mission_invitations::id.eq(invitation_id))
+                    .filter(mission_invitations::status.eq(InvitationStatuses::Pending.to_string()))
+                    .set((
+                        mission_invitations::status.eq(InvitationStatuses::Accepted.to_string()),
+                        mission_invitations::responded_at.eq(now),
+                    ))
+                    .execute(conn)?;
+                if updated == 0 {
+                    return Err(AppError::Conflict(
+                        "Invitation was already answered by another request".to_string(),
+                    ));
+                }
+                Ok(())
+            })
+        })
+        .await??;
+
+        Ok(())
+    }
+
+    async fn decline_invitation(&self, invitation_id: i32, brawler_id: i32) -> AppResult<()> {
+        let pool = Arc::clone(&self.db_pool);
+
+        tokio::task::spawn_blocking(move || -> AppResult<()> {
+            let mut conn = pool.get()?;
+            let updated = diesel::update(mission_invitations::table)
+                .filter(mission_invitations::id.eq(invitation_id))
+                .filter(mission_invitations::brawler_id.eq(brawler_id))
+                .filter(mission_invitations::status.eq(InvitationStatuses::Pending.to_string()))
+                .set((
+                    mission_invitations::status.eq(InvitationStatuses::Declined.to_string()),
+                    mission_invitations::responded_at.eq(now),
+                ))
+                .execute(&mut conn)?;
+            if updated == 0 {
+                return Err(AppError::NotFound(
+                    "Pending invitation not found".to_string(),
+                ));
+            }
+            Ok(())
+        })
+        .await??;
+
+        Ok(())
+    }
+}
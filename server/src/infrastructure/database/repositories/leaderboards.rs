@@ -0,0 +1,99 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use diesel::{
+    Connection, ExpressionMethods, OptionalExtension, QueryDsl, RunQueryDsl, insert_into,
+    sql_types::{Int4, Text},
+    upsert::excluded,
+};
+
+use crate::{
+    domain::{
+        errors::{AppError, AppResult},
+        repositories::leaderboards::LeaderboardRepository,
+        value_objects::leaderboards::{LeaderboardBoard, LeaderboardEntryModel, LeaderboardWindow},
+    },
+    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::leaderboard_refreshes},
+};
+
+pub struct LeaderboardPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl LeaderboardPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl LeaderboardRepository for LeaderboardPostgres {
+    // Names are read at request time so a rename shows before the next refresh
+    async fn get_entries(
+        &self,
+        board: LeaderboardBoard,
+        window: LeaderboardWindow,
+        places: i32,
+    ) -> AppResult<Vec<LeaderboardEntryModel>> {
+        let sql = r#"
+            SELECT l.position,
+                    l.brawler_id,
+                    b.display_name,
+                    COALESCE(b.avatar_url, '') AS avatar_url,
+                    l.score
+            FROM leaderboards l
+            INNER JOIN brawlers b ON b.id = l.brawler_id
+            WHERE l.board = $1
+                AND l.time_window = $2
+                AND l.position <= $3
+            ORDER BY l.position, b.display_name
+        "#;
+
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let entries = diesel::sql_query(sql)
+            .bind::<Text, _>(board.to_string())
+            .bind::<Text, _>(window.to_string())
+            .bind::<Int4, _>(places)
+            .load::<LeaderboardEntryModel>(&mut conn)?;
+
+        Ok(entries)
+    }
+
+    async fn refreshed_at(&self) -> AppResult<Option<NaiveDateTime>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let refreshed_at = leaderboard_refreshes::table
+            .select(leaderboard_refreshes::refreshed_at)
+            .first::<NaiveDateTime>(&mut conn)
+            .optional()?;
+
+        Ok(refreshed_at)
+    }
+
+    async fn refresh(&self, refreshed_at: NaiveDateTime) -> AppResult<()> {
+        let pool = Arc::clone(&self.db_pool);
+
+        tokio::task::spawn_blocking(move || -> AppResult<()> {
+            let mut conn = pool.get()?;
+            conn.transaction::<_, AppError, _>(|conn| {
+                diesel::sql_query("REFRESH MATERIALIZED VIEW CONCURRENTLY leaderboards")
+                    .execute(conn)?;
+
+                insert_into(leaderboard_refreshes::table)
+                    .values(leaderboard_refreshes::refreshed_at.eq(refreshed_at))
+                    .on_conflict(leaderboard_refreshes::id)
+                    .do_update()
+                    .set(
+                        leaderboard_refreshes::refreshed_at
+                            .eq(excluded(leaderboard_refreshes::refreshed_at)),
+                    )
+                    .execute(conn)?;
+                Ok(())
+            })
+        })
+        .await??;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,207 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use diesel::{
+    Connection, ExpressionMethods, OptionalExtension, PgConnection, QueryDsl, RunQueryDsl,
+    SelectableHelper, delete, insert_into,
+};
+use tracing::warn;
+
+use crate::{
+    domain::{
+        entities::mission_attachments::{AddMissionAttachmentEntity, MissionAttachmentEntity},
+        errors::{AppError, AppResult},
+        repositories::mission_attachments::MissionAttachmentRepository,
+        value_objects::{
+            base64_img::Base64Img,
+            mission_attachment::{MissionAttachmentKind, ensure_room_for_file},
+        },
+    },
+    infrastructure::{
+        cloudinary::{self, UploadImageOptions},
+        database::{
+            postgresql_connection::PgPoolSquad, repositories::crew_operation::lock_mission,
+            schema::mission_attachments,
+        },
+    },
+};
+
+// Deletes stored files whose rows are already gone. A file that can not be deleted is only
+// logged, it must not undo the database change that dropped it.
+pub async fn destroy_stored(public_ids: Vec<String>) {
+    for public_id in public_ids {
+        if let Err(e) = cloudinary::destroy(&public_id).await {
+            warn!("Failed to delete stored file {}: {}", public_id, e);
+        }
+    }
+}
+
+fn count_files(conn: &mut PgConnection, mission_id: i32) -> AppResult<i64> {
+    let count = mission_attachments::table
+        .filter(mission_attachments::mission_id.eq(mission_id))
+        .filter(mission_attachments::kind.eq(MissionAttachmentKind::File))
+        .count()
+        .get_result::<i64>(conn)?;
+    Ok(count)
+}
+
+pub struct MissionAttachmentPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl MissionAttachmentPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl MissionAttachmentRepository for MissionAttachmentPostgres {
+    async fn upload(
+        &self,
+        mission_id: i32,
+        uploaded_by: i32,
+        kind: MissionAttachmentKind,
+        file_name: String,
+        base64img: Base64Img,
+        opt: UploadImageOptions,
+    ) -> AppResult<MissionAttachmentEntity> {
+        let content_type = base64img.mime_type().to_string();
+        // Bounded by MAX_ATTACHMENT_BYTES long before it could overflow
+        let size_bytes = i32::try_from(base64img.size()).map_err(anyhow::Error::from)?;
+        let uploaded = cloudinary::upload(base64img, opt).await?;
+
+        let pool = Arc::clone(&self.db_pool);
+        let stored_public_id = uploaded.public_id.clone();
+        let result = tokio::task::spawn_blocking(
+            move || -> AppResult<(MissionAttachmentEntity, Option<String>)> {
+                let mut conn = pool.get()?;
+                conn.transaction::<_, AppError, _>(|conn| {
+                    // Concurrent uploads wait here so each one counts the files the
+                    // others added
+                    if kind == MissionAttachmentKind::File {
+                        lock_mission(conn, mission_id)?;
+                        ensure_room_for_file(count_files(conn, mission_id)?)?;
+                    }
+
+                    let replaced = if kind == MissionAttachmentKind::Cover {
+                        delete(mission_attachments::table)
+                            .filter(mission_attachments::mission_id.eq(mission_id))
+                            .filter(mission_attachments::kind.eq(MissionAttachmentKind::Cover))
+                            .returning(mission_attachments::public_id)
+                            .get_result::<String>(conn)
+                            .optional()?
+                    } else {
+                        None
+                    };
+
+                    let attachment = insert_into(mission_attachments::table)
+                        .values(AddMissionAttachmentEntity {
+                            mission_id,
+                            uploaded_by,
+                            kind,
+                            file_name,
+                            content_type,
+                            size_bytes,
+                            url: uploaded.url,
+                            public_id: uploaded.public_id,
+                        })
+                        .returning(MissionAttachmentEntity::as_returning())
+                        .get_result::<MissionAttachmentEntity>(conn)?;
+
+                    Ok((attachment, replaced))
+                })
+            },
+        )
+        .await?;
+
+        match result {
+            Ok((attachment, replaced)) => {
+                destroy_stored(replaced.into_iter().collect()).await;
+                Ok(attachment)
+            }
+            // Nothing points at the stored file anymore
+            Err(e) => {
+                destroy_stored(vec![stored_public_id]).await;
+                Err(e)
+            }
+        }
+    }
+
+    async fn get_all(&self, mission_id: i32) -> AppResult<Vec<MissionAttachmentEntity>> {
+        let pool = Arc::clone(&self.db_pool);
+
+        let results =
+            tokio::task::spawn_blocking(move || -> AppResult<Vec<MissionAttachmentEntity>> {
+                let mut conn = pool.get()?;
+                let attachments = mission_attachments::table
+                    .filter(mission_attachments::mission_id.eq(mission_id))
+                    .order((
+                        mission_attachments::kind.asc(),
+                        mission_attachments::id.asc(),
+                    ))
+                    .select(MissionAttachmentEntity::as_select())
+                    .load::<MissionAttachmentEntity>(&mut conn)?;
+                Ok(attachments)
+            })
+            .await??;
+
+        Ok(results)
+    }
+
+    async fn count_files(&self, mission_id: i32) -> AppResult<i64> {
+        let pool = Arc::clone(&self.db_pool);
+
+        let result = tokio::task::spawn_blocking(move || -> AppResult<i64> {
+            let mut conn = pool.get()?;
+            count_files(&mut conn, mission_id)
+        })
+        .await??;
+
+        Ok(result)
+    }
+
+    async fn remove(&self, attachment_id: i32, mission_id: i32) -> AppResult<()> {
+        let pool = Arc::clone(&self.db_pool);
+
+        let public_id = tokio::task::spawn_blocking(move || -> AppResult<String> {
+            let mut conn = pool.get()?;
+            let public_id = delete(mission_attachments::table)
+                .filter(mission_attachments::id.eq(attachment_id))
+                .filter(mission_attachments::mission_id.eq(mission_id))
+                .returning(mission_attachments::public_id)
+                .get_result::<String>(&mut conn)
+                .optional()?
+                .ok_or_else(|| {
+                    AppError::NotFound(format!("Attachment {} not found", attachment_id))
+                })?;
+            Ok(public_id)
+        })
+        .await??;
+
+        destroy_stored(vec![public_id]).await;
+        Ok(())
+    }
+
+    async fn remove_cover(&self, mission_id: i32) -> AppResult<()> {
+        let pool = Arc::clone(&self.db_pool);
+
+        let public_id = tokio::task::spawn_blocking(move || -> AppResult<String> {
+            let mut conn = pool.get()?;
+            let public_id = delete(mission_attachments::table)
+                .filter(mission_attachments::mission_id.eq(mission_id))
+                .filter(mission_attachments::kind.eq(MissionAttachmentKind::Cover))
+                .returning(mission_attachments::public_id)
+                .get_result::<String>(&mut conn)
+                .optional()?
+                .ok_or_else(|| {
+                    AppError::NotFound(format!("Mission {} has no cover image", mission_id))
+                })?;
+            Ok(public_id)
+        })
+        .await??;
+
+        destroy_stored(vec![public_id]).await;
+        Ok(())
+    }
+}
@@ -0,0 +1,267 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use diesel::{
+    Connection, ExpressionMethods, OptionalExtension, QueryDsl, QueryableByName, RunQueryDsl,
+    SelectableHelper, dsl, insert_into,
+    sql_types::{BigInt, Bool, Int4, Nullable},
+    update,
+};
+
+use crate::{
+    domain::{
+        entities::mission_comments::{AddMissionCommentEntity, MissionCommentEntity},
+        errors::{AppError, AppResult},
+        repositories::mission_comments::MissionCommentRepository,
+        value_objects::{
+            mission_comment::MissionCommentModel,
+            pagination::{CommentCursor, CommentPageQuery, Page},
+        },
+    },
+    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::mission_comments},
+};
+
+// Shared by the page query and its total count, which bind the mission as $1 and the
+// parent as $2. A deleted comment only stays listed while it still has replies.
+const COMMENT_FILTERS: &str = r#"
+    c.mission_id = $1
+    AND c.parent_id IS NOT DISTINCT FROM $2
+    AND (c.deleted_at IS NULL OR EXISTS (
+        SELECT 1 FROM mission_comments r WHERE r.parent_id = c.id
+    ))
+"#;
+
+#[derive(QueryableByName)]
+struct TotalRow {
+    #[diesel(sql_type = BigInt)]
+    total: i64,
+}
+
+pub struct MissionCommentPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl MissionCommentPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+fn comment_not_found(comment_id: i32) -> AppError {
+    AppError::NotFound(format!("Comment {} not found", comment_id))
+}
+
+#[async_trait]
+impl MissionCommentRepository for MissionCommentPostgres {
+    async fn add(&self, add_mission_comment_entity: AddMissionCommentEntity) -> AppResult<i32> {
+        let pool = Arc::clone(&self.db_pool);
+
+        let result = tokio::task::spawn_blocking(move || -> AppResult<i32> {
+            let mut conn = pool.get()?;
+            conn.transaction::<_, AppError, _>(|conn| {
+                // A reply has to stay in the thread of the same mission
+                if let Some(parent_id) = add_mission_comment_entity.parent_id {
+                    mission_comments::table
+                        .filter(mission_comments::id.eq(parent_id))
+                        .filter(
+                            mission_comments::mission_id.eq(add_mission_comment_entity.mission_id),
+                        )
+                        .filter(mission_comments::deleted_at.is_null())
+                        .select(mission_comments::id)
+                        .first::<i32>(conn)
+                        .optional()?
+                        .ok_or_else(|| comment_not_found(parent_id))?;
+                }
+
+                let id = insert_into(mission_comments::table)
+                    .values(add_mission_comment_entity)
+                    .returning(mission_comments::id)
+                    .get_result::<i32>(conn)?;
+                Ok(id)
+            })
+        })
+        .await??;
+
+        Ok(result)
+    }
+
+    async fn get_one(&self, comment_id: i32, mission_id: i32) -> AppResult<MissionCommentEntity> {
+        let pool = Arc::clone(&self.db_pool);
+
+        let result = tokio::task::spawn_blocking(move || -> AppResult<MissionCommentEntity> {
+            let mut conn = pool.get()?;
+            let comment = mission_comments::table
+                .filter(mission_comments::id.eq(comment_id))
+                .filter(mission_comments::mission_id.eq(mission_id))
+                .filter(mission_comments::deleted_at.is_null())
+                .select(MissionCommentEntity::as_select())
+                .first::<MissionCommentEntity>(&mut conn)
+                .optional()?
+                .ok_or_else(|| comment_not_found(comment_id))?;
+            Ok(comment)
+        })
+        .await??;
+
+        Ok(result)
+    }
+
+    async fn get_page(
+        &self,
+        mission_id: i32,
+        parent_id: Option<i32>,
+        page: &CommentPageQuery,
+    ) -> AppResult<Page<MissionCommentModel>> {
+        let pool = Arc::clone(&self.db_pool);
+        let page = page.clone();
+
+        let results =
+            tokio::task::spawn_blocking(move || -> AppResult<Page<MissionCommentModel>> {
+                let mut conn = pool.get()?;
+                let limit = page.page_size()?;
+                let cursor = page.decode_cursor()?;
+
+                // Pinned comments come first, the rest oldest first so a thread reads top down
+                let sql = format!(
+                    r#"
+SELECT c.id,
+        c.mission_id,
+        c.author_id,
+        COALESCE(b.display_name, '') AS author_display_name,
+        c.parent_id,
+        CASE WHEN c.deleted_at IS NULL THEN c.body END AS body,
+        c.pinned,
+        (SELECT COUNT(*) FROM mission_comments r WHERE r.parent_id = c.id) AS reply_count,
+        c.created_at,
+        c.edited_at,
+        c.deleted_at IS NOT NULL AS deleted
+FROM mission_comments c
+LEFT JOIN brawlers b ON b.id = c.author_id
+WHERE {COMMENT_FILTERS}
+    AND ($3::bool IS NULL OR (NOT c.pinned, c.id) > (NOT $3, $4))
+ORDER BY c.pinned DESC, c.id ASC
+LIMIT $5
+                "#
+                );
+
+                let (cursor_pinned, cursor_id) = match cursor {
+                    Some(CommentCursor { pinned, id }) => (Some(pinned), Some(id)),
+                    None => (None, None),
+                };
+
+                // One extra row tells whether there is a next page without a second query
+                let mut items = diesel::sql_query(sql)
+                    .bind::<Int4, _>(mission_id)
+                    .bind::<Nullable<Int4>, _>(parent_id)
+                    .bind::<Nullable<Bool>, _>(cursor_pinned)
+                    .bind::<Nullable<Int4>, _>(cursor_id)
+                    .bind::<BigInt, _>(limit + 1)
+                    .load::<MissionCommentModel>(&mut conn)?;
+
+                let next_cursor = if items.len() as i64 > limit {
+                    items.truncate(limit as usize);
+                    items.last().map(|last| {
+                        CommentCursor {
+                            pinned: last.pinned,
+                            id: last.id,
+                        }
+                        .encode()
+                    })
+                } else {
+                    None
+                };
+
+                let total = diesel::sql_query(format!(
+                    "SELECT COUNT(*) AS total FROM mission_comments c WHERE {COMMENT_FILTERS}"
+                ))
+                .bind::<Int4, _>(mission_id)
+                .bind::<Nullable<Int4>, _>(parent_id)
+                .get_result::<TotalRow>(&mut conn)?
+                .total;
+
+                Ok(Page {
+                    items,
+                    next_cursor,
+                    total,
+                })
+            })
+            .await??;
+
+        Ok(results)
+    }
+
+    async fn edit(
+        &self,
+        comment_id: i32,
+        mission_id: i32,
+        author_id: i32,
+        body: String,
+    ) -> AppResult<()> {
+        let pool = Arc::clone(&self.db_pool);
+
+        tokio::task::spawn_blocking(move || -> AppResult<()> {
+            let mut conn = pool.get()?;
+            let edited = update(mission_comments::table)
+                .filter(mission_comments::id.eq(comment_id))
+                .filter(mission_comments::mission_id.eq(mission_id))
+                .filter(mission_comments::author_id.eq(author_id))
+                .filter(mission_comments::deleted_at.is_null())
+                .set((
+                    mission_comments::body.eq(body),
+                    mission_comments::edited_at.eq(dsl::now),
+                ))
+                .execute(&mut conn)?;
+            if edited == 0 {
+                return Err(comment_not_found(comment_id));
+            }
+            Ok(())
+        })
+        .await??;
+
+        Ok(())
+    }
+
+    async fn remove(&self, comment_id: i32, mission_id: i32) -> AppResult<()> {
+        let pool = Arc::clone(&self.db_pool);
+
+        tokio::task::spawn_blocking(move || -> AppResult<()> {
+            let mut conn = pool.get()?;
+            let removed = update(mission_comments::table)
+                .filter(mission_comments::id.eq(comment_id))
+                .filter(mission_comments::mission_id.eq(mission_id))
+                .filter(mission_comments::deleted_at.is_null())
+                .set((
+                    mission_comments::deleted_at.eq(dsl::now),
+                    mission_comments::pinned.eq(false),
+                ))
+                .execute(&mut conn)?;
+            if removed == 0 {
+                return Err(comment_not_found(comment_id));
+            }
+            Ok(())
+        })
+        .await??;
+
+        Ok(())
+    }
+
+    async fn set_pinned(&self, comment_id: i32, mission_id: i32, pinned: bool) -> AppResult<()> {
+        let pool = Arc::clone(&self.db_pool);
+
+        tokio::task::spawn_blocking(move || -> AppResult<()> {
+            let mut conn = pool.get()?;
+            let changed = update(mission_comments::table)
+                .filter(mission_comments::id.eq(comment_id))
+                .filter(mission_comments::mission_id.eq(mission_id))
+                .filter(mission_comments::deleted_at.is_null())
+                .set(mission_comments::pinned.eq(pinned))
+                .execute(&mut conn)?;
+            if changed == 0 {
+                return Err(comment_not_found(comment_id));
+            }
+            Ok(())
+        })
+        .await??;
+
+        Ok(())
+    }
+}
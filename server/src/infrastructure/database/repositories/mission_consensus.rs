@@ -0,0 +1,228 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use diesel::{
+    Connection, ExpressionMethods, RunQueryDsl, dsl, insert_into,
+    sql_types::{Int4, Timestamp},
+    update,
+};
+
+use crate::{
+    domain::{
+        entities::mission_outcome_proposals::{AddOutcomeProposalEntity, AddOutcomeVoteEntity},
+        errors::{AppError, AppResult},
+        repositories::mission_consensus::MissionConsensusRepository,
+        value_objects::{
+            brawler_progression::XpRules,
+            mission_consensus::{OutcomeProposalModel, ProposalResolution},
+            mission_state_machine::MissionTransition,
+        },
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        repositories::mission_operation::{apply_transition, apply_transition_by},
+        schema::{mission_outcome_proposals, mission_outcome_votes},
+    },
+};
+
+// A proposal with its votes tallied, filtered by the WHERE clause each query appends
+const PROPOSAL_SELECT: &str = r#"
+    SELECT p.id,
+            p.mission_id,
+            p.proposed_by,
+            b.display_name AS proposed_by_display_name,
+            p.outcome,
+            p.reason,
+            p.eligible_voters,
+            p.required_votes,
+            COUNT(v.brawler_id) FILTER (WHERE v.agree) AS agree_count,
+            COUNT(v.brawler_id) FILTER (WHERE NOT v.agree) AS disagree_count,
+            p.closes_at,
+            p.resolution,
+            p.resolved_at,
+            p.created_at
+    FROM mission_outcome_proposals p
+    INNER JOIN brawlers b ON b.id = p.proposed_by
+    LEFT JOIN mission_outcome_votes v ON v.proposal_id = p.id
+"#;
+
+pub struct MissionConsensusPostgres {
+    db_pool: Arc<PgPoolSquad>,
+    xp_rules: XpRules,
+}
+
+impl MissionConsensusPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>, xp_rules: XpRules) -> Self {
+        Self { db_pool, xp_rules }
+    }
+}
+
+#[async_trait]
+impl MissionConsensusRepository for MissionConsensusPostgres {
+    async fn propose(
+        &self,
+        add_outcome_proposal_entity: AddOutcomeProposalEntity,
+    ) -> AppResult<i32> {
+        let pool = Arc::clone(&self.db_pool);
+
+        let id = tokio::task::spawn_blocking(move || -> AppResult<i32> {
+            let mut conn = pool.get()?;
+            // uq_outcome_proposals_open allows a single open vote per mission
+            let id = insert_into(mission_outcome_proposals::table)
+                .values(add_outcome_proposal_entity)
+                .returning(mission_outcome_proposals::id)
+                .get_result::<i32>(&mut conn)
+                .map_err(|e| match AppError::from(e) {
+                    AppError::Conflict(_) => AppError::Conflict(
+                        "An outcome vote is already open for this mission".to_string(),
+                    ),
+                    e => e,
+                })?;
+            Ok(id)
+        })
+        .await??;
+
+        Ok(id)
+    }
+
+    async fn get_latest(&self, mission_id: i32) -> AppResult<OutcomeProposalModel> {
+        let sql = format!(
+            "{} WHERE p.mission_id = $1 GROUP BY p.id, b.display_name ORDER BY p.id DESC LIMIT 1",
+            PROPOSAL_SELECT
+        );
+
+        let pool = Arc::clone(&self.db_pool);
+        let proposal = tokio::task::spawn_blocking(move || -> AppResult<OutcomeProposalModel> {
+            let mut conn = pool.get()?;
+            let proposal = diesel::sql_query(sql)
+                .bind::<Int4, _>(mission_id)
+                .get_result::<OutcomeProposalModel>(&mut conn)
+                .map_err(|e| match AppError::from(e) {
+                    AppError::NotFound(_) => AppError::NotFound(format!(
+                        "No outcome was proposed for mission {}",
+                        mission_id
+                    )),
+                    e => e,
+                })?;
+            Ok(proposal)
+        })
+        .await??;
+
+        Ok(proposal)
+    }
+
+    async fn vote(&self, add_outcome_vote_entity: AddOutcomeVoteEntity) -> AppResult<()> {
+        let pool = Arc::clone(&self.db_pool);
+
+        tokio::task::spawn_blocking(move || -> AppResult<()> {
+            let mut conn = pool.get()?;
+            insert_into(mission_outcome_votes::table)
+                .values(add_outcome_vote_entity)
+                .execute(&mut conn)
+                .map_err(|e| match AppError::from(e) {
+                    AppError::Conflict(_) => {
+                        AppError::Conflict("You already voted on this outcome".to_string())
+                    }
+                    e => e,
+                })?;
+            Ok(())
+        })
+        .await??;
+
+        Ok(())
+    }
+
+    async fn get_expired(&self, now: NaiveDateTime) -> AppResult<Vec<OutcomeProposalModel>> {
+        let sql = format!(
+            r#"{}
+            WHERE p.resolved_at IS NULL
+                AND p.closes_at <= $1
+                AND EXISTS (
+                    SELECT 1 FROM missions m
+                    WHERE m.id = p.mission_id
+                        AND m.status = 'InProgress'
+                        AND m.deleted_at IS NULL
+                )
+            GROUP BY p.id, b.display_name
+            ORDER BY p.id"#,
+            PROPOSAL_SELECT
+        );
+
+        let pool = Arc::clone(&self.db_pool);
+        let proposals =
+            tokio::task::spawn_blocking(move || -> AppResult<Vec<OutcomeProposalModel>> {
+                let mut conn = pool.get()?;
+                let proposals = diesel::sql_query(sql)
+                    .bind::<Timestamp, _>(now)
+                    .load::<OutcomeProposalModel>(&mut conn)?;
+                Ok(proposals)
+            })
+            .await??;
+
+        Ok(proposals)
+    }
+
+    async fn settle(
+        &self,
+        proposal_id: i32,
+        mission_id: i32,
+        chief_id: i32,
+        resolution: ProposalResolution,
+        transition: MissionTransition,
+    ) -> AppResult<bool> {
+        let pool = Arc::clone(&self.db_pool);
+        let xp_rules = self.xp_rules.clone();
+
+        let settled = tokio::task::spawn_blocking(move || -> AppResult<bool> {
+            let mut conn = pool.get()?;
+            conn.transaction::<_, AppError, _>(|conn| {
+                // Whoever closes the proposal first moves the mission, a racing last vote
+                // or scheduler run finds nothing left to do
+                let closed = update(mission_outcome_proposals::table)
+                    .filter(mission_outcome_proposals::id.eq(proposal_id))
+                    .filter(mission_outcome_proposals::resolved_at.is_null())
+                    .set((
+                        mission_outcome_proposals::resolution.eq(resolution),
+                        mission_outcome_proposals::resolved_at.eq(dsl::now),
+                    ))
+                    .execute(conn)?;
+                if closed == 0 {
+                    return Ok(false);
+                }
+
+                apply_transition(conn, mission_id, chief_id, &transition, &xp_rules)?;
+                Ok(true)
+            })
+        })
+        .await??;
+
+        Ok(settled)
+    }
+
+    async fn resolve_dispute(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        admin_id: i32,
+        transition: MissionTransition,
+    ) -> AppResult<i32> {
+        let pool = Arc::clone(&self.db_pool);
+        let xp_rules = self.xp_rules.clone();
+
+        let id = tokio::task::spawn_blocking(move || -> AppResult<i32> {
+            let mut conn = pool.get()?;
+            apply_transition_by(
+                &mut conn,
+                mission_id,
+                chief_id,
+                admin_id,
+                &transition,
+                &xp_rules,
+            )
+        })
+        .await??;
+
+        Ok(id)
+    }
+}
@@ -0,0 +1,87 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use diesel::{PgConnection, QueryResult, RunQueryDsl, insert_into, sql_types::Int4};
+
+use crate::{
+    domain::{
+        entities::mission_ratings::AddMissionRatingEntity,
+        errors::{AppError, AppResult},
+        repositories::mission_debrief::MissionDebriefRepository,
+        value_objects::mission_debrief::{MissionRatingModel, Reputation},
+    },
+    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::mission_ratings},
+};
+
+// Shared with the profile stats so both agree on how a reputation is rounded
+pub fn load_reputation(conn: &mut PgConnection, brawler_id: i32) -> QueryResult<Reputation> {
+    diesel::sql_query(
+        r#"
+            SELECT ROUND(AVG(score), 2)::float8 AS reputation, COUNT(*) AS rating_count
+            FROM mission_ratings
+            WHERE ratee_id = $1
+        "#,
+    )
+    .bind::<Int4, _>(brawler_id)
+    .get_result::<Reputation>(conn)
+}
+
+pub struct MissionDebriefPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl MissionDebriefPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl MissionDebriefRepository for MissionDebriefPostgres {
+    async fn add_rating(
+        &self,
+        add_mission_rating_entity: AddMissionRatingEntity,
+    ) -> AppResult<i32> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        // uq_rating_once keeps it to one rating per rater and ratee on a mission
+        let id = insert_into(mission_ratings::table)
+            .values(add_mission_rating_entity)
+            .returning(mission_ratings::id)
+            .get_result::<i32>(&mut conn)
+            .map_err(|e| match AppError::from(e) {
+                AppError::Conflict(_) => {
+                    AppError::Conflict("You already rated this brawler on this mission".to_string())
+                }
+                e => e,
+            })?;
+
+        Ok(id)
+    }
+
+    async fn get_ratings(&self, mission_id: i32) -> AppResult<Vec<MissionRatingModel>> {
+        let sql = r#"
+            SELECT r.id,
+                    r.rater_id,
+                    rater.display_name AS rater_display_name,
+                    r.ratee_id,
+                    ratee.display_name AS ratee_display_name,
+                    r.score,
+                    r.mission_score,
+                    r.comment,
+                    r.created_at
+            FROM mission_ratings r
+            INNER JOIN brawlers rater ON rater.id = r.rater_id
+            INNER JOIN brawlers ratee ON ratee.id = r.ratee_id
+            WHERE r.mission_id = $1
+            ORDER BY r.id
+        "#;
+
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let ratings = diesel::sql_query(sql)
+            .bind::<Int4, _>(mission_id)
+            .load::<MissionRatingModel>(&mut conn)?;
+
+        Ok(ratings)
+    }
+}
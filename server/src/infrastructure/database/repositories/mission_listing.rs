@@ -0,0 +1,224 @@
+use chrono::NaiveDateTime;
+use diesel::{
+    PgConnection, QueryableByName, RunQueryDsl,
+    pg::Pg,
+    query_builder::{BoxedSqlQuery, SqlQuery},
+    sql_types::{Array, BigInt, Bool, Int4, Nullable, Text, Timestamp, Varchar},
+};
+
+use crate::domain::{
+    errors::AppResult,
+    value_objects::{
+        mission_difficulty::MissionDifficulty,
+        mission_filter::{MissionFilter, MissionMembership},
+        mission_model::MissionListItem,
+        mission_statuses::{MissionStatus, MissionStatuses},
+        pagination::{MissionCursor, MissionPageQuery, MissionSortKey, Page, SortDirection},
+    },
+};
+
+// Shared by the page query and its total count, which bind the filters as $1 to $10
+const MISSION_FILTERS: &str = r#"
+    m.deleted_at IS NULL
+    AND ($1::mission_status[] IS NULL OR m.status = ANY($1))
+    AND ($2::varchar IS NULL OR m.name ILIKE $2)
+    AND ($3::text[] IS NULL OR m.difficulty = ANY($3))
+    AND ($4::int4 IS NULL OR m.chief_id = $4)
+    AND ($5::timestamp IS NULL OR m.created_at >= $5)
+    AND ($6::timestamp IS NULL OR m.created_at <= $6)
+    AND ($7::bool IS NULL OR $7 = (
+        (SELECT COUNT(*) FROM crew_memberships o WHERE o.mission_id = m.id) < m.max_crew
+    ))
+    AND ($8::int4 IS NULL OR EXISTS (
+        SELECT 1 FROM crew_memberships j WHERE j.mission_id = m.id AND j.brawler_id = $8
+    ))
+    AND ($9::int4 IS NULL OR NOT EXISTS (
+        SELECT 1 FROM crew_memberships j WHERE j.mission_id = m.id AND j.brawler_id = $9
+    ))
+    AND ($10::text IS NULL OR m.search_vector @@ websearch_to_tsquery('english', $10))
+"#;
+
+struct FilterBinds {
+    statuses: Option<Vec<MissionStatuses>>,
+    name: Option<String>,
+    difficulties: Option<Vec<String>>,
+    chief_id: Option<i32>,
+    created_after: Option<NaiveDateTime>,
+    created_before: Option<NaiveDateTime>,
+    has_open_slots: Option<bool>,
+    joined_by: Option<i32>,
+    not_joined_by: Option<i32>,
+    search: Option<String>,
+}
+
+impl FilterBinds {
+    fn new(filter: &MissionFilter, viewer_id: Option<i32>) -> Self {
+        let membership_of = |wanted: MissionMembership| {
+            if filter.membership == Some(wanted) {
+                viewer_id
+            } else {
+                None
+            }
+        };
+
+        Self {
+            statuses: filter.status.clone(),
+            name: filter.name.as_ref().map(|n| format!("%{}%", n)),
+            difficulties: filter
+                .difficulty
+                .as_ref()
+                .map(|levels| levels.iter().map(|d| d.to_string()).collect()),
+            chief_id: filter.chief_id,
+            created_after: filter.created_after,
+            created_before: filter.created_before,
+            has_open_slots: filter.has_open_slots,
+            joined_by: membership_of(MissionMembership::Joined),
+            not_joined_by: membership_of(MissionMembership::NotJoined),
+            search: filter.search_terms().map(str::to_string),
+        }
+    }
+
+    fn bind(&self, sql: String) -> BoxedSqlQuery<'static, Pg, SqlQuery> {
+        diesel::sql_query(sql)
+            .into_boxed()
+            .bind::<Nullable<Array<MissionStatus>>, _>(self.statuses.clone())
+            .bind::<Nullable<Varchar>, _>(self.name.clone())
+            .bind::<Nullable<Array<Text>>, _>(self.difficulties.clone())
+            .bind::<Nullable<Int4>, _>(self.chief_id)
+            .bind::<Nullable<Timestamp>, _>(self.created_after)
+            .bind::<Nullable<Timestamp>, _>(self.created_before)
+            .bind::<Nullable<Bool>, _>(self.has_open_slots)
+            .bind::<Nullable<Int4>, _>(self.joined_by)
+            .bind::<Nullable<Int4>, _>(self.not_joined_by)
+            .bind::<Nullable<Text>, _>(self.search.clone())
+    }
+}
+
+#[derive(QueryableByName)]
+struct TotalRow {
+    #[diesel(sql_type = BigInt)]
+    total: i64,
+}
+
+// `MissionDifficulty::level` of a difficulty column, so SQL orders it the same way
+fn difficulty_level_sql(column: &str) -> String {
+    let cases = MissionDifficulty::ALL
+        .iter()
+        .map(|difficulty| format!("WHEN '{}' THEN {}", difficulty, difficulty.level()))
+        .collect::<Vec<_>>()
+        .join(" ");
+    format!("CASE {} {} END", column, cases)
+}
+
+// Keyset pagination over every listing of missions. The listing is wrapped in a subquery
+// so crew_count can be sorted and compared like any other column, and ties on the sort
+// key are broken by id so the cursor always points at exactly one row.
+pub fn load_mission_page(
+    conn: &mut PgConnection,
+    filter: &MissionFilter,
+    viewer_id: Option<i32>,
+    page: &MissionPageQuery,
+) -> AppResult<Page<MissionListItem>> {
+    let page = page.resolve_for(filter)?;
+    let sort = page.sort_key();
+    let limit = page.page_size()?;
+    let cursor = page.decode_cursor()?;
+
+    // Column names come from the enum, never from the request
+    let (sort_column, cursor_cast) = match sort {
+        MissionSortKey::CreatedAt => ("listing.created_at".to_string(), "timestamp"),
+        MissionSortKey::Name => ("listing.name".to_string(), "varchar"),
+        MissionSortKey::CrewCount => ("listing.crew_count".to_string(), "bigint"),
+        MissionSortKey::Difficulty => (difficulty_level_sql("listing.difficulty"), "int4"),
+        MissionSortKey::Relevance => ("listing.rank".to_string(), "real"),
+    };
+    let (order, comparison) = match page.direction() {
+        SortDirection::Asc => ("ASC", ">"),
+        SortDirection::Desc => ("DESC", "<"),
+    };
+
+    let sql = format!(
+        r#"
+SELECT listing.*,
+        CASE WHEN $10::text IS NULL THEN NULL ELSE ts_headline(
+            'english',
+            concat_ws(' ', listing.name, listing.description),
+            websearch_to_tsquery('english', $10),
+            'StartSel=<mark>, StopSel=</mark>, MaxFragments=2, MaxWords=20, MinWords=8'
+        ) END AS snippet
+FROM (
+    SELECT m.id,
+            m.name,
+            m.description,
+            m.status,
+            m.difficulty,
+            m.chief_id,
+            COALESCE(b.display_name, '') AS chief_display_name,
+            COUNT(cm.brawler_id) AS crew_count,
+            m.max_crew,
+            m.min_crew,
+            m.join_policy,
+            m.completion_mode,
+            m.scheduled_start,
+            m.deadline,
+            m.series_id,
+            (SELECT a.url FROM mission_attachments a
+                WHERE a.mission_id = m.id AND a.kind = 'Cover') AS cover_url,
+            m.created_at,
+            m.updated_at,
+            CASE WHEN $10::text IS NULL THEN NULL
+                ELSE ts_rank(m.search_vector, websearch_to_tsquery('english', $10))
+            END AS rank
+    FROM missions m
+    LEFT JOIN brawlers b ON b.id = m.chief_id
+    LEFT JOIN crew_memberships cm ON cm.mission_id = m.id
+    WHERE {MISSION_FILTERS}
+    GROUP BY m.id, b.display_name
+) listing
+WHERE ($11::text IS NULL OR ({sort_column}, listing.id) {comparison} ($11::{cursor_cast}, $12))
+ORDER BY {sort_column} {order}, listing.id {order}
+LIMIT $13
+        "#
+    );
+
+    let binds = FilterBinds::new(filter, viewer_id);
+    let (cursor_value, cursor_id) = match cursor {
+        Some(MissionCursor { value, id, .. }) => (Some(value), Some(id)),
+        None => (None, None),
+    };
+
+    // One extra row tells whether there is a next page without a second query
+    let mut items = binds
+        .bind(sql)
+        .bind::<Nullable<Text>, _>(cursor_value)
+        .bind::<Nullable<Int4>, _>(cursor_id)
+        .bind::<BigInt, _>(limit + 1)
+        .load::<MissionListItem>(conn)?;
+
+    let next_cursor = if items.len() as i64 > limit {
+        items.truncate(limit as usize);
+        items.last().map(|last| {
+            MissionCursor {
+                sort,
+                value: sort.cursor_value(last),
+                id: last.mission.id,
+            }
+            .encode()
+        })
+    } else {
+        None
+    };
+
+    let total = binds
+        .bind(format!(
+            "SELECT COUNT(*) AS total FROM missions m WHERE {MISSION_FILTERS}"
+        ))
+        .get_result::<TotalRow>(conn)?
+        .total;
+
+    Ok(Page {
+        items,
+        next_cursor,
+        total,
+    })
+}
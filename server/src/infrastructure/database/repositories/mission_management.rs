@@ -1,16 +1,70 @@
 use crate::{
     domain::{
-        entities::missions::{AddMissionEntity, EditMissionEntity},
+        entities::{
+            crew_memberships::CrewMemberShips,
+            join_requests::AddMissionInvitationEntity,
+            mission_status_history::AddMissionStatusHistoryEntity,
+            missions::{AddMissionEntity, EditMissionEntity},
+        },
+        errors::{AppError, AppResult},
         repositories::mission_management::MissionManagementRepository,
-        value_objects::mission_statuses::MissionStatuses,
+        value_objects::{
+            mission_model::DeletedMissionModel, mission_retention::MissionRetention,
+            mission_state_machine::MissionTransition, mission_statuses::MissionStatuses,
+        },
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        repositories::{
+            crew_operation::{count_crew, ensure_crew_can_change, lock_mission},
+            mission_attachments::destroy_stored,
+            mission_operation::record_transition,
+            mission_waitlist::promote_from_waitlist,
+        },
+        schema::{
+            crew_memberships, mission_attachments, mission_invitations, mission_status_history,
+            missions,
+        },
     },
-    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::missions},
 };
-use anyhow::{Ok, Result};
 use async_trait::async_trait;
-use diesel::{ExpressionMethods, RunQueryDsl, dsl::now, dsl::update, insert_into};
+use chrono::NaiveDateTime;
+use diesel::{
+    Connection, ExpressionMethods, OptionalExtension, PgConnection, QueryDsl, RunQueryDsl, delete,
+    dsl::{self, update},
+    insert_into,
+    sql_types::{Double, Int4, Timestamp},
+};
 use std::sync::Arc;
 
+// The mission and its first history row go in together, so every mission has a full trail
+pub fn insert_mission(
+    conn: &mut PgConnection,
+    add_mission_entity: AddMissionEntity,
+) -> AppResult<i32> {
+    conn.transaction::<_, AppError, _>(|conn| {
+        let chief_id = add_mission_entity.chief_id;
+        let to_status = add_mission_entity.status;
+
+        let mission_id = insert_into(missions::table)
+            .values(add_mission_entity)
+            .returning(missions::id)
+            .get_result::<i32>(conn)?;
+
+        insert_into(mission_status_history::table)
+            .values(AddMissionStatusHistoryEntity {
+                mission_id,
+                changed_by: chief_id,
+                from_status: None,
+                to_status,
+                reason: None,
+            })
+            .execute(conn)?;
+
+        Ok(mission_id)
+    })
+}
+
 pub struct MissionManagementPostgres {
     db_pool: Arc<PgPoolSquad>,
 }
@@ -23,56 +77,332 @@ impl MissionManagementPostgres {
 
 #[async_trait]
 impl MissionManagementRepository for MissionManagementPostgres {
-    async fn add(&self, add_mission_entity: AddMissionEntity) -> Result<i32> {
+    async fn add(&self, add_mission_entity: AddMissionEntity) -> AppResult<i32> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
-        let result = insert_into(missions::table)
-            .values(add_mission_entity)
-            .returning(missions::id)
-            .get_result::<i32>(&mut conn)?;
+        let result = insert_mission(&mut conn, add_mission_entity)?;
         Ok(result)
     }
 
-    async fn edit(&self, mission_id: i32, edit_mission_entity: EditMissionEntity) -> Result<i32> {
+    async fn add_clone(
+        &self,
+        source_mission_id: i32,
+        add_mission_entity: AddMissionEntity,
+        reinvite_crew: bool,
+    ) -> AppResult<i32> {
+        let pool = Arc::clone(&self.db_pool);
+
+        let result = tokio::task::spawn_blocking(move || -> AppResult<i32> {
+            let mut conn = pool.get()?;
+            conn.transaction::<_, AppError, _>(|conn| {
+                let chief_id = add_mission_entity.chief_id;
+                let mission_id = insert_mission(conn, add_mission_entity)?;
+
+                if reinvite_crew {
+                    let invitations = crew_memberships::table
+                        .filter(crew_memberships::mission_id.eq(source_mission_id))
+                        .filter(crew_memberships::brawler_id.ne(chief_id))
+                        .select(crew_memberships::brawler_id)
+                        .load::<i32>(conn)?
+                        .into_iter()
+                        .map(|brawler_id| AddMissionInvitationEntity {
+                            mission_id,
+                            brawler_id,
+                            invited_by: chief_id,
+                        })
+                        .collect::<Vec<_>>();
+
+                    insert_into(mission_invitations::table)
+                        .values(invitations)
+                        .execute(conn)?;
+                }
+
+                Ok(mission_id)
+            })
+        })
+        .await??;
+
+        Ok(result)
+    }
+
+    async fn edit(
+        &self,
+        mission_id: i32,
+        edit_mission_entity: EditMissionEntity,
+    ) -> AppResult<i32> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
-        let result = update(missions::table)
-            .filter(missions::id.eq(mission_id))
-            .filter(missions::deleted_at.is_null())
-            .filter(missions::status.eq(MissionStatuses::Open.to_string()))
-            .set(edit_mission_entity)
-            .returning(missions::id)
-            .get_result::<i32>(&mut conn)?;
+        let result = conn.transaction::<_, AppError, _>(|conn| {
+            let max_crew = edit_mission_entity.max_crew;
+            // Holds off joins until the new limit is checked against the crew
+            if max_crew.is_some() {
+                lock_mission(conn, mission_id)?;
+            }
+
+            let id = update(missions::table)
+                .filter(missions::id.eq(mission_id))
+                .filter(missions::chief_id.eq(edit_mission_entity.chief_id))
+                .filter(missions::deleted_at.is_null())
+                .filter(missions::status.eq(MissionStatuses::Open))
+                .set(edit_mission_entity)
+                .returning(missions::id)
+                .get_result::<i32>(conn)
+                .optional()?
+                .ok_or_else(|| AppError::NotFound("Open mission not found".to_string()))?;
+
+            if let Some(max_crew) = max_crew {
+                let crew_count = count_crew(conn, id)?;
+                if crew_count > i64::from(max_crew) {
+                    return Err(AppError::Conflict(format!(
+                        "max_crew can not go below the {} brawlers already in the crew",
+                        crew_count
+                    )));
+                }
+
+                // A bigger crew limit opens slots for whoever is waiting
+                promote_from_waitlist(conn, id)?;
+            }
+            Ok(id)
+        })?;
         Ok(result)
     }
 
-    async fn remove(&self, mission_id: i32, chief_id: i32) -> Result<()> {
+    async fn remove(&self, mission_id: i32, chief_id: i32) -> AppResult<()> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
-        update(missions::table)
-            .filter(missions::id.eq(mission_id))
-            .filter(missions::deleted_at.is_null())
-            .filter(
-                missions::status.eq_any(vec![
-                    MissionStatuses::Open.to_string(),
-                    MissionStatuses::Completed.to_string(),
-                    MissionStatuses::Failed.to_string(),
-                ]),
-            )
-            .filter(missions::chief_id.eq(chief_id))
-            .set(missions::deleted_at.eq(now))
-            .execute(&mut conn)?;
+        conn.transaction::<_, AppError, _>(|conn| {
+            let removed = update(missions::table)
+                .filter(missions::id.eq(mission_id))
+                .filter(missions::deleted_at.is_null())
+                .filter(missions::status.eq_any(vec![
+                    MissionStatuses::Open,
+                    MissionStatuses::Completed,
+                    MissionStatuses::Failed,
+                    MissionStatuses::Cancelled,
+                ]))
+                .filter(missions::chief_id.eq(chief_id))
+                .set(missions::deleted_at.eq(dsl::now))
+                .execute(conn)?;
+            if removed > 0 {
+                return Ok(());
+            }
+
+            // Nothing matched, look the mission up again to tell the chief why
+            let (mission_chief_id, status) = missions::table
+                .filter(missions::id.eq(mission_id))
+                .filter(missions::deleted_at.is_null())
+                .select((missions::chief_id, missions::status))
+                .first::<(i32, MissionStatuses)>(conn)
+                .optional()?
+                .ok_or_else(|| AppError::NotFound(format!("Mission {} not found", mission_id)))?;
+
+            if mission_chief_id != chief_id {
+                return Err(AppError::Forbidden(
+                    "Only the chief can remove this mission".to_string(),
+                ));
+            }
+
+            Err(AppError::InvalidStateTransition(format!(
+                "Cannot remove a mission that is {}",
+                status
+            )))
+        })?;
 
         Ok(())
     }
 
-    async fn start(&self, mission_id: i32) -> Result<()> {
-        let mut conn = Arc::clone(&self.db_pool).get()?;
+    async fn get_deleted(
+        &self,
+        chief_id: i32,
+        retention: MissionRetention,
+        now: NaiveDateTime,
+    ) -> AppResult<Vec<DeletedMissionModel>> {
+        let pool = Arc::clone(&self.db_pool);
+
+        let results =
+            tokio::task::spawn_blocking(move || -> AppResult<Vec<DeletedMissionModel>> {
+                let mut conn = pool.get()?;
+
+                let sql = r#"
+SELECT m.id,
+        m.name,
+        m.description,
+        m.status,
+        m.difficulty,
+        m.chief_id,
+        COALESCE(b.display_name, '') AS chief_display_name,
+        COUNT(cm.brawler_id) AS crew_count,
+        m.max_crew,
+        m.min_crew,
+        m.join_policy,
+        m.completion_mode,
+        m.scheduled_start,
+        m.deadline,
+        m.series_id,
+        (SELECT a.url FROM mission_attachments a
+            WHERE a.mission_id = m.id AND a.kind = 'Cover') AS cover_url,
+        m.created_at,
+        m.updated_at,
+        m.deleted_at,
+        m.deleted_at + make_interval(secs => $3) AS restorable_until
+FROM missions m
+LEFT JOIN brawlers b ON b.id = m.chief_id
+LEFT JOIN crew_memberships cm ON cm.mission_id = m.id
+WHERE m.chief_id = $1
+    AND m.deleted_at > $2
+GROUP BY m.id, b.display_name
+ORDER BY m.deleted_at DESC, m.id DESC
+            "#;
+
+                let missions = diesel::sql_query(sql)
+                    .bind::<Int4, _>(chief_id)
+                    .bind::<Timestamp, _>(retention.cutoff(now))
+                    .bind::<Double, _>(retention.window.num_seconds() as f64)
+                    .load::<DeletedMissionModel>(&mut conn)?;
+                Ok(missions)
+            })
+            .await??;
+
+        Ok(results)
+    }
+
+    async fn restore(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        deleted_after: NaiveDateTime,
+    ) -> AppResult<()> {
+        let pool = Arc::clone(&self.db_pool);
+
+        let restored = tokio::task::spawn_blocking(move || -> AppResult<usize> {
+            let mut conn = pool.get()?;
+            let restored = update(missions::table)
+                .filter(missions::id.eq(mission_id))
+                .filter(missions::chief_id.eq(chief_id))
+                .filter(missions::deleted_at.gt(deleted_after))
+                .set(missions::deleted_at.eq(None::<NaiveDateTime>))
+                .execute(&mut conn)?;
+            Ok(restored)
+        })
+        .await??;
+        if restored == 0 {
+            return Err(AppError::NotFound(format!(
+                "No removed mission {} that can still be restored",
+                mission_id
+            )));
+        }
+
+        Ok(())
+    }
+
+    async fn purge(&self, deleted_before: NaiveDateTime) -> AppResult<Vec<i32>> {
+        let pool = Arc::clone(&self.db_pool);
+
+        let (purged, stored_files) =
+            tokio::task::spawn_blocking(move || -> AppResult<(Vec<i32>, Vec<String>)> {
+                let mut conn = pool.get()?;
+                conn.transaction::<_, AppError, _>(|conn| {
+                    // Locked so a restore can not slip in between collecting the files and
+                    // deleting
+                    let expired = missions::table
+                        .filter(missions::deleted_at.le(deleted_before))
+                        .select(missions::id)
+                        .for_update()
+                        .load::<i32>(conn)?;
+
+                    // Crew, history, waitlist and the rest go along through their cascading
+                    // foreign keys, the attachments' stored files have to be collected before
+                    // they are gone
+                    let stored_files = mission_attachments::table
+                        .filter(mission_attachments::mission_id.eq_any(&expired))
+                        .select(mission_attachments::public_id)
+                        .load::<String>(conn)?;
+
+                    let purged = delete(missions::table)
+                        .filter(missions::id.eq_any(&expired))
+                        .returning(missions::id)
+                        .get_results::<i32>(conn)?;
+
+                    Ok((purged, stored_files))
+                })
+            })
+            .await??;
+
+        destroy_stored(stored_files).await;
+
+        Ok(purged)
+    }
+
+    async fn start(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        transition: MissionTransition,
+    ) -> AppResult<()> {
+        let pool = Arc::clone(&self.db_pool);
+
+        tokio::task::spawn_blocking(move || -> AppResult<i32> {
+            let mut conn = pool.get()?;
+            // Starting never completes a mission, so there is no XP to award
+            record_transition(&mut conn, mission_id, chief_id, chief_id, &transition)
+        })
+        .await??;
+
+        Ok(())
+    }
+
+    async fn transfer_chief(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        new_chief_id: i32,
+        stay_in_crew: bool,
+    ) -> AppResult<()> {
+        let pool = Arc::clone(&self.db_pool);
+
+        tokio::task::spawn_blocking(move || -> AppResult<()> {
+            let mut conn = pool.get()?;
+            conn.transaction::<_, AppError, _>(|conn| {
+                let mission = lock_mission(conn, mission_id)?;
+
+                if mission.chief_id != chief_id {
+                    return Err(AppError::Forbidden(
+                        "Only the chief can hand over the mission".to_string(),
+                    ));
+                }
+
+                ensure_crew_can_change(&mission)?;
+
+                // The new chief leaves the crew, so the seat count only changes when the
+                // old chief steps out as well
+                let promoted = delete(crew_memberships::table)
+                    .filter(crew_memberships::mission_id.eq(mission_id))
+                    .filter(crew_memberships::brawler_id.eq(new_chief_id))
+                    .execute(conn)?;
+                if promoted == 0 {
+                    return Err(AppError::Validation(
+                        "The new chief must be a crew member of this mission".to_string(),
+                    ));
+                }
+
+                update(missions::table)
+                    .filter(missions::id.eq(mission_id))
+                    .set(missions::chief_id.eq(new_chief_id))
+                    .execute(conn)?;
+
+                if stay_in_crew {
+                    insert_into(crew_memberships::table)
+                        .values(CrewMemberShips {
+                            mission_id,
+                            brawler_id: chief_id,
+                        })
+                        .execute(conn)?;
+                } else {
+                    promote_from_waitlist(conn, mission_id)?;
+                }
 
-        update(missions::table)
-            .filter(missions::id.eq(mission_id))
-            .filter(missions::deleted_at.is_null())
-            .filter(missions::status.eq(MissionStatuses::Open.to_string()))
-            .set(missions::status.eq(MissionStatuses::InProgress.to_string()))
-            .execute(&mut conn)?;
+                Ok(())
+            })
+        })
+        .await??;
 
         Ok(())
     }
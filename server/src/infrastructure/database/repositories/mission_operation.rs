@@ -1,74 +1,199 @@
 use std::sync::Arc;
 
-use anyhow::{Context, Ok, Result};
 use async_trait::async_trait;
-use diesel::{ExpressionMethods, RunQueryDsl, dsl::update};
+use diesel::{
+    Connection, ExpressionMethods, PgConnection, QueryDsl, RunQueryDsl,
+    dsl::{insert_into, update},
+};
 
 use crate::{
     domain::{
+        entities::{
+            mission_status_history::AddMissionStatusHistoryEntity, xp_events::AddXpEventEntity,
+        },
+        errors::{AppError, AppResult},
         repositories::mission_operation::MissionOperationRepository,
-        value_objects::mission_statuses::MissionStatuses,
+        value_objects::{
+            brawler_progression::{XpReason, XpRules},
+            mission_difficulty::MissionDifficulty,
+            mission_state_machine::MissionTransition,
+            mission_statuses::MissionStatuses,
+        },
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        repositories::crew_operation::{ensure_crew_can_start, lock_mission},
+        schema::{brawlers, crew_memberships, mission_status_history, missions, xp_events},
     },
-    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::missions},
 };
 pub struct MissionOperationPostgres {
     db_pool: Arc<PgPoolSquad>,
+    xp_rules: XpRules,
 }
 
 impl MissionOperationPostgres {
-    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
-        Self { db_pool }
+    pub fn new(db_pool: Arc<PgPoolSquad>, xp_rules: XpRules) -> Self {
+        Self { db_pool, xp_rules }
     }
+}
 
-    async fn set_status(
-        &self,
-        mission_id: i32,
-        chief_id: i32,
-        status: MissionStatuses,
-    ) -> Result<i32> {
-        let db_pool = Arc::clone(&self.db_pool);
-        let status_string = status.to_string();
-        let id = tokio::task::spawn_blocking(move || -> Result<i32> {
-            let mut conn = db_pool.get().context("Failed to get DB connection")?;
+// Compare-and-set on the expected status: when two requests race, only the first one
+// still sees `transition.from` and the other gets an InvalidStateTransition.
+// The history row is written in the same transaction so the audit trail never drifts.
+pub fn apply_transition(
+    conn: &mut PgConnection,
+    mission_id: i32,
+    chief_id: i32,
+    transition: &MissionTransition,
+    xp_rules: &XpRules,
+) -> AppResult<i32> {
+    apply_transition_by(conn, mission_id, chief_id, chief_id, transition, xp_rules)
+}
 
-            update(missions::table)
-                .filter(missions::id.eq(mission_id))
-                .filter(missions::chief_id.eq(chief_id))
-                .filter(missions::deleted_at.is_null())
-                .set((missions::status.eq(status_string),))
-                .returning(missions::id)
-                .get_result::<i32>(&mut conn)
-                .context("Failed to execute mission update query")
-        })
-        .await??;
+// Same as `apply_transition` for someone other than the chief, who is then the one the
+// history names
+pub fn apply_transition_by(
+    conn: &mut PgConnection,
+    mission_id: i32,
+    chief_id: i32,
+    changed_by: i32,
+    transition: &MissionTransition,
+    xp_rules: &XpRules,
+) -> AppResult<i32> {
+    conn.transaction::<_, AppError, _>(|conn| {
+        let id = record_transition(conn, mission_id, chief_id, changed_by, transition)?;
+
+        if transition.to == MissionStatuses::Completed {
+            award_completion_xp(conn, id, xp_rules)?;
+        }
 
         Ok(id)
-    }
+    })
 }
 
-#[async_trait]
-impl MissionOperationRepository for MissionOperationPostgres {
-    async fn to_progress(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
-        let result = self
-            .set_status(mission_id, chief_id, MissionStatuses::InProgress)
-            .await?;
+// The status change and its history row without the XP, only for transitions that can
+// not complete a mission. Starting checks the crew size under the mission lock.
+pub fn record_transition(
+    conn: &mut PgConnection,
+    mission_id: i32,
+    chief_id: i32,
+    changed_by: i32,
+    transition: &MissionTransition,
+) -> AppResult<i32> {
+    conn.transaction::<_, AppError, _>(|conn| {
+        if transition.to == MissionStatuses::InProgress {
+            let mission = lock_mission(conn, mission_id)?;
+            ensure_crew_can_start(conn, mission_id, &mission)?;
+        }
+
+        let id = compare_and_set_status(conn, mission_id, chief_id, transition)?;
 
-        Ok(result)
+        insert_into(mission_status_history::table)
+            .values(AddMissionStatusHistoryEntity {
+                mission_id: id,
+                changed_by,
+                from_status: Some(transition.from),
+                to_status: transition.to,
+                reason: transition.reason.clone(),
+            })
+            .execute(conn)?;
+
+        Ok(id)
+    })
+}
+
+// The chief and everyone on the crew when the mission completed get XP for it. The ledger
+// takes a mission once per brawler and reason, so a replayed completion adds nothing.
+fn award_completion_xp(conn: &mut PgConnection, mission_id: i32, rules: &XpRules) -> AppResult<()> {
+    let (chief_id, difficulty) = missions::table
+        .filter(missions::id.eq(mission_id))
+        .select((missions::chief_id, missions::difficulty))
+        .first::<(i32, MissionDifficulty)>(conn)?;
+    let crew = crew_memberships::table
+        .filter(crew_memberships::mission_id.eq(mission_id))
+        .select(crew_memberships::brawler_id)
+        .load::<i32>(conn)?;
+    let crew_size = crew.len() as i64;
+
+    let participants = std::iter::once((chief_id, XpReason::MissionLed))
+        .chain(crew.into_iter().map(|id| (id, XpReason::MissionCompleted)));
+    for (brawler_id, reason) in participants {
+        let amount = rules.award(difficulty, crew_size, reason);
+        let inserted = insert_into(xp_events::table)
+            .values(AddXpEventEntity {
+                brawler_id,
+                mission_id: Some(mission_id),
+                reason: reason.to_string(),
+                amount,
+            })
+            .on_conflict_do_nothing()
+            .execute(conn)?;
+        if inserted == 0 {
+            continue;
+        }
+
+        update(brawlers::table)
+            .filter(brawlers::id.eq(brawler_id))
+            .set(brawlers::xp.eq(brawlers::xp + amount))
+            .execute(conn)?;
     }
 
-    async fn to_completed(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
-        let result = self
-            .set_status(mission_id, chief_id, MissionStatuses::Completed)
-            .await?;
+    Ok(())
+}
 
-        Ok(result)
+fn compare_and_set_status(
+    conn: &mut PgConnection,
+    mission_id: i32,
+    chief_id: i32,
+    transition: &MissionTransition,
+) -> AppResult<i32> {
+    let updated = update(missions::table)
+        .filter(missions::id.eq(mission_id))
+        .filter(missions::chief_id.eq(chief_id))
+        .filter(missions::deleted_at.is_null())
+        .filter(missions::status.eq(transition.from))
+        .set(missions::status.eq(transition.to))
+        .returning(missions::id)
+        .get_results::<i32>(conn)?;
+
+    match updated.first() {
+        Some(id) => Ok(*id),
+        None => {
+            let exists = missions::table
+                .filter(missions::id.eq(mission_id))
+                .filter(missions::deleted_at.is_null())
+                .count()
+                .get_result::<i64>(conn)?;
+            if exists == 0 {
+                return Err(AppError::NotFound(format!(
+                    "Mission {} not found",
+                    mission_id
+                )));
+            }
+            Err(AppError::InvalidStateTransition(format!(
+                "Mission is no longer {}, it was changed by another request",
+                transition.from
+            )))
+        }
     }
+}
 
-    async fn to_failed(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
-        let result = self
-            .set_status(mission_id, chief_id, MissionStatuses::Failed)
-            .await?;
+#[async_trait]
+impl MissionOperationRepository for MissionOperationPostgres {
+    async fn transition(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        transition: MissionTransition,
+    ) -> AppResult<i32> {
+        let db_pool = Arc::clone(&self.db_pool);
+        let xp_rules = self.xp_rules.clone();
+        let id = tokio::task::spawn_blocking(move || -> AppResult<i32> {
+            let mut conn = db_pool.get()?;
+            apply_transition(&mut conn, mission_id, chief_id, &transition, &xp_rules)
+        })
+        .await??;
 
-        Ok(result)
+        Ok(id)
     }
 }
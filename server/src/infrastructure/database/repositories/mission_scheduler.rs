@@ -0,0 +1,66 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::{
+        errors::AppResult, repositories::mission_scheduler::MissionSchedulerRepository,
+        value_objects::mission_schedule::DueMissionModel,
+    },
+    infrastructure::database::postgresql_connection::PgPoolSquad,
+};
+
+pub struct MissionSchedulerPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl MissionSchedulerPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl MissionSchedulerRepository for MissionSchedulerPostgres {
+    async fn get_due_missions(&self, now: NaiveDateTime) -> AppResult<Vec<DueMissionModel>> {
+        let pool = Arc::clone(&self.db_pool);
+
+        let results = tokio::task::spawn_blocking(move || -> AppResult<Vec<DueMissionModel>> {
+            let mut conn = pool.get()?;
+
+            let sql = r#"
+SELECT m.id,
+        m.chief_id,
+        m.status,
+        COUNT(cm.brawler_id) AS crew_count,
+        m.min_crew,
+        m.max_crew,
+        m.scheduled_start,
+        m.deadline
+FROM missions m
+LEFT JOIN crew_memberships cm ON cm.mission_id = m.id
+WHERE m.deleted_at IS NULL
+    AND (
+        -- An open outcome vote settles the mission instead of its deadline
+        (m.status = 'InProgress' AND m.deadline <= $1 AND NOT EXISTS (
+            SELECT 1 FROM mission_outcome_proposals p
+            WHERE p.mission_id = m.id AND p.resolved_at IS NULL
+        ))
+        OR (m.status = 'Open' AND (m.scheduled_start <= $1 OR m.deadline <= $1))
+    )
+GROUP BY m.id
+ORDER BY m.id
+            "#;
+
+            let missions = diesel::sql_query(sql)
+                .bind::<diesel::sql_types::Timestamp, _>(now)
+                .load::<DueMissionModel>(&mut conn)?;
+            Ok(missions)
+        })
+        .await??;
+
+        Ok(results)
+    }
+}
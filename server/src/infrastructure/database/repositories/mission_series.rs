@@ -0,0 +1,256 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{NaiveDateTime, NaiveTime};
+use diesel::{
+    Connection, ExpressionMethods, OptionalExtension, QueryDsl, RunQueryDsl, SelectableHelper,
+    delete, dsl::now as db_now, insert_into, update,
+};
+
+use crate::{
+    domain::{
+        entities::mission_series::{
+            AddMissionSeriesEntity, EditMissionSeriesEntity, MissionSeriesEntity,
+        },
+        errors::{AppError, AppResult},
+        repositories::mission_series::MissionSeriesRepository,
+        value_objects::mission_statuses::MissionStatuses,
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        repositories::mission_waitlist::promote_from_waitlist,
+        schema::{mission_series, missions},
+    },
+};
+
+pub struct MissionSeriesPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl MissionSeriesPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+fn series_not_found(series_id: i32) -> AppError {
+    AppError::NotFound(format!("Mission series {} not found", series_id))
+}
+
+#[async_trait]
+impl MissionSeriesRepository for MissionSeriesPostgres {
+    async fn add(&self, add_mission_series_entity: AddMissionSeriesEntity) -> AppResult<i32> {
+        let pool = Arc::clone(&self.db_pool);
+
+        let result = tokio::task::spawn_blocking(move || -> AppResult<i32> {
+            let mut conn = pool.get()?;
+            let id = insert_into(mission_series::table)
+                .values(add_mission_series_entity)
+                .returning(mission_series::id)
+                .get_result::<i32>(&mut conn)?;
+            Ok(id)
+        })
+        .await??;
+
+        Ok(result)
+    }
+
+    async fn edit(
+        &self,
+        series_id: i32,
+        edit_mission_series_entity: EditMissionSeriesEntity,
+        now: NaiveDateTime,
+    ) -> AppResult<i32> {
+        let pool = Arc::clone(&self.db_pool);
+
+        let result = tokio::task::spawn_blocking(move || -> AppResult<i32> {
+            let mut conn = pool.get()?;
+            conn.transaction::<_, AppError, _>(|conn| {
+                let chief_id = edit_mission_series_entity.chief_id;
+                let occurrence_changes = edit_mission_series_entity.occurrence_changes();
+                let duration_minutes = edit_mission_series_entity.duration_minutes;
+                let ends_on = edit_mission_series_entity.ends_on;
+
+                let id = update(mission_series::table)
+                    .filter(mission_series::id.eq(series_id))
+                    .filter(mission_series::chief_id.eq(chief_id))
+                    .set(edit_mission_series_entity)
+                    .returning(mission_series::id)
+                    .get_result::<i32>(conn)
+                    .optional()?
+                    .ok_or_else(|| series_not_found(series_id))?;
+
+                // Occurrences handed over to another chief are theirs to edit now
+                let upcoming = missions::table
+                    .filter(missions::series_id.eq(id))
+                    .filter(missions::chief_id.eq(chief_id))
+                    .filter(missions::status.eq(MissionStatuses::Open))
+                    .filter(missions::deleted_at.is_null())
+                    .filter(missions::scheduled_start.gt(now));
+
+                if let Some(changes) = occurrence_changes {
+                    let max_crew_changed = changes.max_crew.is_some();
+                    let mission_ids = update(upcoming.clone())
+                        .set(changes)
+                        .returning(missions::id)
+                        .get_results::<i32>(conn)?;
+
+                    if max_crew_changed {
+                        for mission_id in mission_ids {
+                            promote_from_waitlist(conn, mission_id)?;
+                        }
+                    }
+                }
+
+                if let Some(minutes) = duration_minutes {
+                    diesel::sql_query(
+                        r#"
+UPDATE missions
+SET deadline = scheduled_start + make_interval(mins => $1)
+WHERE series_id = $2
+    AND chief_id = $3
+    AND status = 'Open'
+    AND deleted_at IS NULL
+    AND scheduled_start > $4
+                        "#,
+                    )
+                    .bind::<diesel::sql_types::Int4, _>(minutes)
+                    .bind::<diesel::sql_types::Int4, _>(id)
+                    .bind::<diesel::sql_types::Int4, _>(chief_id)
+                    .bind::<diesel::sql_types::Timestamp, _>(now)
+                    .execute(conn)?;
+                }
+
+                // Occurrences already created past a new end date go away with it
+                if let Some(cutoff) = ends_on.and_then(|date| date.succ_opt()) {
+                    update(upcoming)
+                        .filter(missions::scheduled_start.ge(cutoff.and_time(NaiveTime::MIN)))
+                        .set(missions::deleted_at.eq(db_now))
+                        .execute(conn)?;
+                }
+
+                Ok(id)
+            })
+        })
+        .await??;
+
+        Ok(result)
+    }
+
+    async fn remove(&self, series_id: i32, chief_id: i32, now: NaiveDateTime) -> AppResult<()> {
+        let pool = Arc::clone(&self.db_pool);
+
+        tokio::task::spawn_blocking(move || -> AppResult<()> {
+            let mut conn = pool.get()?;
+            conn.transaction::<_, AppError, _>(|conn| {
+                let exists = mission_series::table
+                    .filter(mission_series::id.eq(series_id))
+                    .filter(mission_series::chief_id.eq(chief_id))
+                    .count()
+                    .get_result::<i64>(conn)?;
+                if exists == 0 {
+                    return Err(series_not_found(series_id));
+                }
+
+                update(missions::table)
+                    .filter(missions::series_id.eq(series_id))
+                    .filter(missions::chief_id.eq(chief_id))
+                    .filter(missions::status.eq(MissionStatuses::Open))
+                    .filter(missions::deleted_at.is_null())
+                    .filter(missions::scheduled_start.gt(now))
+                    .set(missions::deleted_at.eq(db_now))
+                    .execute(conn)?;
+
+                // Past occurrences stay as regular missions, their series_id is cleared
+                delete(mission_series::table)
+                    .filter(mission_series::id.eq(series_id))
+                    .execute(conn)?;
+
+                Ok(())
+            })
+        })
+        .await??;
+
+        Ok(())
+    }
+
+    async fn get_one(&self, series_id: i32, chief_id: i32) -> AppResult<MissionSeriesEntity> {
+        let pool = Arc::clone(&self.db_pool);
+
+        let result = tokio::task::spawn_blocking(move || -> AppResult<MissionSeriesEntity> {
+            let mut conn = pool.get()?;
+            let series = mission_series::table
+                .filter(mission_series::id.eq(series_id))
+                .filter(mission_series::chief_id.eq(chief_id))
+                .select(MissionSeriesEntity::as_select())
+                .first::<MissionSeriesEntity>(&mut conn)
+                .optional()?
+                .ok_or_else(|| series_not_found(series_id))?;
+            Ok(series)
+        })
+        .await??;
+
+        Ok(result)
+    }
+
+    async fn get_all(&self, chief_id: i32) -> AppResult<Vec<MissionSeriesEntity>> {
+        let pool = Arc::clone(&self.db_pool);
+
+        let results =
+            tokio::task::spawn_blocking(move || -> AppResult<Vec<MissionSeriesEntity>> {
+                let mut conn = pool.get()?;
+                let series = mission_series::table
+                    .filter(mission_series::chief_id.eq(chief_id))
+                    .order((mission_series::name.asc(), mission_series::id.asc()))
+                    .select(MissionSeriesEntity::as_select())
+                    .load::<MissionSeriesEntity>(&mut conn)?;
+                Ok(series)
+            })
+            .await??;
+
+        Ok(results)
+    }
+
+    async fn get_due_series(&self, horizon: NaiveDateTime) -> AppResult<Vec<MissionSeriesEntity>> {
+        let pool = Arc::clone(&self.db_pool);
+
+        let results =
+            tokio::task::spawn_blocking(move || -> AppResult<Vec<MissionSeriesEntity>> {
+                let mut conn = pool.get()?;
+                let series = mission_series::table
+                    .filter(mission_series::next_occurrence.le(horizon))
+                    .order(mission_series::id.asc())
+                    .select(MissionSeriesEntity::as_select())
+                    .load::<MissionSeriesEntity>(&mut conn)?;
+                Ok(series)
+            })
+            .await??;
+
+        Ok(results)
+    }
+
+    async fn advance(
+        &self,
+        series_id: i32,
+        from: NaiveDateTime,
+        next: Option<NaiveDateTime>,
+    ) -> AppResult<bool> {
+        let pool = Arc::clone(&self.db_pool);
+
+        let result = tokio::task::spawn_blocking(move || -> AppResult<bool> {
+            let mut conn = pool.get()?;
+            let updated = update(mission_series::table)
+                .filter(mission_series::id.eq(series_id))
+                .filter(mission_series::next_occurrence.eq(from))
+                .set((
+                    mission_series::next_occurrence.eq(next),
+                    mission_series::generated_count.eq(mission_series::generated_count + 1),
+                ))
+                .execute(&mut conn)?;
+            Ok(updated > 0)
+        })
+        .await??;
+
+        Ok(result)
+    }
+}
@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use diesel::prelude::*;
+
+use crate::{
+    domain::{
+        errors::AppResult, repositories::mission_status_history::MissionStatusHistoryRepository,
+        value_objects::mission_status_history::MissionStatusHistoryModel,
+    },
+    infrastructure::database::postgresql_connection::PgPoolSquad,
+};
+
+pub struct MissionStatusHistoryPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl MissionStatusHistoryPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl MissionStatusHistoryRepository for MissionStatusHistoryPostgres {
+    async fn get_history(&self, mission_id: i32) -> AppResult<Vec<MissionStatusHistoryModel>> {
+        let pool = Arc::clone(&self.db_pool);
+
+        let results =
+            tokio::task::spawn_blocking(move || -> AppResult<Vec<MissionStatusHistoryModel>> {
+                let mut conn = pool.get()?;
+
+                let sql = r#"
+SELECT h.id,
+        h.mission_id,
+        h.changed_by,
+        COALESCE(b.display_name, '') AS changed_by_display_name,
+        h.from_status,
+        h.to_status,
+        h.reason,
+        h.changed_at
+FROM mission_status_history h
+LEFT JOIN brawlers b ON b.id = h.changed_by
+WHERE h.mission_id = $1
+ORDER BY h.changed_at ASC, h.id ASC
+                "#;
+
+                let history = diesel::sql_query(sql)
+                    .bind::<diesel::sql_types::Int4, _>(mission_id)
+                    .load::<MissionStatusHistoryModel>(&mut conn)?;
+                Ok(history)
+            })
+            .await??;
+
+        Ok(results)
+    }
+}
@@ -0,0 +1,132 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use diesel::{
+    ExpressionMethods, OptionalExtension, QueryDsl, RunQueryDsl, SelectableHelper, delete,
+    insert_into, update,
+};
+
+use crate::{
+    domain::{
+        entities::mission_templates::{
+            AddMissionTemplateEntity, EditMissionTemplateEntity, MissionTemplateEntity,
+        },
+        errors::{AppError, AppResult},
+        repositories::mission_templates::MissionTemplateRepository,
+    },
+    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::mission_templates},
+};
+
+pub struct MissionTemplatePostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl MissionTemplatePostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+// Templates are private to their owner, someone else's template reads as missing
+fn template_not_found(template_id: i32) -> AppError {
+    AppError::NotFound(format!("Mission template {} not found", template_id))
+}
+
+#[async_trait]
+impl MissionTemplateRepository for MissionTemplatePostgres {
+    async fn add(&self, add_mission_template_entity: AddMissionTemplateEntity) -> AppResult<i32> {
+        let pool = Arc::clone(&self.db_pool);
+
+        let result = tokio::task::spawn_blocking(move || -> AppResult<i32> {
+            let mut conn = pool.get()?;
+            let id = insert_into(mission_templates::table)
+                .values(add_mission_template_entity)
+                .returning(mission_templates::id)
+                .get_result::<i32>(&mut conn)?;
+            Ok(id)
+        })
+        .await??;
+
+        Ok(result)
+    }
+
+    async fn edit(
+        &self,
+        template_id: i32,
+        edit_mission_template_entity: EditMissionTemplateEntity,
+    ) -> AppResult<i32> {
+        let pool = Arc::clone(&self.db_pool);
+
+        let result = tokio::task::spawn_blocking(move || -> AppResult<i32> {
+            let mut conn = pool.get()?;
+            let id = update(mission_templates::table)
+                .filter(mission_templates::id.eq(template_id))
+                .filter(mission_templates::owner_id.eq(edit_mission_template_entity.owner_id))
+                .set(edit_mission_template_entity)
+                .returning(mission_templates::id)
+                .get_result::<i32>(&mut conn)
+                .optional()?
+                .ok_or_else(|| template_not_found(template_id))?;
+            Ok(id)
+        })
+        .await??;
+
+        Ok(result)
+    }
+
+    async fn remove(&self, template_id: i32, owner_id: i32) -> AppResult<()> {
+        let pool = Arc::clone(&self.db_pool);
+
+        tokio::task::spawn_blocking(move || -> AppResult<()> {
+            let mut conn = pool.get()?;
+            let deleted = delete(mission_templates::table)
+                .filter(mission_templates::id.eq(template_id))
+                .filter(mission_templates::owner_id.eq(owner_id))
+                .execute(&mut conn)?;
+            if deleted == 0 {
+                return Err(template_not_found(template_id));
+            }
+            Ok(())
+        })
+        .await??;
+
+        Ok(())
+    }
+
+    async fn get_one(&self, template_id: i32, owner_id: i32) -> AppResult<MissionTemplateEntity> {
+        let pool = Arc::clone(&self.db_pool);
+
+        let result = tokio::task::spawn_blocking(move || -> AppResult<MissionTemplateEntity> {
+            let mut conn = pool.get()?;
+            let template = mission_templates::table
+                .filter(mission_templates::id.eq(template_id))
+                .filter(mission_templates::owner_id.eq(owner_id))
+                .select(MissionTemplateEntity::as_select())
+                .first::<MissionTemplateEntity>(&mut conn)
+                .optional()?
+                .ok_or_else(|| template_not_found(template_id))?;
+            Ok(template)
+        })
+        .await??;
+
+        Ok(result)
+    }
+
+    async fn get_all(&self, owner_id: i32) -> AppResult<Vec<MissionTemplateEntity>> {
+        let pool = Arc::clone(&self.db_pool);
+
+        let results =
+            tokio::task::spawn_blocking(move || -> AppResult<Vec<MissionTemplateEntity>> {
+                let mut conn = pool.get()?;
+                let templates = mission_templates::table
+                    .filter(mission_templates::owner_id.eq(owner_id))
+                    .order((mission_templates::name.asc(), mission_templates::id.asc()))
+                    .select(MissionTemplateEntity::as_select())
+                    .load::<MissionTemplateEntity>(&mut conn)?;
+                Ok(templates)
+            })
+            .await??;
+
+        Ok(results)
+    }
+}
@@ -1,17 +1,24 @@
 use std::sync::Arc;
 
-use anyhow::{Ok, Result};
 use async_trait::async_trait;
 use diesel::prelude::*;
 
 use crate::{
     domain::{
+        errors::{AppError, AppResult},
         repositories::mission_viewing::MissionViewingRepository,
         value_objects::{
-            brawler_model::BrawlerModel, mission_filter::MissionFilter, mission_model::MissionModel,
+            brawler_model::CrewMemberRow,
+            mission_filter::MissionFilter,
+            mission_model::{MissionListItem, MissionModel},
+            mission_statuses::{MissionStatus, MissionStatuses},
+            pagination::{MissionPageQuery, Page},
         },
     },
-    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::crew_memberships},
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad, repositories::mission_listing::load_mission_page,
+        schema::crew_memberships,
+    },
 };
 pub struct MissionViewingPostgres {
     db_pool: Arc<PgPoolSquad>,
@@ -25,7 +32,7 @@ impl MissionViewingPostgres {
 
 #[async_trait]
 impl MissionViewingRepository for MissionViewingPostgres {
-    async fn crew_counting(&self, mission_id: i32) -> Result<i64> {
+    async fn crew_counting(&self, mission_id: i32) -> AppResult<i64> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
         let value = crew_memberships::table
@@ -33,11 +40,10 @@ impl MissionViewingRepository for MissionViewingPostgres {
             .count()
             .first::<i64>(&mut conn)?;
 
-        let count = i64::try_from(value)?;
-        Ok(count)
+        Ok(value)
     }
 
-    async fn get_one(&self, mission_id: i32) -> Result<MissionModel> {
+    async fn get_one(&self, mission_id: i32) -> AppResult<MissionModel> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
         let sql = r#"
@@ -51,6 +57,14 @@ SELECT m.id,
         COUNT(cm.brawler_id) AS crew_count,
         COUNT(cm.brawler_id) AS crew_count,
         m.max_crew,
+        m.min_crew,
+        m.join_policy,
+        m.completion_mode,
+        m.scheduled_start,
+        m.deadline,
+        m.series_id,
+        (SELECT a.url FROM mission_attachments a
+            WHERE a.mission_id = m.id AND a.kind = 'Cover') AS cover_url,
         m.created_at,
         m.created_at,
         m.updated_at
@@ -65,65 +79,42 @@ LIMIT 1
 
         let result = diesel::sql_query(sql)
             .bind::<diesel::sql_types::Int4, _>(mission_id)
-            .get_result::<MissionModel>(&mut conn)?;
+            .get_result::<MissionModel>(&mut conn)
+            .optional()?
+            .ok_or_else(|| AppError::NotFound(format!("Mission {} not found", mission_id)))?;
 
         Ok(result)
     }
 
-    async fn get_all(&self, mission_filter: &MissionFilter) -> Result<Vec<MissionModel>> {
-        use diesel::sql_types::{Nullable, Varchar};
-
+    async fn get_all(
+        &self,
+        mission_filter: &MissionFilter,
+        viewer_id: Option<i32>,
+        page: &MissionPageQuery,
+    ) -> AppResult<Page<MissionListItem>> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
-        let sql = r#"
-SELECT m.id,
-        m.name,
-        m.description,
-        m.status,
-        m.difficulty,
-        m.chief_id,
-        COALESCE(b.display_name, '') AS chief_display_name,
-        COUNT(cm.brawler_id) AS crew_count,
-        COUNT(cm.brawler_id) AS crew_count,
-        m.max_crew,
-        m.created_at,
-        m.created_at,
-        m.updated_at
-FROM missions m
-LEFT JOIN brawlers b ON b.id = m.chief_id
-LEFT JOIN crew_memberships cm ON cm.mission_id = m.id
-WHERE m.deleted_at IS NULL
-    AND ($1::varchar IS NULL OR m.status = $1)
-    AND ($2::varchar IS NULL OR m.name ILIKE $2)
-GROUP BY m.id, b.display_name, m.name, m.description, m.status, m.difficulty, m.chief_id, m.created_at, m.updated_at
-ORDER BY m.created_at DESC
-        "#;
-
-        // Prepare optional bind values
-        let status_bind: Option<String> = mission_filter.status.as_ref().map(|s| s.to_string());
-        let name_bind: Option<String> = mission_filter.name.as_ref().map(|n| format!("%{}%", n));
+        let result = load_mission_page(&mut conn, mission_filter, viewer_id, page)?;
 
-        let rows = diesel::sql_query(sql)
-            .bind::<Nullable<Varchar>, _>(status_bind)
-            .bind::<Nullable<Varchar>, _>(name_bind)
-            .load::<MissionModel>(&mut conn)?;
-
-        Ok(rows)
+        Ok(result)
     }
 
-    async fn get_crew(&self, mission_id: i32) -> Result<Vec<BrawlerModel>> {
+    async fn get_crew(&self, mission_id: i32) -> AppResult<Vec<CrewMemberRow>> {
         let sql = r#"
             SELECT b.display_name,
                     COALESCE(b.avatar_url, '') AS avatar_url,
                     COALESCE(s.success_count, 0) AS mission_success_count,
-                    COALESCE(j.joined_count, 0) AS mission_joined_count
+                    COALESCE(j.joined_count, 0) AS mission_joined_count,
+                    rt.reputation,
+                    COALESCE(rt.rating_count, 0) AS rating_count,
+                    b.xp
             FROM crew_memberships cm
             INNER JOIN brawlers b ON b.id = cm.brawler_id
             LEFT JOIN (
                 SELECT cm2.brawler_id, COUNT(*) AS success_count
                 FROM crew_memberships cm2
                 INNER JOIN missions m2 ON m2.id = cm2.mission_id
-                WHERE m2.status = 'success'
+                WHERE m2.status = $2
                 GROUP BY cm2.brawler_id
             ) s ON s.brawler_id = b.id
             LEFT JOIN (
@@ -131,17 +122,23 @@ ORDER BY m.created_at DESC
                 FROM crew_memberships cm3
                 GROUP BY cm3.brawler_id
             ) j ON j.brawler_id = b.id
+            LEFT JOIN (
+                SELECT ratee_id, ROUND(AVG(score), 2)::float8 AS reputation, COUNT(*) AS rating_count
+                FROM mission_ratings
+                GROUP BY ratee_id
+            ) rt ON rt.ratee_id = b.id
             WHERE cm.mission_id = $1
         "#;
 
         let mut conn = Arc::clone(&self.db_pool).get()?;
         let brawler_list = diesel::sql_query(sql)
             .bind::<diesel::sql_types::Int4, _>(mission_id)
-            .load::<BrawlerModel>(&mut conn)?;
+            .bind::<MissionStatus, _>(MissionStatuses::Completed)
+            .load::<CrewMemberRow>(&mut conn)?;
 
         Ok(brawler_list)
     }
-    async fn is_crew_member(&self, mission_id: i32, brawler_id: i32) -> Result<bool> {
+    async fn is_crew_member(&self, mission_id: i32, brawler_id: i32) -> AppResult<bool> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
         let count = crew_memberships::table
@@ -153,7 +150,7 @@ ORDER BY m.created_at DESC
         Ok(count > 0)
     }
 
-    async fn get_my_memberships(&self, brawler_id: i32) -> Result<Vec<i32>> {
+    async fn get_my_memberships(&self, brawler_id: i32) -> AppResult<Vec<i32>> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
         let mission_ids = crew_memberships::table
@@ -0,0 +1,265 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use diesel::{dsl::now, prelude::*};
+
+use crate::{
+    domain::{
+        entities::mission_waitlist::{AddMissionWaitlistEntity, AddWaitlistPromotionEntity},
+        errors::{AppError, AppResult},
+        repositories::mission_waitlist::MissionWaitlistRepository,
+        value_objects::{
+            mission_join_policy::MissionJoinPolicy,
+            mission_statuses::MissionStatuses,
+            mission_waitlist::{WaitlistPositionModel, WaitlistPromotionModel},
+        },
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        repositories::crew_operation::{count_crew, ensure_not_banned, lock_mission},
+        schema::{crew_memberships, mission_waitlist, waitlist_promotions},
+    },
+};
+
+pub struct MissionWaitlistPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl MissionWaitlistPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+// Fills every free slot of an open mission from the head of its waitlist (FIFO) and
+// records a promotion for each brawler moved into the crew. Must run inside the
+// transaction that freed the slots.
+pub fn promote_from_waitlist(conn: &mut PgConnection, mission_id: i32) -> AppResult<Vec<i32>> {
+    let mission = lock_mission(conn, mission_id)?;
+    // Waitlists only exist for open missions, a chief who tightens the policy has to
+    // approve or invite whoever is still queued.
+    if mission.status != MissionStatuses::Open || mission.join_policy != MissionJoinPolicy::Open {
+        return Ok(Vec::new());
+    }
+
+    let free_slots = mission.max_crew as i64 - count_crew(conn, mission_id)?;
+    if free_slots <= 0 {
+        return Ok(Vec::new());
+    }
+
+    let next_in_line = mission_waitlist::table
+        .filter(mission_waitlist::mission_id.eq(mission_id))
+        .order(mission_waitlist::id.asc())
+        .limit(free_slots)
+        .select((mission_waitlist::id, mission_waitlist::brawler_id))
+        .load::<(i32, i32)>(conn)?;
+
+    let mut promoted = Vec::with_capacity(next_in_line.len());
+    for (waitlist_id, brawler_id) in next_in_line {
+        diesel::delete(mission_waitlist::table)
+            .filter(mission_waitlist::id.eq(waitlist_id))
+            .execute(conn)?;
+
+        diesel::insert_into(crew_memberships::table)
+            .values((
+                crew_memberships::mission_id.eq(mission_id),
+                crew_memberships::brawler_id.eq(brawler_id),
+            ))
+            .execute(conn)?;
+
+        diesel::insert_into(waitlist_promotions::table)
+            .values(AddWaitlistPromotionEntity {
+                mission_id,
+                brawler_id,
+            })
+            .execute(conn)?;
+
+        promoted.push(brawler_id);
+    }
+
+    Ok(promoted)
+}
+
+fn waitlist_position(
+    conn: &mut PgConnection,
+    mission_id: i32,
+    brawler_id: i32,
+) -> AppResult<WaitlistPositionModel> {
+    let waitlist_id = mission_waitlist::table
+        .filter(mission_waitlist::mission_id.eq(mission_id))
+        .filter(mission_waitlist::brawler_id.eq(brawler_id))
+        .select(mission_waitlist::id)
+        .first::<i32>(conn)
+        .optional()?
+        .ok_or_else(|| {
+            AppError::NotFound("You are not on the waitlist of this mission".to_string())
+        })?;
+
+    let position = mission_waitlist::table
+        .filter(mission_waitlist::mission_id.eq(mission_id))
+        .filter(mission_waitlist::id.le(waitlist_id))
+        .count()
+        .get_result::<i64>(conn)?;
+
+    let waiting = mission_waitlist::table
+        .filter(mission_waitlist::mission_id.eq(mission_id))
+        .count()
+        .get_result::<i64>(conn)?;
+
+    Ok(WaitlistPositionModel {
+        mission_id,
+        position,
+        waiting,
+    })
+}
+
+#[async_trait]
+impl MissionWaitlistRepository for MissionWaitlistPostgres {
+    async fn enqueue(&self, mission_id: i32, brawler_id: i32) -> AppResult<WaitlistPositionModel> {
+        let pool = Arc::clone(&self.db_pool);
+
+        let result = tokio::task::spawn_blocking(move || -> AppResult<WaitlistPositionModel> {
+            let mut conn = pool.get()?;
+            conn.transaction::<_, AppError, _>(|conn| {
+                let mission = lock_mission(conn, mission_id)?;
+
+                if mission.chief_id == brawler_id {
+                    return Err(AppError::Forbidden(
+                        "The Chief can not join in his own mission as a crew member!!".to_string(),
+                    ));
+                }
+
+                let already_joined = crew_memberships::table
+                    .filter(crew_memberships::mission_id.eq(mission_id))
+                    .filter(crew_memberships::brawler_id.eq(brawler_id))
+                    .count()
+                    .get_result::<i64>(conn)?;
+                if already_joined > 0 {
+                    return Err(AppError::Conflict(
+                        "You have already joined this mission!!".to_string(),
+                    ));
+                }
+
+                if mission.status != MissionStatuses::Open {
+                    return Err(AppError::Conflict("Mission is not joinable".to_string()));
+                }
+
+                if mission.join_policy != MissionJoinPolicy::Open {
+                    return Err(AppError::Forbidden(
+                        "Only missions open to everyone have a waitlist".to_string(),
+                    ));
+                }
+
+                ensure_not_banned(conn, mission_id, brawler_id)?;
+
+                if count_crew(conn, mission_id)? < mission.max_crew as i64 {
+                    return Err(AppError::Conflict(
+                        "Mission still has free slots, join it directly".to_string(),
+                    ));
+                }
+
+                diesel::insert_into(mission_waitlist::table)
+                    .values(AddMissionWaitlistEntity {
+                        mission_id,
+                        brawler_id,
+                    })
+                    .execute(conn)
+                    .map_err(|e| match AppError::from(e) {
+                        AppError::Conflict(_) => AppError::Conflict(
+                            "You are already on the waitlist of this mission".to_string(),
+                        ),
+                        e => e,
+                    })?;
+
+                waitlist_position(conn, mission_id, brawler_id)
+            })
+        })
+        .await??;
+
+        Ok(result)
+    }
+
+    async fn withdraw(&self, mission_id: i32, brawler_id: i32) -> AppResult<()> {
+        let pool = Arc::clone(&self.db_pool);
+
+        tokio::task::spawn_blocking(move || -> AppResult<()> {
+            let mut conn = pool.get()?;
+            let removed = diesel::delete(mission_waitlist::table)
+                .filter(mission_waitlist::mission_id.eq(mission_id))
+                .filter(mission_waitlist::brawler_id.eq(brawler_id))
+                .execute(&mut conn)?;
+            if removed == 0 {
+                return Err(AppError::NotFound(
+                    "You are not on the waitlist of this mission".to_string(),
+                ));
+            }
+            Ok(())
+        })
+        .await??;
+
+        Ok(())
+    }
+
+    async fn position(&self, mission_id: i32, brawler_id: i32) -> AppResult<WaitlistPositionModel> {
+        let pool = Arc::clone(&self.db_pool);
+
+        let result = tokio::task::spawn_blocking(move || -> AppResult<WaitlistPositionModel> {
+            let mut conn = pool.get()?;
+            waitlist_position(&mut conn, mission_id, brawler_id)
+        })
+        .await??;
+
+        Ok(result)
+    }
+
+    async fn get_promotions(&self, brawler_id: i32) -> AppResult<Vec<WaitlistPromotionModel>> {
+        let pool = Arc::clone(&self.db_pool);
+
+        let results =
+            tokio::task::spawn_blocking(move || -> AppResult<Vec<WaitlistPromotionModel>> {
+                let mut conn = pool.get()?;
+
+                let sql = r#"
+SELECT p.id,
+        p.mission_id,
+        m.name AS mission_name,
+        p.promoted_at,
+        p.seen_at
+FROM waitlist_promotions p
+INNER JOIN missions m ON m.id = p.mission_id
+WHERE p.brawler_id = $1
+    AND p.seen_at IS NULL
+    AND m.deleted_at IS NULL
+ORDER BY p.promoted_at DESC, p.id DESC
+            "#;
+
+                let promotions = diesel::sql_query(sql)
+                    .bind::<diesel::sql_types::Int4, _>(brawler_id)
+                    .load::<WaitlistPromotionModel>(&mut conn)?;
+                Ok(promotions)
+            })
+            .await??;
+
+        Ok(results)
+    }
+
+    async fn mark_promotion_seen(&self, promotion_id: i32, brawler_id: i32) -> AppResult<()> {
+        let pool = Arc::clone(&self.db_pool);
+
+        tokio::task::spawn_blocking(move || -> AppResult<()> {
+            let mut conn = pool.get()?;
+            let updated = diesel::update(waitlist_promotions::table)
+                .filter(waitlist_promotions::id.eq(promotion_id))
+                .filter(waitlist_promotions::brawler_id.eq(brawler_id))
+                .set(waitlist_promotions::seen_at.eq(now))
+                .execute(&mut conn)?;
+            if updated == 0 {
+                return Err(AppError::NotFound("Promotion not found".to_string()));
+            }
+            Ok(())
+        })
+        .await??;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,107 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use diesel::{dsl::now, insert_into, prelude::*, update};
+
+use crate::{
+    domain::{
+        entities::refresh_tokens::{AddRefreshTokenEntity, RefreshTokenEntity},
+        errors::{AppError, AppResult},
+        repositories::refresh_tokens::RefreshTokenRepository,
+    },
+    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::refresh_tokens},
+};
+
+pub struct RefreshTokenPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl RefreshTokenPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl RefreshTokenRepository for RefreshTokenPostgres {
+    async fn store(&self, add_refresh_token_entity: AddRefreshTokenEntity) -> AppResult<()> {
+        let pool = Arc::clone(&self.db_pool);
+
+        tokio::task::spawn_blocking(move || -> AppResult<()> {
+            let mut conn = pool.get()?;
+            insert_into(refresh_tokens::table)
+                .values(add_refresh_token_entity)
+                .execute(&mut conn)?;
+            Ok(())
+        })
+        .await??;
+
+        Ok(())
+    }
+
+    async fn find_by_hash(&self, token_hash: String) -> AppResult<RefreshTokenEntity> {
+        let pool = Arc::clone(&self.db_pool);
+
+        let result = tokio::task::spawn_blocking(move || -> AppResult<RefreshTokenEntity> {
+            let mut conn = pool.get()?;
+            let refresh_token = refresh_tokens::table
+                .filter(refresh_tokens::token_hash.eq(token_hash))
+                .select(RefreshTokenEntity::as_select())
+                .first::<RefreshTokenEntity>(&mut conn)
+                .optional()?
+                .ok_or_else(|| AppError::NotFound("Refresh token not found".to_string()))?;
+            Ok(refresh_token)
+        })
+        .await??;
+
+        Ok(result)
+    }
+
+    async fn rotate(
+        &self,
+        token_id: i32,
+        add_refresh_token_entity: AddRefreshTokenEntity,
+    ) -> AppResult<bool> {
+        let pool = Arc::clone(&self.db_pool);
+
+        let rotated = tokio::task::spawn_blocking(move || -> AppResult<bool> {
+            let mut conn = pool.get()?;
+            conn.transaction::<bool, AppError, _>(|conn| {
+                // Only the request that actually revokes the old token may issue a new one
+                let revoked = update(refresh_tokens::table)
+                    .filter(refresh_tokens::id.eq(token_id))
+                    .filter(refresh_tokens::revoked_at.is_null())
+                    .set(refresh_tokens::revoked_at.eq(now))
+                    .execute(conn)?;
+                if revoked == 0 {
+                    return Ok(false);
+                }
+
+                insert_into(refresh_tokens::table)
+                    .values(add_refresh_token_entity)
+                    .execute(conn)?;
+                Ok(true)
+            })
+        })
+        .await??;
+
+        Ok(rotated)
+    }
+
+    async fn revoke_family(&self, family_id: String) -> AppResult<()> {
+        let pool = Arc::clone(&self.db_pool);
+
+        tokio::task::spawn_blocking(move || -> AppResult<()> {
+            let mut conn = pool.get()?;
+            update(refresh_tokens::table)
+                .filter(refresh_tokens::family_id.eq(family_id))
+                .filter(refresh_tokens::revoked_at.is_null())
+                .set(refresh_tokens::revoked_at.eq(now))
+                .execute(&mut conn)?;
+            Ok(())
+        })
+        .await??;
+
+        Ok(())
+    }
+}
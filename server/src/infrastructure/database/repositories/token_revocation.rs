@@ -0,0 +1,115 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{NaiveDateTime, Utc};
+use diesel::{delete, dsl::now, insert_into, prelude::*, update};
+
+use crate::{
+    domain::{
+        errors::{AppError, AppResult},
+        repositories::token_revocation::TokenRevocationRepository,
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        schema::{brawlers, refresh_tokens, revoked_tokens},
+    },
+};
+
+pub struct TokenRevocationPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl TokenRevocationPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl TokenRevocationRepository for TokenRevocationPostgres {
+    async fn revoke(
+        &self,
+        jti: String,
+        brawler_id: i32,
+        expires_at: NaiveDateTime,
+    ) -> AppResult<()> {
+        let pool = Arc::clone(&self.db_pool);
+
+        tokio::task::spawn_blocking(move || -> AppResult<()> {
+            let mut conn = pool.get()?;
+
+            // Entries are only useful until the token would have expired anyway
+            delete(revoked_tokens::table)
+                .filter(revoked_tokens::expires_at.lt(Utc::now().naive_utc()))
+                .execute(&mut conn)?;
+
+            insert_into(revoked_tokens::table)
+                .values((
+                    revoked_tokens::jti.eq(jti),
+                    revoked_tokens::brawler_id.eq(brawler_id),
+                    revoked_tokens::expires_at.eq(expires_at),
+                ))
+                .on_conflict_do_nothing()
+                .execute(&mut conn)?;
+            Ok(())
+        })
+        .await??;
+
+        Ok(())
+    }
+
+    async fn revoke_all(&self, brawler_id: i32) -> AppResult<()> {
+        let pool = Arc::clone(&self.db_pool);
+
+        tokio::task::spawn_blocking(move || -> AppResult<()> {
+            let mut conn = pool.get()?;
+            conn.transaction::<(), AppError, _>(|conn| {
+                update(brawlers::table)
+                    .filter(brawlers::id.eq(brawler_id))
+                    .set(brawlers::token_generation.eq(brawlers::token_generation + 1))
+                    .execute(conn)?;
+
+                update(refresh_tokens::table)
+                    .filter(refresh_tokens::brawler_id.eq(brawler_id))
+                    .filter(refresh_tokens::revoked_at.is_null())
+                    .set(refresh_tokens::revoked_at.eq(now))
+                    .execute(conn)?;
+                Ok(())
+            })
+        })
+        .await??;
+
+        Ok(())
+    }
+
+    async fn is_revoked(
+        &self,
+        jti: String,
+        brawler_id: i32,
+        token_generation: i32,
+    ) -> AppResult<bool> {
+        let pool = Arc::clone(&self.db_pool);
+
+        let revoked = tokio::task::spawn_blocking(move || -> AppResult<bool> {
+            let mut conn = pool.get()?;
+
+            let current_generation = brawlers::table
+                .filter(brawlers::id.eq(brawler_id))
+                .select(brawlers::token_generation)
+                .first::<i32>(&mut conn)
+                .optional()?;
+            if current_generation != Some(token_generation) {
+                return Ok(true);
+            }
+
+            let revoked_count = revoked_tokens::table
+                .filter(revoked_tokens::jti.eq(jti))
+                .count()
+                .get_result::<i64>(&mut conn)?;
+            Ok(revoked_count > 0)
+        })
+        .await??;
+
+        Ok(revoked)
+    }
+}
@@ -1,6 +1,10 @@
 // @generated automatically by Diesel CLI.
 
 diesel::table! {
+    use diesel::sql_types::*;
+    use crate::domain::value_objects::mission_statuses::MissionStatus;
+    use crate::infrastructure::database::sql_types::Tsvector;
+
     brawlers (id) {
         id -> Int4,
         #[max_length = 255]
@@ -15,10 +19,17 @@ diesel::table! {
         avatar_url -> Nullable<Varchar>,
         #[max_length = 255]
         avatar_public_id -> Nullable<Varchar>,
+        token_generation -> Int4,
+        is_admin -> Bool,
+        xp -> Int4,
     }
 }
 
 diesel::table! {
+    use diesel::sql_types::*;
+    use crate::domain::value_objects::mission_statuses::MissionStatus;
+    use crate::infrastructure::database::sql_types::Tsvector;
+
     crew_memberships (mission_id, brawler_id) {
         mission_id -> Int4,
         brawler_id -> Int4,
@@ -27,13 +38,268 @@ diesel::table! {
 }
 
 diesel::table! {
-    missions (id) {
+    use diesel::sql_types::*;
+    use crate::domain::value_objects::mission_statuses::MissionStatus;
+    use crate::infrastructure::database::sql_types::Tsvector;
+
+    crew_removals (id) {
+        id -> Int4,
+        mission_id -> Int4,
+        brawler_id -> Int4,
+        removed_by -> Int4,
+        reason -> Nullable<Text>,
+        banned -> Bool,
+        removed_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::domain::value_objects::mission_statuses::MissionStatus;
+    use crate::infrastructure::database::sql_types::Tsvector;
+
+    join_requests (id) {
+        id -> Int4,
+        mission_id -> Int4,
+        brawler_id -> Int4,
+        #[max_length = 32]
+        status -> Varchar,
+        created_at -> Timestamp,
+        decided_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::domain::value_objects::mission_statuses::MissionStatus;
+    use crate::infrastructure::database::sql_types::Tsvector;
+
+    leaderboard_refreshes (id) {
+        id -> Bool,
+        refreshed_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::domain::value_objects::mission_statuses::MissionStatus;
+    use crate::infrastructure::database::sql_types::Tsvector;
+
+    leaderboards (board, time_window, brawler_id) {
+        board -> Text,
+        time_window -> Text,
+        brawler_id -> Int4,
+        score -> Float8,
+        position -> Int4,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::domain::value_objects::mission_statuses::MissionStatus;
+    use crate::infrastructure::database::sql_types::Tsvector;
+
+    mission_attachments (id) {
+        id -> Int4,
+        mission_id -> Int4,
+        uploaded_by -> Int4,
+        #[max_length = 16]
+        kind -> Varchar,
+        #[max_length = 255]
+        file_name -> Varchar,
+        #[max_length = 64]
+        content_type -> Varchar,
+        size_bytes -> Int4,
+        #[max_length = 512]
+        url -> Varchar,
+        #[max_length = 255]
+        public_id -> Varchar,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::domain::value_objects::mission_statuses::MissionStatus;
+    use crate::infrastructure::database::sql_types::Tsvector;
+
+    mission_comments (id) {
+        id -> Int4,
+        mission_id -> Int4,
+        author_id -> Int4,
+        parent_id -> Nullable<Int4>,
+        body -> Text,
+        pinned -> Bool,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+        edited_at -> Nullable<Timestamp>,
+        deleted_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::domain::value_objects::mission_statuses::MissionStatus;
+    use crate::infrastructure::database::sql_types::Tsvector;
+
+    mission_invitations (id) {
+        id -> Int4,
+        mission_id -> Int4,
+        brawler_id -> Int4,
+        invited_by -> Int4,
+        #[max_length = 32]
+        status -> Varchar,
+        created_at -> Timestamp,
+        responded_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::domain::value_objects::mission_statuses::MissionStatus;
+    use crate::infrastructure::database::sql_types::Tsvector;
+
+    mission_outcome_proposals (id) {
+        id -> Int4,
+        mission_id -> Int4,
+        proposed_by -> Int4,
+        outcome -> MissionStatus,
+        reason -> Nullable<Text>,
+        eligible_voters -> Int4,
+        required_votes -> Int4,
+        closes_at -> Timestamp,
+        #[max_length = 32]
+        resolution -> Nullable<Varchar>,
+        resolved_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::domain::value_objects::mission_statuses::MissionStatus;
+    use crate::infrastructure::database::sql_types::Tsvector;
+
+    mission_outcome_votes (proposal_id, brawler_id) {
+        proposal_id -> Int4,
+        brawler_id -> Int4,
+        agree -> Bool,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::domain::value_objects::mission_statuses::MissionStatus;
+    use crate::infrastructure::database::sql_types::Tsvector;
+
+    mission_ratings (id) {
+        id -> Int4,
+        mission_id -> Int4,
+        rater_id -> Int4,
+        ratee_id -> Int4,
+        score -> Int4,
+        mission_score -> Nullable<Int4>,
+        comment -> Nullable<Text>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::domain::value_objects::mission_statuses::MissionStatus;
+    use crate::infrastructure::database::sql_types::Tsvector;
+
+    mission_series (id) {
         id -> Int4,
+        chief_id -> Int4,
         #[max_length = 255]
         name -> Varchar,
         description -> Nullable<Text>,
+        #[max_length = 50]
+        difficulty -> Varchar,
+        min_crew -> Int4,
+        max_crew -> Int4,
+        #[max_length = 32]
+        join_policy -> Varchar,
+        duration_minutes -> Nullable<Int4>,
+        #[max_length = 16]
+        frequency -> Varchar,
+        #[max_length = 32]
+        weekdays -> Varchar,
+        starts_at -> Timestamp,
+        ends_on -> Nullable<Date>,
+        occurrence_count -> Nullable<Int4>,
+        generated_count -> Int4,
+        next_occurrence -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::domain::value_objects::mission_statuses::MissionStatus;
+    use crate::infrastructure::database::sql_types::Tsvector;
+
+    mission_status_history (id) {
+        id -> Int4,
+        mission_id -> Int4,
+        changed_by -> Int4,
+        from_status -> Nullable<MissionStatus>,
+        to_status -> MissionStatus,
+        reason -> Nullable<Text>,
+        changed_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::domain::value_objects::mission_statuses::MissionStatus;
+    use crate::infrastructure::database::sql_types::Tsvector;
+
+    mission_templates (id) {
+        id -> Int4,
+        owner_id -> Int4,
         #[max_length = 255]
-        status -> Varchar,
+        name -> Varchar,
+        description -> Nullable<Text>,
+        #[max_length = 50]
+        difficulty -> Varchar,
+        min_crew -> Int4,
+        max_crew -> Int4,
+        #[max_length = 32]
+        join_policy -> Varchar,
+        duration_minutes -> Nullable<Int4>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::domain::value_objects::mission_statuses::MissionStatus;
+    use crate::infrastructure::database::sql_types::Tsvector;
+
+    mission_waitlist (id) {
+        id -> Int4,
+        mission_id -> Int4,
+        brawler_id -> Int4,
+        enqueued_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::domain::value_objects::mission_statuses::MissionStatus;
+    use crate::infrastructure::database::sql_types::Tsvector;
+
+    missions (id) {
+        id -> Int4,
+        #[max_length = 255]
+        name -> Varchar,
+        description -> Nullable<Text>,
+        status -> MissionStatus,
         chief_id -> Int4,
         created_at -> Timestamp,
         updated_at -> Timestamp,
@@ -41,15 +307,131 @@ diesel::table! {
         max_crew -> Int4,
         #[max_length = 50]
         difficulty -> Varchar,
+        #[max_length = 32]
+        join_policy -> Varchar,
+        search_vector -> Nullable<Tsvector>,
+        scheduled_start -> Nullable<Timestamp>,
+        deadline -> Nullable<Timestamp>,
+        min_crew -> Int4,
+        series_id -> Nullable<Int4>,
+        #[max_length = 32]
+        completion_mode -> Varchar,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::domain::value_objects::mission_statuses::MissionStatus;
+    use crate::infrastructure::database::sql_types::Tsvector;
+
+    refresh_tokens (id) {
+        id -> Int4,
+        brawler_id -> Int4,
+        #[max_length = 36]
+        family_id -> Varchar,
+        #[max_length = 64]
+        token_hash -> Varchar,
+        expires_at -> Timestamp,
+        created_at -> Timestamp,
+        revoked_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::domain::value_objects::mission_statuses::MissionStatus;
+    use crate::infrastructure::database::sql_types::Tsvector;
+
+    revoked_tokens (jti) {
+        #[max_length = 36]
+        jti -> Varchar,
+        brawler_id -> Int4,
+        expires_at -> Timestamp,
+        revoked_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::domain::value_objects::mission_statuses::MissionStatus;
+    use crate::infrastructure::database::sql_types::Tsvector;
+
+    waitlist_promotions (id) {
+        id -> Int4,
+        mission_id -> Int4,
+        brawler_id -> Int4,
+        promoted_at -> Timestamp,
+        seen_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::domain::value_objects::mission_statuses::MissionStatus;
+    use crate::infrastructure::database::sql_types::Tsvector;
+
+    xp_events (id) {
+        id -> Int4,
+        brawler_id -> Int4,
+        mission_id -> Nullable<Int4>,
+        #[max_length = 32]
+        reason -> Varchar,
+        amount -> Int4,
+        created_at -> Timestamp,
     }
 }
 
 diesel::joinable!(crew_memberships -> brawlers (brawler_id));
 diesel::joinable!(crew_memberships -> missions (mission_id));
+diesel::joinable!(crew_removals -> missions (mission_id));
+diesel::joinable!(join_requests -> brawlers (brawler_id));
+diesel::joinable!(join_requests -> missions (mission_id));
+diesel::joinable!(leaderboards -> brawlers (brawler_id));
+diesel::joinable!(mission_attachments -> brawlers (uploaded_by));
+diesel::joinable!(mission_attachments -> missions (mission_id));
+diesel::joinable!(mission_comments -> brawlers (author_id));
+diesel::joinable!(mission_comments -> missions (mission_id));
+diesel::joinable!(mission_invitations -> missions (mission_id));
+diesel::joinable!(mission_outcome_proposals -> brawlers (proposed_by));
+diesel::joinable!(mission_outcome_proposals -> missions (mission_id));
+diesel::joinable!(mission_outcome_votes -> brawlers (brawler_id));
+diesel::joinable!(mission_outcome_votes -> mission_outcome_proposals (proposal_id));
+diesel::joinable!(mission_ratings -> missions (mission_id));
+diesel::joinable!(mission_series -> brawlers (chief_id));
+diesel::joinable!(mission_status_history -> brawlers (changed_by));
+diesel::joinable!(mission_status_history -> missions (mission_id));
+diesel::joinable!(mission_templates -> brawlers (owner_id));
+diesel::joinable!(mission_waitlist -> brawlers (brawler_id));
+diesel::joinable!(mission_waitlist -> missions (mission_id));
 diesel::joinable!(missions -> brawlers (chief_id));
+diesel::joinable!(missions -> mission_series (series_id));
+diesel::joinable!(refresh_tokens -> brawlers (brawler_id));
+diesel::joinable!(revoked_tokens -> brawlers (brawler_id));
+diesel::joinable!(waitlist_promotions -> brawlers (brawler_id));
+diesel::joinable!(waitlist_promotions -> missions (mission_id));
+diesel::joinable!(xp_events -> brawlers (brawler_id));
+diesel::joinable!(xp_events -> missions (mission_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
     brawlers,
     crew_memberships,
+    crew_removals,
+    join_requests,
+    leaderboard_refreshes,
+    leaderboards,
+    mission_attachments,
+    mission_comments,
+    mission_invitations,
+    mission_outcome_proposals,
+    mission_outcome_votes,
+    mission_ratings,
+    mission_series,
+    mission_status_history,
+    mission_templates,
+    mission_waitlist,
     missions,
+    refresh_tokens,
+    revoked_tokens,
+    waitlist_promotions,
+    xp_events,
 );
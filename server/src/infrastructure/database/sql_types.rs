@@ -0,0 +1,5 @@
+// Postgres types only the schema needs, imported through diesel.toml
+
+#[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+#[diesel(postgres_type(name = "tsvector", schema = "pg_catalog"))]
+pub struct Tsvector;
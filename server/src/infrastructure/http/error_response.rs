@@ -0,0 +1,52 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+
+use crate::{
+    config::{config_loader::get_stage, stage::Stage},
+    domain::errors::AppError,
+};
+
+#[derive(Debug, Serialize)]
+pub struct ErrorBody {
+    pub code: &'static str,
+    pub message: String,
+    pub details: Option<serde_json::Value>,
+}
+
+impl IntoResponse for AppError {
+    fn into_response(self) -> Response {
+        let status = match &self {
+            AppError::NotFound(_) => StatusCode::NOT_FOUND,
+            AppError::Forbidden(_) => StatusCode::FORBIDDEN,
+            AppError::Conflict(_) => StatusCode::CONFLICT,
+            AppError::Validation(_) => StatusCode::BAD_REQUEST,
+            AppError::InvalidStateTransition(_) => StatusCode::CONFLICT,
+            AppError::MissionFull(_) => StatusCode::CONFLICT,
+            AppError::Unauthorized(_) => StatusCode::UNAUTHORIZED,
+            AppError::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+
+        let (message, details) = match &self {
+            AppError::Internal(e) => {
+                tracing::error!("{:?}", e);
+                // Never leak internals to production clients
+                let details = (get_stage() != Stage::Production)
+                    .then(|| serde_json::Value::String(format!("{:#}", e)));
+                ("Internal server error".to_string(), details)
+            }
+            e => (e.to_string(), None),
+        };
+
+        let body = ErrorBody {
+            code: self.code(),
+            message,
+            details,
+        };
+
+        (status, Json(body)).into_response()
+    }
+}
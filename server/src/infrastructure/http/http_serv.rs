@@ -19,9 +19,17 @@ use tracing::info;
 
 use crate::{
     config::config_model::DotEnvyConfig,
+    domain::{
+        errors::AppError,
+        value_objects::{
+            brawler_progression::XpRules, crew_requirement::CrewLimits,
+            mission_consensus::ConsensusRules, mission_retention::MissionRetention,
+        },
+    },
     infrastructure::{
         database::postgresql_connection::PgPoolSquad,
         http::routers::{self},
+        scheduler,
     },
 };
 
@@ -34,16 +42,32 @@ fn static_serve() -> Router {
     Router::new().fallback_service(service)
 }
 
-fn api_serve(db_pool: Arc<PgPoolSquad>) -> Router {
+fn api_serve(config: &DotEnvyConfig, db_pool: Arc<PgPoolSquad>, xp_rules: XpRules) -> Router {
+    let crew_limits = CrewLimits::new(config.crew.min_crew, config.crew.max_crew);
+    let retention = MissionRetention::new(config.retention.deleted_missions);
+    let consensus_rules =
+        ConsensusRules::new(config.consensus.quorum, config.consensus.voting_window);
+
     Router::new()
-        .nest("/brawler", routers::brawlers::routes(Arc::clone(&db_pool)))
+        .nest(
+            "/brawler",
+            routers::brawlers::routes(Arc::clone(&db_pool), xp_rules.clone()),
+        )
         .nest(
             "/view",
-            routers::mission_viewing::routes(Arc::clone(&db_pool)),
+            routers::mission_viewing::routes(Arc::clone(&db_pool), xp_rules.clone())
+                .merge(routers::mission_comments::routes(Arc::clone(&db_pool)))
+                .merge(routers::mission_attachments::view_routes(Arc::clone(&db_pool))),
         )
         .nest(
             "/mission",
-            routers::mission_operation::routes(Arc::clone(&db_pool)),
+            routers::mission_operation::routes(Arc::clone(&db_pool), xp_rules.clone()).merge(
+                routers::mission_consensus::routes(
+                    Arc::clone(&db_pool),
+                    consensus_rules,
+                    xp_rules.clone(),
+                ),
+            ),
         )
         .nest(
             "/crew",
@@ -51,20 +75,59 @@ fn api_serve(db_pool: Arc<PgPoolSquad>) -> Router {
         )
         .nest(
             "/mission-management",
-            routers::mission_management::routes(Arc::clone(&db_pool)),
+            routers::mission_management::routes(
+                Arc::clone(&db_pool),
+                crew_limits,
+                retention,
+            )
+            .merge(routers::mission_attachments::routes(Arc::clone(&db_pool))),
+        )
+        .nest(
+            "/mission-series",
+            routers::mission_series::routes(Arc::clone(&db_pool), crew_limits),
+        )
+        .nest(
+            "/mission-template",
+            routers::mission_templates::routes(Arc::clone(&db_pool), crew_limits),
+        )
+        .nest(
+            "/debrief",
+            routers::mission_debrief::routes(Arc::clone(&db_pool)),
+        )
+        .nest(
+            "/leaderboard",
+            routers::leaderboards::routes(Arc::clone(&db_pool)),
+        )
+        .nest(
+            "/waitlist",
+            routers::mission_waitlist::routes(Arc::clone(&db_pool)),
         )
         .nest(
             "/authentication",
-            routers::authentication::routes(Arc::clone(&db_pool)),
+            routers::authentication::routes(Arc::clone(&db_pool), xp_rules),
         )
         .nest("/util", routers::default_router::routes())
-        .fallback(|| async { (StatusCode::NOT_FOUND, "API not found") })
+        .fallback(|| async { AppError::NotFound("API not found".to_string()) })
 }
 
 pub async fn start(config: Arc<DotEnvyConfig>, db_pool: Arc<PgPoolSquad>) -> Result<()> {
+    // Built once here, every completed mission is scored with the same rules
+    let xp_rules = XpRules {
+        base: config.xp.base,
+        per_crew_member: config.xp.per_crew_member,
+        easy_weight: config.xp.easy_weight,
+        medium_weight: config.xp.medium_weight,
+        hard_weight: config.xp.hard_weight,
+        chief_bonus: config.xp.chief_bonus,
+        level_step: config.xp.level_step,
+        rank_levels: config.xp.rank_levels,
+    };
+
+    scheduler::spawn(Arc::clone(&db_pool), &config, xp_rules.clone());
+
     let app = Router::new()
         .nest_service("/uploads", ServeDir::new("uploads"))
-        .nest("/api", api_serve(db_pool))
+        .nest("/api", api_serve(&config, db_pool, xp_rules))
         .fallback_service(static_serve())
         // .route("/health_check", get(default_router::health_check)
         // .route("/make-error", get(default_router::make_error)
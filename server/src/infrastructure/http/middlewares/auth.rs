@@ -1,35 +1,118 @@
+use std::sync::Arc;
+
 use axum::{
-    extract::Request,
-    http::{StatusCode, header},
+    extract::{Request, State},
+    http::header,
     middleware::Next,
     response::Response,
 };
 
-use crate::{config::config_loader::get_jwt_env, infrastructure::jwt::verify_token};
+use crate::{
+    config::config_loader::get_jwt_env,
+    domain::{
+        errors::AppError,
+        repositories::{brawlers::BrawlerRepository, token_revocation::TokenRevocationRepository},
+    },
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{brawlers::BrawlerPostgres, token_revocation::TokenRevocationPostgres},
+        },
+        jwt::{jwt_model::Claims, verify_token},
+    },
+};
+
+pub async fn auth(
+    State(db_pool): State<Arc<PgPoolSquad>>,
+    mut req: Request,
+    next: Next,
+) -> Result<Response, AppError> {
+    let header = req
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .ok_or_else(|| AppError::Unauthorized("Missing authorization header".to_string()))?;
+
+    let (user_id, claims) = authenticate(db_pool, header).await?;
+
+    req.extensions_mut().insert(user_id);
+    req.extensions_mut().insert(claims);
+
+    Ok(next.run(req).await)
+}
 
-pub async fn auth(mut req: Request, next: Next) -> Result<Response, StatusCode> {
+// For public routes that behave differently for a signed-in caller. A missing or stale
+// token is treated as an anonymous request so the public listing keeps working.
+pub async fn optional_auth(
+    State(db_pool): State<Arc<PgPoolSquad>>,
+    mut req: Request,
+    next: Next,
+) -> Result<Response, AppError> {
     let header = req
         .headers()
         .get(header::AUTHORIZATION)
         .and_then(|value| value.to_str().ok())
-        .ok_or(StatusCode::UNAUTHORIZED)?;
+        .map(str::to_string);
+
+    if let Some(header) = header {
+        match authenticate(db_pool, &header).await {
+            Ok((user_id, claims)) => {
+                req.extensions_mut().insert(user_id);
+                req.extensions_mut().insert(claims);
+            }
+            Err(AppError::Unauthorized(_)) => {}
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(next.run(req).await)
+}
+
+// Layered inside `auth`, which has already put the caller's id on the request. Admins are
+// granted in the database, there is no endpoint that hands the flag out.
+pub async fn admin(
+    State(db_pool): State<Arc<PgPoolSquad>>,
+    req: Request,
+    next: Next,
+) -> Result<Response, AppError> {
+    let user_id = req
+        .extensions()
+        .get::<i32>()
+        .copied()
+        .ok_or_else(|| AppError::Unauthorized("Sign in as an admin".to_string()))?;
 
+    let brawler = BrawlerPostgres::new(db_pool).find_by_id(user_id).await?;
+    if !brawler.is_admin {
+        return Err(AppError::Forbidden("Only an admin can do this".to_string()));
+    }
+
+    Ok(next.run(req).await)
+}
+
+async fn authenticate(db_pool: Arc<PgPoolSquad>, header: &str) -> Result<(i32, Claims), AppError> {
     let token = header
         .strip_prefix("Bearer ")
-        .ok_or(StatusCode::UNAUTHORIZED)?
+        .ok_or_else(|| AppError::Unauthorized("Expected a Bearer token".to_string()))?
         .to_string();
 
     let jwt_env = get_jwt_env().unwrap();
     let secret = jwt_env.secret;
 
-    let claims = verify_token(secret, token).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let claims = verify_token(secret, token)
+        .map_err(|_| AppError::Unauthorized("Invalid or expired token".to_string()))?;
 
     let user_id = claims
         .sub
         .parse::<i32>()
-        .map_err(|_| StatusCode::UNAUTHORIZED)?;
+        .map_err(|_| AppError::Unauthorized("Invalid token subject".to_string()))?;
 
-    req.extensions_mut().insert(user_id);
+    // A valid signature is not enough: the token may have been logged out
+    let revoked = TokenRevocationPostgres::new(db_pool)
+        .is_revoked(claims.jti.clone(), user_id, claims.gen)
+        .await?;
+    if revoked {
+        return Err(AppError::Unauthorized("Token has been revoked".to_string()));
+    }
 
-    Ok(next.run(req).await)
+    Ok((user_id, claims))
 }
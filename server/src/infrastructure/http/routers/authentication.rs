@@ -1,7 +1,6 @@
-use std::sync::Arc;
 use axum::{
-    Json, Router,
-    extract::{State},
+    Extension, Json, Router,
+    extract::State,
     http::StatusCode,
     response::IntoResponse,
     routing::{get, post},
@@ -10,65 +9,209 @@ use axum_extra::extract::{
     CookieJar,
     cookie::{Cookie, SameSite},
 };
+use std::sync::Arc;
 
 use crate::{
     application::use_cases::authentication::AuthenticationUseCase,
-    domain::repositories::brawlers::BrawlerRepository,
+    domain::{
+        errors::AppError,
+        repositories::{
+            brawlers::BrawlerRepository, refresh_tokens::RefreshTokenRepository,
+            token_revocation::TokenRevocationRepository,
+        },
+        value_objects::brawler_progression::XpRules,
+    },
     infrastructure::{
-        database::{postgresql_connection::PgPoolSquad, repositories::brawlers::BrawlerPostgres},
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{
+                brawlers::BrawlerPostgres, refresh_tokens::RefreshTokenPostgres,
+                token_revocation::TokenRevocationPostgres,
+            },
+        },
+        http::middlewares::auth::auth,
+        jwt::{
+            authentication_model::RefreshTokenModel,
+            jwt_model::{Claims, Passport},
+        },
     },
 };
 
-pub async fn login<T>(
-    State(user_case): State<Arc<AuthenticationUseCase<T>>>,
+const REFRESH_COOKIE_PATH: &str = "/api/authentication";
+
+pub fn add_session_cookies(jar: CookieJar, passport: &Passport) -> CookieJar {
+    let token_cookie = Cookie::build(("token", passport.token.clone()))
+        .path("/")
+        .same_site(SameSite::Lax)
+        .http_only(false) // Let JS read it for syncing to localStorage
+        .build();
+    let jar = jar.add(token_cookie);
+
+    match &passport.refresh_token {
+        Some(refresh_token) => {
+            let refresh_cookie = Cookie::build(("refresh_token", refresh_token.clone()))
+                .path(REFRESH_COOKIE_PATH)
+                .same_site(SameSite::Strict)
+                .http_only(true)
+                .build();
+            jar.add(refresh_cookie)
+        }
+        None => jar,
+    }
+}
+
+pub fn remove_session_cookies(jar: CookieJar) -> CookieJar {
+    jar.remove(Cookie::build("token").path("/"))
+        .remove(Cookie::build("refresh_token").path(REFRESH_COOKIE_PATH))
+}
+
+pub async fn login<T1, T2, T3>(
+    State(user_case): State<Arc<AuthenticationUseCase<T1, T2, T3>>>,
     jar: CookieJar,
     Json(model): Json<crate::infrastructure::jwt::authentication_model::LoginModel>,
 ) -> impl IntoResponse
 where
-    T: BrawlerRepository + Send + Sync,
+    T1: BrawlerRepository + Send + Sync,
+    T2: RefreshTokenRepository + Send + Sync,
+    T3: TokenRevocationRepository + Send + Sync,
 {
     match user_case.login(model).await {
-        Ok(passport) => {
-            let cookie = Cookie::build(("token", passport.token.clone()))
-                .path("/")
-                .same_site(SameSite::Lax)
-                .http_only(false) // Let JS read it for syncing to localStorage
-                .build();
+        Ok(passport) => (add_session_cookies(jar, &passport), Json(passport)).into_response(),
+
+        Err(e) => e.into_response(),
+    }
+}
 
-            (jar.add(cookie), Json(passport)).into_response()
+pub async fn refresh<T1, T2, T3>(
+    State(user_case): State<Arc<AuthenticationUseCase<T1, T2, T3>>>,
+    jar: CookieJar,
+    model: Option<Json<RefreshTokenModel>>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: RefreshTokenRepository + Send + Sync,
+    T3: TokenRevocationRepository + Send + Sync,
+{
+    // Browsers send the http-only cookie, other clients may post the token in the body
+    let refresh_token = match (model, jar.get("refresh_token")) {
+        (Some(Json(model)), _) => model.refresh_token,
+        (None, Some(cookie)) => cookie.value().to_string(),
+        (None, None) => {
+            return AppError::Unauthorized("Missing refresh token".to_string()).into_response();
         }
+    };
+
+    match user_case.refresh(refresh_token).await {
+        Ok(passport) => (add_session_cookies(jar, &passport), Json(passport)).into_response(),
 
-        Err(e) => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+        Err(e) => {
+            let jar = jar.remove(Cookie::build("refresh_token").path(REFRESH_COOKIE_PATH));
+            (jar, e).into_response()
+        }
     }
 }
 
-pub async fn get_me<T>(
-    State(user_case): State<Arc<AuthenticationUseCase<T>>>,
+pub async fn get_me<T1, T2, T3>(
+    State(user_case): State<Arc<AuthenticationUseCase<T1, T2, T3>>>,
     jar: CookieJar,
 ) -> impl IntoResponse
 where
-    T: BrawlerRepository + Send + Sync,
+    T1: BrawlerRepository + Send + Sync,
+    T2: RefreshTokenRepository + Send + Sync,
+    T3: TokenRevocationRepository + Send + Sync,
 {
     if let Some(token_cookie) = jar.get("token") {
         let token = token_cookie.value();
-        if let Ok(claims) = crate::infrastructure::jwt::verify_token(crate::config::config_loader::get_jwt_env().unwrap().secret, token.to_string()) {
-            if let Ok(user_id) = claims.sub.parse::<i32>() {
-                if let Ok(passport) = user_case.get_me(user_id).await {
-                    return (StatusCode::OK, Json(passport)).into_response();
-                }
+        if let Ok(claims) = crate::infrastructure::jwt::verify_token(
+            crate::config::config_loader::get_jwt_env().unwrap().secret,
+            token.to_string(),
+        ) {
+            if let Ok(passport) = user_case.get_me(claims).await {
+                return (StatusCode::OK, Json(passport)).into_response();
             }
         }
     }
-    
-    (StatusCode::UNAUTHORIZED, "Unauthorized").into_response()
+
+    AppError::Unauthorized("Unauthorized".to_string()).into_response()
+}
+
+pub async fn logout<T1, T2, T3>(
+    State(user_case): State<Arc<AuthenticationUseCase<T1, T2, T3>>>,
+    Extension(claims): Extension<Claims>,
+    jar: CookieJar,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: RefreshTokenRepository + Send + Sync,
+    T3: TokenRevocationRepository + Send + Sync,
+{
+    let refresh_token = jar
+        .get("refresh_token")
+        .map(|cookie| cookie.value().to_string());
+
+    match user_case.logout(claims, refresh_token).await {
+        Ok(_) => (remove_session_cookies(jar), (StatusCode::OK, "Logged out")).into_response(),
+
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn logout_all<T1, T2, T3>(
+    State(user_case): State<Arc<AuthenticationUseCase<T1, T2, T3>>>,
+    Extension(user_id): Extension<i32>,
+    jar: CookieJar,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: RefreshTokenRepository + Send + Sync,
+    T3: TokenRevocationRepository + Send + Sync,
+{
+    match user_case.logout_all(user_id).await {
+        Ok(_) => (
+            remove_session_cookies(jar),
+            (StatusCode::OK, "Logged out of all sessions"),
+        )
+            .into_response(),
+
+        Err(e) => e.into_response(),
+    }
 }
 
-pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
-    let repository = BrawlerPostgres::new(db_pool);
-    let user_case = AuthenticationUseCase::new(Arc::new(repository));
+pub fn routes(db_pool: Arc<PgPoolSquad>, xp_rules: XpRules) -> Router {
+    let brawler_repository = BrawlerPostgres::new(Arc::clone(&db_pool));
+    let refresh_token_repository = RefreshTokenPostgres::new(Arc::clone(&db_pool));
+    let token_revocation_repository = TokenRevocationPostgres::new(Arc::clone(&db_pool));
+    let user_case = AuthenticationUseCase::new(
+        Arc::new(brawler_repository),
+        Arc::new(refresh_token_repository),
+        Arc::new(token_revocation_repository),
+        xp_rules,
+    );
+
+    let protected_routes = Router::new()
+        .route(
+            "/logout",
+            post(logout::<BrawlerPostgres, RefreshTokenPostgres, TokenRevocationPostgres>),
+        )
+        .route(
+            "/logout-all",
+            post(logout_all::<BrawlerPostgres, RefreshTokenPostgres, TokenRevocationPostgres>),
+        )
+        .route_layer(axum::middleware::from_fn_with_state(db_pool, auth));
 
     Router::new()
-        .route("/login", post(login::<BrawlerPostgres>))
-        .route("/me", get(get_me::<BrawlerPostgres>))
+        .merge(protected_routes)
+        .route(
+            "/login",
+            post(login::<BrawlerPostgres, RefreshTokenPostgres, TokenRevocationPostgres>),
+        )
+        .route(
+            "/refresh",
+            post(refresh::<BrawlerPostgres, RefreshTokenPostgres, TokenRevocationPostgres>),
+        )
+        .route(
+            "/me",
+            get(get_me::<BrawlerPostgres, RefreshTokenPostgres, TokenRevocationPostgres>),
+        )
         .with_state(Arc::new(user_case))
 }
@@ -2,31 +2,50 @@ use std::sync::Arc;
 
 use axum::{
     Extension, Json, Router,
-    extract::{State},
+    extract::{Query, State},
     http::StatusCode,
     response::IntoResponse,
     routing::{get, post},
 };
-use axum_extra::extract::{
-    CookieJar,
-    cookie::{Cookie, SameSite},
-};
+use axum_extra::extract::CookieJar;
 
 use crate::{
-    application::use_cases::brawlers::BrawlersUseCase,
+    application::use_cases::{authentication::AuthenticationUseCase, brawlers::BrawlersUseCase},
     domain::{
-        repositories::brawlers::BrawlerRepository,
-        value_objects::{brawler_model::RegisterBrawlerModel, uploaded_img::UploadBase64Img},
+        repositories::{
+            brawlers::BrawlerRepository, refresh_tokens::RefreshTokenRepository,
+            token_revocation::TokenRevocationRepository,
+        },
+        value_objects::{
+            brawler_model::RegisterBrawlerModel, brawler_progression::XpRules,
+            pagination::MissionPageQuery, uploaded_img::UploadBase64Img,
+        },
     },
     infrastructure::{
-        database::{postgresql_connection::PgPoolSquad, repositories::brawlers::BrawlerPostgres},
-        http::middlewares::auth::auth,
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{
+                brawlers::BrawlerPostgres, refresh_tokens::RefreshTokenPostgres,
+                token_revocation::TokenRevocationPostgres,
+            },
+        },
+        http::{middlewares::auth::auth, routers::authentication::add_session_cookies},
     },
 };
 
-pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
-    let repository = BrawlerPostgres::new(db_pool);
-    let user_case = BrawlersUseCase::new(Arc::new(repository));
+pub fn routes(db_pool: Arc<PgPoolSquad>, xp_rules: XpRules) -> Router {
+    let repository = Arc::new(BrawlerPostgres::new(Arc::clone(&db_pool)));
+    let user_case = BrawlersUseCase::new(Arc::clone(&repository), xp_rules.clone());
+
+    // Registering also signs the brawler in, so it needs the refresh token store
+    let refresh_token_repository = RefreshTokenPostgres::new(Arc::clone(&db_pool));
+    let token_revocation_repository = TokenRevocationPostgres::new(Arc::clone(&db_pool));
+    let authentication_use_case = AuthenticationUseCase::new(
+        repository,
+        Arc::new(refresh_token_repository),
+        Arc::new(token_revocation_repository),
+        xp_rules,
+    );
 
     let protected_routes = Router::new()
         .route("/avatar", post(upload_avatar))
@@ -34,53 +53,53 @@ pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
         .route("/stats", get(get_profile_stats))
         .route("/username", post(update_username))
         .route("/avatar-url", post(update_avatar_url))
-        .route_layer(axum::middleware::from_fn(auth));
+        .route_layer(axum::middleware::from_fn_with_state(db_pool, auth));
+
+    let public_routes = Router::new()
+        .route(
+            "/register",
+            post(register::<BrawlerPostgres, RefreshTokenPostgres, TokenRevocationPostgres>),
+        )
+        .with_state(Arc::new(authentication_use_case));
 
     Router::new()
         .merge(protected_routes)
-        .route("/register", post(register::<BrawlerPostgres>))
         .with_state(Arc::new(user_case))
+        .merge(public_routes)
 }
 
 pub async fn get_missions<T>(
     State(brawlers_use_case): State<Arc<BrawlersUseCase<T>>>,
     Extension(brawler_id): Extension<i32>,
+    Query(page): Query<MissionPageQuery>,
 ) -> impl IntoResponse
 where
     T: BrawlerRepository + Send + Sync,
 {
-    match brawlers_use_case.get_missions(brawler_id).await {
+    match brawlers_use_case.get_missions(brawler_id, &page).await {
         Ok(missions) => (StatusCode::OK, Json(missions)).into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
-pub async fn register<T>(
-    State(user_case): State<Arc<BrawlersUseCase<T>>>,
+pub async fn register<T1, T2, T3>(
+    State(user_case): State<Arc<AuthenticationUseCase<T1, T2, T3>>>,
     jar: CookieJar,
     Json(model): Json<RegisterBrawlerModel>,
 ) -> impl IntoResponse
 where
-    T: BrawlerRepository + Send + Sync,
+    T1: BrawlerRepository + Send + Sync,
+    T2: RefreshTokenRepository + Send + Sync,
+    T3: TokenRevocationRepository + Send + Sync,
 {
     match user_case.register(model).await {
-        Ok(passport) => {
-            let cookie = Cookie::build(("token", passport.token.clone()))
-                .path("/")
-                .same_site(SameSite::Lax)
-                .http_only(false)
-                .build();
-
-            (jar.add(cookie), (StatusCode::CREATED, Json(passport))).into_response()
-        }
+        Ok(passport) => (
+            add_session_cookies(jar, &passport),
+            (StatusCode::CREATED, Json(passport)),
+        )
+            .into_response(),
 
-        Err(e) => {
-            if e.to_string().contains("Username already exists") {
-                (StatusCode::CONFLICT, e.to_string()).into_response()
-            } else {
-                (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response()
-            }
-        }
+        Err(e) => e.into_response(),
     }
 }
 
@@ -102,7 +121,7 @@ where
         },
         Err(e) => {
             tracing::error!("Avatar upload failed for user {}: {}", user_id, e);
-            (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response()
+            e.into_response()
         },
     }
 }
@@ -116,7 +135,7 @@ where
 {
     match brawlers_use_case.get_profile_stats(brawler_id).await {
         Ok(stats) => (StatusCode::OK, Json(stats)).into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
@@ -133,13 +152,7 @@ where
         .await
     {
         Ok(_) => (StatusCode::OK, "Username updated successfully").into_response(),
-        Err(e) => {
-            if e.to_string().contains("Username already exists") {
-                (StatusCode::CONFLICT, e.to_string()).into_response()
-            } else {
-                (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response()
-            }
-        }
+        Err(e) => e.into_response(),
     }
 }
 
@@ -161,7 +174,7 @@ where
         },
         Err(e) => {
             tracing::error!("Avatar URL update failed for user {}: {}", brawler_id, e);
-            (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response()
+            e.into_response()
         },
     }
 }
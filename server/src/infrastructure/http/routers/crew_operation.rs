@@ -1,70 +1,77 @@
 use std::sync::Arc;
 
 use axum::{
-    Extension, Router,
+    Extension, Json, Router,
     extract::{Path, State},
     http::StatusCode,
     middleware,
     response::IntoResponse,
-    routing::{delete, post},
+    routing::{delete, get, patch, post},
 };
 
 use crate::{
     application::use_cases::crew_operation::CrewOperationUseCase,
-    domain::repositories::{
-        crew_operation::CrewOperationRepository, mission_viewing::MissionViewingRepository,
+    domain::{
+        repositories::{
+            crew_operation::CrewOperationRepository, join_requests::JoinRequestRepository,
+            mission_viewing::MissionViewingRepository,
+        },
+        value_objects::{
+            crew_management::KickCrewMemberModel,
+            join_requests::{InviteBrawlerModel, JoinOutcome},
+        },
     },
     infrastructure::{
         database::{
             postgresql_connection::PgPoolSquad,
             repositories::{
-                crew_operation::CrewOperationPostgres, mission_viewing::MissionViewingPostgres,
+                crew_operation::CrewOperationPostgres, join_requests::JoinRequestPostgres,
+                mission_viewing::MissionViewingPostgres,
             },
         },
         http::middlewares::auth::auth,
     },
 };
 
-pub async fn join<T1, T2>(
-    State(user_case): State<Arc<CrewOperationUseCase<T1, T2>>>,
+pub async fn join<T1, T2, T3>(
+    State(user_case): State<Arc<CrewOperationUseCase<T1, T2, T3>>>,
     Extension(user_id): Extension<i32>,
     Path(mission_id): Path<i32>,
 ) -> impl IntoResponse
 where
     T1: CrewOperationRepository + Send + Sync + 'static,
     T2: MissionViewingRepository + Send + Sync,
+    T3: JoinRequestRepository + Send + Sync,
 {
     match user_case.join(mission_id, user_id).await {
-        Ok(_) => (
+        Ok(JoinOutcome::Joined) => (
             StatusCode::OK,
             format!("Join Mission_id:{} completed", mission_id),
         )
             .into_response(),
 
-        Err(e) => {
-            let status = if e.to_string().contains("can not join")
-                || e.to_string().contains("already joined")
-                || e.to_string().contains("not joinable")
-                || e.to_string().contains("is full")
-                || e.to_string().contains("not leavable")
-            {
-                StatusCode::BAD_REQUEST
-            } else {
-                StatusCode::INTERNAL_SERVER_ERROR
-            };
-            (status, e.to_string()).into_response()
-        }
+        Ok(JoinOutcome::Requested { request_id }) => (
+            StatusCode::ACCEPTED,
+            format!(
+                "Join request {} for Mission_id:{} is waiting for the chief",
+                request_id, mission_id
+            ),
+        )
+            .into_response(),
+
+        Err(e) => e.into_response(),
     }
 }
 
-pub async fn leave<T1, T2>(
-    State(user_case): State<Arc<CrewOperationUseCase<T1, T2>>>,
+pub async fn leave<T1, T2, T3>(
+    State(user_case): State<Arc<CrewOperationUseCase<T1, T2, T3>>>,
     Extension(user_id): Extension<i32>,
     Path(mission_id): Path<i32>,
 ) -> impl IntoResponse
 where
     T1: CrewOperationRepository + Send + Sync + 'static,
     T2: MissionViewingRepository + Send + Sync,
+    T3: JoinRequestRepository + Send + Sync,
 {
     match user_case.leave(mission_id, user_id).await {
         Ok(_) => (
@@ -73,33 +80,202 @@ where
         )
             .into_response(),
 
-        Err(e) => {
-            let status = if e.to_string().contains("can not join")
-                || e.to_string().contains("already joined")
-                || e.to_string().contains("not joinable")
-                || e.to_string().contains("is full")
-                || e.to_string().contains("not leavable")
-            {
-                StatusCode::BAD_REQUEST
-            } else {
-                StatusCode::INTERNAL_SERVER_ERROR
-            };
-            (status, e.to_string()).into_response()
-        }
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn kick<T1, T2, T3>(
+    State(user_case): State<Arc<CrewOperationUseCase<T1, T2, T3>>>,
+    Extension(user_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+    Json(model): Json<KickCrewMemberModel>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository + Send + Sync + 'static,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: JoinRequestRepository + Send + Sync,
+{
+    let brawler_id = model.brawler_id;
+
+    match user_case.kick(mission_id, user_id, model).await {
+        Ok(_) => (
+            StatusCode::OK,
+            format!(
+                "Brawler {} removed from Mission_id:{}",
+                brawler_id, mission_id
+            ),
+        )
+            .into_response(),
+
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn get_pending_requests<T1, T2, T3>(
+    State(user_case): State<Arc<CrewOperationUseCase<T1, T2, T3>>>,
+    Extension(user_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository + Send + Sync + 'static,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: JoinRequestRepository + Send + Sync,
+{
+    match user_case.get_pending_requests(mission_id, user_id).await {
+        Ok(model) => (StatusCode::OK, Json(model)).into_response(),
+
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn approve_request<T1, T2, T3>(
+    State(user_case): State<Arc<CrewOperationUseCase<T1, T2, T3>>>,
+    Extension(user_id): Extension<i32>,
+    Path(request_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository + Send + Sync + 'static,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: JoinRequestRepository + Send + Sync,
+{
+    match user_case.approve_request(request_id, user_id).await {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Join request {} approved", request_id),
+        )
+            .into_response(),
+
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn reject_request<T1, T2, T3>(
+    State(user_case): State<Arc<CrewOperationUseCase<T1, T2, T3>>>,
+    Extension(user_id): Extension<i32>,
+    Path(request_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository + Send + Sync + 'static,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: JoinRequestRepository + Send + Sync,
+{
+    match user_case.reject_request(request_id, user_id).await {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Join request {} rejected", request_id),
+        )
+            .into_response(),
+
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn invite<T1, T2, T3>(
+    State(user_case): State<Arc<CrewOperationUseCase<T1, T2, T3>>>,
+    Extension(user_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+    Json(invite_brawler_model): Json<InviteBrawlerModel>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository + Send + Sync + 'static,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: JoinRequestRepository + Send + Sync,
+{
+    match user_case
+        .invite(mission_id, user_id, invite_brawler_model.brawler_id)
+        .await
+    {
+        Ok(invitation_id) => (StatusCode::CREATED, invitation_id.to_string()).into_response(),
+
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn get_pending_invitations<T1, T2, T3>(
+    State(user_case): State<Arc<CrewOperationUseCase<T1, T2, T3>>>,
+    Extension(user_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository + Send + Sync + 'static,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: JoinRequestRepository + Send + Sync,
+{
+    match user_case.get_pending_invitations(user_id).await {
+        Ok(model) => (StatusCode::OK, Json(model)).into_response(),
+
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn accept_invitation<T1, T2, T3>(
+    State(user_case): State<Arc<CrewOperationUseCase<T1, T2, T3>>>,
+    Extension(user_id): Extension<i32>,
+    Path(invitation_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository + Send + Sync + 'static,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: JoinRequestRepository + Send + Sync,
+{
+    match user_case.accept_invitation(invitation_id, user_id).await {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Invitation {} accepted", invitation_id),
+        )
+            .into_response(),
+
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn decline_invitation<T1, T2, T3>(
+    State(user_case): State<Arc<CrewOperationUseCase<T1, T2, T3>>>,
+    Extension(user_id): Extension<i32>,
+    Path(invitation_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository + Send + Sync + 'static,
+    T2: MissionViewingRepository + Send + Sync,
+    T3: JoinRequestRepository + Send + Sync,
+{
+    match user_case.decline_invitation(invitation_id, user_id).await {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Invitation {} declined", invitation_id),
+        )
+            .into_response(),
+
+        Err(e) => e.into_response(),
     }
 }
 
 pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
     let crew_operation_repository = CrewOperationPostgres::new(Arc::clone(&db_pool));
     let viewing_repositiory = MissionViewingPostgres::new(Arc::clone(&db_pool));
+    let join_request_repository = JoinRequestPostgres::new(Arc::clone(&db_pool));
     let user_case = CrewOperationUseCase::new(
         Arc::new(crew_operation_repository),
         Arc::new(viewing_repositiory),
+        Arc::new(join_request_repository),
     );
 
     Router::new()
         .route("/join/{mission_id}", post(join))
         .route("/leave/{mission_id}", delete(leave))
-        .route_layer(middleware::from_fn(auth))
+        .route("/kick/{mission_id}", post(kick))
+        .route("/requests/{mission_id}", get(get_pending_requests))
+        .route("/requests/{request_id}/approve", patch(approve_request))
+        .route("/requests/{request_id}/reject", patch(reject_request))
+        .route("/invitations", get(get_pending_invitations))
+        .route("/invitations/{mission_id}", post(invite))
+        .route(
+            "/invitations/{invitation_id}/accept",
+            patch(accept_invitation),
+        )
+        .route(
+            "/invitations/{invitation_id}/decline",
+            patch(decline_invitation),
+        )
+        .route_layer(middleware::from_fn_with_state(Arc::clone(&db_pool), auth))
         .with_state(Arc::new(user_case))
 }
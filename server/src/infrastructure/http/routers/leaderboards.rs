@@ -0,0 +1,59 @@
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    routing::get,
+};
+
+use crate::{
+    application::use_cases::leaderboards::LeaderboardUseCase,
+    domain::{
+        repositories::leaderboards::LeaderboardRepository,
+        value_objects::leaderboards::{LeaderboardBoard, LeaderboardQuery},
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad, repositories::leaderboards::LeaderboardPostgres,
+    },
+};
+
+pub async fn get_board<T>(
+    State(user_case): State<Arc<LeaderboardUseCase<T>>>,
+    Path(board): Path<LeaderboardBoard>,
+    query: Query<LeaderboardQuery>,
+) -> impl IntoResponse
+where
+    T: LeaderboardRepository + Send + Sync,
+{
+    match user_case.get_board(board, &query).await {
+        Ok(model) => (StatusCode::OK, Json(model)).into_response(),
+
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn get_weekly_top_ten<T>(
+    State(user_case): State<Arc<LeaderboardUseCase<T>>>,
+) -> impl IntoResponse
+where
+    T: LeaderboardRepository + Send + Sync,
+{
+    match user_case.get_weekly_top_ten().await {
+        Ok(model) => (StatusCode::OK, Json(model)).into_response(),
+
+        Err(e) => e.into_response(),
+    }
+}
+
+// Public like the mission views, the boards are rebuilt by the scheduler
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let leaderboard_repository = LeaderboardPostgres::new(Arc::clone(&db_pool));
+    let user_case = LeaderboardUseCase::new(Arc::new(leaderboard_repository));
+
+    Router::new()
+        .route("/weekly-top-ten", get(get_weekly_top_ten))
+        .route("/{board}", get(get_board))
+        .with_state(Arc::new(user_case))
+}
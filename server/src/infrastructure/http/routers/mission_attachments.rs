@@ -0,0 +1,157 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get, post, put},
+};
+
+use crate::{
+    application::use_cases::mission_attachments::MissionAttachmentUseCase,
+    domain::{
+        repositories::{
+            mission_attachments::MissionAttachmentRepository,
+            mission_viewing::MissionViewingRepository,
+        },
+        value_objects::{
+            mission_attachment::UploadMissionAttachmentModel, uploaded_img::UploadBase64Img,
+        },
+    },
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{
+                mission_attachments::MissionAttachmentPostgres,
+                mission_viewing::MissionViewingPostgres,
+            },
+        },
+        http::middlewares::auth::auth,
+    },
+};
+
+type AttachmentUseCase =
+    MissionAttachmentUseCase<MissionAttachmentPostgres, MissionViewingPostgres>;
+
+pub async fn get_all<T1, T2>(
+    State(user_case): State<Arc<MissionAttachmentUseCase<T1, T2>>>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: MissionAttachmentRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match user_case.get_all(mission_id).await {
+        Ok(model) => (StatusCode::OK, Json(model)).into_response(),
+
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn upload_file<T1, T2>(
+    State(user_case): State<Arc<MissionAttachmentUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+    Json(model): Json<UploadMissionAttachmentModel>,
+) -> impl IntoResponse
+where
+    T1: MissionAttachmentRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match user_case.upload_file(mission_id, user_id, model).await {
+        Ok(attachment) => (StatusCode::CREATED, Json(attachment)).into_response(),
+
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn upload_cover<T1, T2>(
+    State(user_case): State<Arc<MissionAttachmentUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+    Json(model): Json<UploadBase64Img>,
+) -> impl IntoResponse
+where
+    T1: MissionAttachmentRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match user_case
+        .upload_cover(mission_id, user_id, model.base64_string)
+        .await
+    {
+        Ok(attachment) => (StatusCode::CREATED, Json(attachment)).into_response(),
+
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn remove<T1, T2>(
+    State(user_case): State<Arc<MissionAttachmentUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Path((mission_id, attachment_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: MissionAttachmentRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match user_case.remove(mission_id, attachment_id, user_id).await {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Remove attachment_id: {} completed!!", attachment_id),
+        )
+            .into_response(),
+
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn remove_cover<T1, T2>(
+    State(user_case): State<Arc<MissionAttachmentUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: MissionAttachmentRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match user_case.remove_cover(mission_id, user_id).await {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Remove cover of mission_id: {} completed!!", mission_id),
+        )
+            .into_response(),
+
+        Err(e) => e.into_response(),
+    }
+}
+
+fn use_case(db_pool: &Arc<PgPoolSquad>) -> Arc<AttachmentUseCase> {
+    let attachment_repository = MissionAttachmentPostgres::new(Arc::clone(db_pool));
+    let viewing_repositiory = MissionViewingPostgres::new(Arc::clone(db_pool));
+    Arc::new(MissionAttachmentUseCase::new(
+        Arc::new(attachment_repository),
+        Arc::new(viewing_repositiory),
+    ))
+}
+
+// Public listing, mounted under /view next to the mission
+pub fn view_routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    Router::new()
+        .route("/{mission_id}/attachments", get(get_all))
+        .with_state(use_case(&db_pool))
+}
+
+// Chief only uploads and removals, mounted under /mission-management
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    Router::new()
+        .route("/{mission_id}/attachments", post(upload_file))
+        .route("/{mission_id}/attachments/{attachment_id}", delete(remove))
+        .route(
+            "/{mission_id}/cover",
+            put(upload_cover).delete(remove_cover),
+        )
+        .route_layer(middleware::from_fn_with_state(Arc::clone(&db_pool), auth))
+        .with_state(use_case(&db_pool))
+}
@@ -0,0 +1,220 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{get, patch, post},
+};
+
+use crate::{
+    application::use_cases::mission_comments::MissionCommentUseCase,
+    domain::{
+        repositories::{
+            mission_comments::MissionCommentRepository, mission_viewing::MissionViewingRepository,
+        },
+        value_objects::{
+            mission_comment::{AddMissionCommentModel, EditMissionCommentModel},
+            pagination::CommentPageQuery,
+        },
+    },
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{
+                mission_comments::MissionCommentPostgres, mission_viewing::MissionViewingPostgres,
+            },
+        },
+        http::middlewares::auth::{auth, optional_auth},
+    },
+};
+
+pub async fn get_comments<T1, T2>(
+    State(user_case): State<Arc<MissionCommentUseCase<T1, T2>>>,
+    viewer: Option<Extension<i32>>,
+    Path(mission_id): Path<i32>,
+    page: Query<CommentPageQuery>,
+) -> impl IntoResponse
+where
+    T1: MissionCommentRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    let viewer_id = viewer.map(|Extension(user_id)| user_id);
+
+    match user_case.get_comments(mission_id, viewer_id, &page).await {
+        Ok(model) => (StatusCode::OK, Json(model)).into_response(),
+
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn get_replies<T1, T2>(
+    State(user_case): State<Arc<MissionCommentUseCase<T1, T2>>>,
+    viewer: Option<Extension<i32>>,
+    Path((mission_id, comment_id)): Path<(i32, i32)>,
+    page: Query<CommentPageQuery>,
+) -> impl IntoResponse
+where
+    T1: MissionCommentRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    let viewer_id = viewer.map(|Extension(user_id)| user_id);
+
+    match user_case
+        .get_replies(mission_id, comment_id, viewer_id, &page)
+        .await
+    {
+        Ok(model) => (StatusCode::OK, Json(model)).into_response(),
+
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn add<T1, T2>(
+    State(user_case): State<Arc<MissionCommentUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+    Json(model): Json<AddMissionCommentModel>,
+) -> impl IntoResponse
+where
+    T1: MissionCommentRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match user_case.add(mission_id, user_id, model).await {
+        Ok(comment_id) => {
+            let json_value = serde_json::json!({
+                "comment_id": comment_id,
+            });
+            (StatusCode::CREATED, axum::Json(json_value)).into_response()
+        }
+
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn edit<T1, T2>(
+    State(user_case): State<Arc<MissionCommentUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Path((mission_id, comment_id)): Path<(i32, i32)>,
+    Json(model): Json<EditMissionCommentModel>,
+) -> impl IntoResponse
+where
+    T1: MissionCommentRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match user_case.edit(mission_id, comment_id, user_id, model).await {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Edit comment_id: {} completed!!", comment_id),
+        )
+            .into_response(),
+
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn remove<T1, T2>(
+    State(user_case): State<Arc<MissionCommentUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Path((mission_id, comment_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: MissionCommentRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match user_case.remove(mission_id, comment_id, user_id).await {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Remove comment_id: {} completed!!", comment_id),
+        )
+            .into_response(),
+
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn pin<T1, T2>(
+    State(user_case): State<Arc<MissionCommentUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Path((mission_id, comment_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: MissionCommentRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match user_case
+        .set_pinned(mission_id, comment_id, user_id, true)
+        .await
+    {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Pin comment_id: {} completed!!", comment_id),
+        )
+            .into_response(),
+
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn unpin<T1, T2>(
+    State(user_case): State<Arc<MissionCommentUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Path((mission_id, comment_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: MissionCommentRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match user_case
+        .set_pinned(mission_id, comment_id, user_id, false)
+        .await
+    {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Unpin comment_id: {} completed!!", comment_id),
+        )
+            .into_response(),
+
+        Err(e) => e.into_response(),
+    }
+}
+
+// Mounted under /view next to the mission it belongs to. Reading is public where the
+// mission allows it, everything else needs a signed-in brawler.
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let comment_repository = MissionCommentPostgres::new(Arc::clone(&db_pool));
+    let viewing_repositiory = MissionViewingPostgres::new(Arc::clone(&db_pool));
+    let user_case = Arc::new(MissionCommentUseCase::new(
+        Arc::new(comment_repository),
+        Arc::new(viewing_repositiory),
+    ));
+
+    let public = Router::new()
+        .route("/{mission_id}/comments", get(get_comments))
+        .route(
+            "/{mission_id}/comments/{comment_id}/replies",
+            get(get_replies),
+        )
+        .route_layer(middleware::from_fn_with_state(
+            Arc::clone(&db_pool),
+            optional_auth,
+        ))
+        .with_state(Arc::clone(&user_case));
+
+    let signed_in = Router::new()
+        .route("/{mission_id}/comments", post(add))
+        .route(
+            "/{mission_id}/comments/{comment_id}",
+            patch(edit).delete(remove),
+        )
+        .route(
+            "/{mission_id}/comments/{comment_id}/pin",
+            post(pin).delete(unpin),
+        )
+        .route_layer(middleware::from_fn_with_state(Arc::clone(&db_pool), auth))
+        .with_state(user_case);
+
+    public.merge(signed_in)
+}
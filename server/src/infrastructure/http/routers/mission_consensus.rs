@@ -0,0 +1,141 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{get, patch, post},
+};
+
+use crate::{
+    application::use_cases::mission_consensus::MissionConsensusUseCase,
+    domain::{
+        repositories::{
+            mission_consensus::MissionConsensusRepository,
+            mission_viewing::MissionViewingRepository,
+        },
+        value_objects::{
+            brawler_progression::XpRules,
+            mission_consensus::{
+                ConsensusRules, OutcomeVoteModel, ProposeOutcomeModel, ResolveDisputeModel,
+            },
+        },
+    },
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{
+                mission_consensus::MissionConsensusPostgres,
+                mission_viewing::MissionViewingPostgres,
+            },
+        },
+        http::middlewares::auth::{admin, auth},
+    },
+};
+
+pub async fn get_proposal<T1, T2>(
+    State(user_case): State<Arc<MissionConsensusUseCase<T1, T2>>>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: MissionConsensusRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match user_case.get_proposal(mission_id).await {
+        Ok(model) => (StatusCode::OK, Json(model)).into_response(),
+
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn propose<T1, T2>(
+    State(user_case): State<Arc<MissionConsensusUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+    Json(model): Json<ProposeOutcomeModel>,
+) -> impl IntoResponse
+where
+    T1: MissionConsensusRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match user_case.propose(mission_id, user_id, model).await {
+        Ok(proposal_id) => {
+            let json_value = serde_json::json!({
+                "proposal_id": proposal_id,
+            });
+            (StatusCode::CREATED, axum::Json(json_value)).into_response()
+        }
+
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn vote<T1, T2>(
+    State(user_case): State<Arc<MissionConsensusUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+    Json(model): Json<OutcomeVoteModel>,
+) -> impl IntoResponse
+where
+    T1: MissionConsensusRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match user_case.vote(mission_id, user_id, model).await {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Vote on mission_id: {} completed!!", mission_id),
+        )
+            .into_response(),
+
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn resolve_dispute<T1, T2>(
+    State(user_case): State<Arc<MissionConsensusUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+    Json(model): Json<ResolveDisputeModel>,
+) -> impl IntoResponse
+where
+    T1: MissionConsensusRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match user_case.resolve_dispute(mission_id, user_id, model).await {
+        Ok(mission_id) => (
+            StatusCode::OK,
+            format!("Resolve dispute on mission_id: {} completed!!", mission_id),
+        )
+            .into_response(),
+
+        Err(e) => e.into_response(),
+    }
+}
+
+// Mounted under /mission next to the chief's own status changes
+pub fn routes(db_pool: Arc<PgPoolSquad>, rules: ConsensusRules, xp_rules: XpRules) -> Router {
+    let consensus_repository = MissionConsensusPostgres::new(Arc::clone(&db_pool), xp_rules);
+    let viewing_repositiory = MissionViewingPostgres::new(Arc::clone(&db_pool));
+    let user_case = Arc::new(MissionConsensusUseCase::new(
+        Arc::new(consensus_repository),
+        Arc::new(viewing_repositiory),
+        rules,
+    ));
+
+    let crew = Router::new()
+        .route("/{mission_id}/proposal", get(get_proposal).post(propose))
+        .route("/{mission_id}/proposal/vote", post(vote))
+        .route_layer(middleware::from_fn_with_state(Arc::clone(&db_pool), auth))
+        .with_state(Arc::clone(&user_case));
+
+    // `admin` reads the caller `auth` put on the request, so it has to be layered first
+    let admins = Router::new()
+        .route("/{mission_id}/resolve", patch(resolve_dispute))
+        .route_layer(middleware::from_fn_with_state(Arc::clone(&db_pool), admin))
+        .route_layer(middleware::from_fn_with_state(Arc::clone(&db_pool), auth))
+        .with_state(user_case);
+
+    crew.merge(admins)
+}
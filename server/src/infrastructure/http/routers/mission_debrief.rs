@@ -0,0 +1,106 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{get, post},
+};
+
+use crate::{
+    application::use_cases::mission_debrief::MissionDebriefUseCase,
+    domain::{
+        repositories::{
+            mission_debrief::MissionDebriefRepository, mission_viewing::MissionViewingRepository,
+        },
+        value_objects::mission_debrief::{RateChiefModel, RateCrewMemberModel},
+    },
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{
+                mission_debrief::MissionDebriefPostgres, mission_viewing::MissionViewingPostgres,
+            },
+        },
+        http::middlewares::auth::auth,
+    },
+};
+
+pub async fn get_debrief<T1, T2>(
+    State(user_case): State<Arc<MissionDebriefUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: MissionDebriefRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match user_case.get_debrief(mission_id, user_id).await {
+        Ok(model) => (StatusCode::OK, Json(model)).into_response(),
+
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn rate_chief<T1, T2>(
+    State(user_case): State<Arc<MissionDebriefUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+    Json(model): Json<RateChiefModel>,
+) -> impl IntoResponse
+where
+    T1: MissionDebriefRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match user_case.rate_chief(mission_id, user_id, model).await {
+        Ok(rating_id) => {
+            let json_value = serde_json::json!({
+                "rating_id": rating_id,
+            });
+            (StatusCode::CREATED, axum::Json(json_value)).into_response()
+        }
+
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn rate_crew_member<T1, T2>(
+    State(user_case): State<Arc<MissionDebriefUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Path((mission_id, brawler_id)): Path<(i32, i32)>,
+    Json(model): Json<RateCrewMemberModel>,
+) -> impl IntoResponse
+where
+    T1: MissionDebriefRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match user_case
+        .rate_crew_member(mission_id, user_id, brawler_id, model)
+        .await
+    {
+        Ok(rating_id) => {
+            let json_value = serde_json::json!({
+                "rating_id": rating_id,
+            });
+            (StatusCode::CREATED, axum::Json(json_value)).into_response()
+        }
+
+        Err(e) => e.into_response(),
+    }
+}
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let debrief_repository = MissionDebriefPostgres::new(Arc::clone(&db_pool));
+    let viewing_repositiory = MissionViewingPostgres::new(Arc::clone(&db_pool));
+    let user_case =
+        MissionDebriefUseCase::new(Arc::new(debrief_repository), Arc::new(viewing_repositiory));
+
+    Router::new()
+        .route("/{mission_id}", get(get_debrief))
+        .route("/{mission_id}/chief", post(rate_chief))
+        .route("/{mission_id}/crew/{brawler_id}", post(rate_crew_member))
+        .route_layer(middleware::from_fn_with_state(Arc::clone(&db_pool), auth))
+        .with_state(Arc::new(user_case))
+}
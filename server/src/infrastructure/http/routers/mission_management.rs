@@ -6,7 +6,7 @@ use axum::{
     http::StatusCode,
     middleware,
     response::IntoResponse,
-    routing::{delete, patch, post},
+    routing::{delete, get, patch, post},
 };
 
 use crate::{
@@ -16,7 +16,13 @@ use crate::{
             mission_management::MissionManagementRepository,
             mission_viewing::MissionViewingRepository,
         },
-        value_objects::mission_model::{AddMissionModel, EditMissionModel},
+        value_objects::{
+            crew_management::TransferChiefModel,
+            crew_requirement::CrewLimits,
+            mission_model::{AddMissionModel, CloneMissionModel, EditMissionModel},
+            mission_retention::MissionRetention,
+            mission_status_history::MissionTransitionModel,
+        },
     },
     infrastructure::{
         database::{
@@ -47,16 +53,7 @@ where
             (StatusCode::CREATED, axum::Json(json_value)).into_response()
         }
 
-        Err(e) => {
-            let status = if e.to_string().contains("characters long")
-                || e.to_string().contains("taken by brawler")
-            {
-                StatusCode::BAD_REQUEST
-            } else {
-                StatusCode::INTERNAL_SERVER_ERROR
-            };
-            (status, e.to_string()).into_response()
-        }
+        Err(e) => e.into_response(),
     }
 }
 
@@ -77,16 +74,31 @@ where
         )
             .into_response(),
 
-        Err(e) => {
-            let status = if e.to_string().contains("characters long")
-                || e.to_string().contains("taken by brawler")
-            {
-                StatusCode::BAD_REQUEST
-            } else {
-                StatusCode::INTERNAL_SERVER_ERROR
-            };
-            (status, e.to_string()).into_response()
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn clone_mission<T1, T2>(
+    State(user_case): State<Arc<MissionManagementUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+    body: Option<Json<CloneMissionModel>>,
+) -> impl IntoResponse
+where
+    T1: MissionManagementRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    let model = body.map(|Json(model)| model).unwrap_or_default();
+
+    match user_case.clone_mission(mission_id, user_id, model).await {
+        Ok(mission_id) => {
+            let json_value = serde_json::json!({
+                "mission_id": mission_id,
+            });
+            (StatusCode::CREATED, axum::Json(json_value)).into_response()
         }
+
+        Err(e) => e.into_response(),
     }
 }
 
@@ -106,16 +118,42 @@ where
         )
             .into_response(),
 
-        Err(e) => {
-            let status = if e.to_string().contains("characters long")
-                || e.to_string().contains("taken by brawler")
-            {
-                StatusCode::BAD_REQUEST
-            } else {
-                StatusCode::INTERNAL_SERVER_ERROR
-            };
-            (status, e.to_string()).into_response()
-        }
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn get_deleted<T1, T2>(
+    State(user_case): State<Arc<MissionManagementUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T1: MissionManagementRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match user_case.get_deleted(user_id).await {
+        Ok(missions) => (StatusCode::OK, Json(missions)).into_response(),
+
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn restore<T1, T2>(
+    State(user_case): State<Arc<MissionManagementUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: MissionManagementRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match user_case.restore(mission_id, user_id).await {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Restore mission_id: {} completed!!", mission_id),
+        )
+            .into_response(),
+
+        Err(e) => e.into_response(),
     }
 }
 
@@ -123,42 +161,69 @@ pub async fn start<T1, T2>(
     State(user_case): State<Arc<MissionManagementUseCase<T1, T2>>>,
     Extension(user_id): Extension<i32>,
     Path(mission_id): Path<i32>,
+    body: Option<Json<MissionTransitionModel>>,
 ) -> impl IntoResponse
 where
     T1: MissionManagementRepository + Send + Sync,
     T2: MissionViewingRepository + Send + Sync,
 {
-    match user_case.start(mission_id, user_id).await {
+    let reason = body.and_then(|Json(model)| model.reason);
+
+    match user_case.start(mission_id, user_id, reason).await {
         Ok(_) => (
             StatusCode::OK,
             format!("Start mission_id: {} completed!!", mission_id),
         )
             .into_response(),
 
-        Err(e) => {
-            let status = if e.to_string().contains("Only the Chief") {
-                StatusCode::FORBIDDEN
-            } else if e.to_string().contains("Mission is not in Open status") {
-                StatusCode::BAD_REQUEST
-            } else {
-                StatusCode::INTERNAL_SERVER_ERROR
-            };
-            (status, e.to_string()).into_response()
-        }
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn transfer_chief<T1, T2>(
+    State(user_case): State<Arc<MissionManagementUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+    Json(model): Json<TransferChiefModel>,
+) -> impl IntoResponse
+where
+    T1: MissionManagementRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match user_case.transfer_chief(mission_id, user_id, model).await {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Transfer chief of mission_id: {} completed!!", mission_id),
+        )
+            .into_response(),
+
+        Err(e) => e.into_response(),
     }
 }
 
-pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+pub fn routes(
+    db_pool: Arc<PgPoolSquad>,
+    crew_limits: CrewLimits,
+    retention: MissionRetention,
+) -> Router {
     let mission_repository = MissionManagementPostgres::new(Arc::clone(&db_pool));
     let viewing_repositiory = MissionViewingPostgres::new(Arc::clone(&db_pool));
-    let user_case =
-        MissionManagementUseCase::new(Arc::new(mission_repository), Arc::new(viewing_repositiory));
+    let user_case = MissionManagementUseCase::new(
+        Arc::new(mission_repository),
+        Arc::new(viewing_repositiory),
+        crew_limits,
+        retention,
+    );
 
     Router::new()
         .route("/", post(add))
+        .route("/deleted", get(get_deleted))
         .route("/{mission_id}", patch(edit))
         .route("/{mission_id}", delete(remove))
+        .route("/{mission_id}/restore", post(restore))
         .route("/{mission_id}/start", post(start))
-        .route_layer(middleware::from_fn(auth))
+        .route("/{mission_id}/clone", post(clone_mission))
+        .route("/{mission_id}/transfer-chief", post(transfer_chief))
+        .route_layer(middleware::from_fn_with_state(Arc::clone(&db_pool), auth))
         .with_state(Arc::new(user_case))
 }
@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use axum::{
-    Extension, Router,
+    Extension, Json, Router,
     extract::{Path, State},
     http::StatusCode,
     middleware,
@@ -11,8 +11,14 @@ use axum::{
 
 use crate::{
     application::use_cases::mission_operation::MissionOperationUseCase,
-    domain::repositories::{
-        mission_operation::MissionOperationRepository, mission_viewing::MissionViewingRepository,
+    domain::{
+        repositories::{
+            mission_operation::MissionOperationRepository,
+            mission_viewing::MissionViewingRepository,
+        },
+        value_objects::{
+            brawler_progression::XpRules, mission_status_history::MissionTransitionModel,
+        },
     },
     infrastructure::{
         database::{
@@ -30,15 +36,18 @@ pub async fn in_progress<T1, T2>(
     State(user_case): State<Arc<MissionOperationUseCase<T1, T2>>>,
     Extension(user_id): Extension<i32>,
     Path(mission_id): Path<i32>,
+    body: Option<Json<MissionTransitionModel>>,
 ) -> impl IntoResponse
 where
     T1: MissionOperationRepository + Send + Sync,
     T2: MissionViewingRepository + Send + Sync,
 {
-    match user_case.in_progress(mission_id, user_id).await {
+    let reason = body.and_then(|Json(model)| model.reason);
+
+    match user_case.in_progress(mission_id, user_id, reason).await {
         Ok(mission_id) => (StatusCode::OK, mission_id.to_string()).into_response(),
 
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
@@ -46,15 +55,18 @@ pub async fn to_completed<T1, T2>(
     State(user_case): State<Arc<MissionOperationUseCase<T1, T2>>>,
     Extension(user_id): Extension<i32>,
     Path(mission_id): Path<i32>,
+    body: Option<Json<MissionTransitionModel>>,
 ) -> impl IntoResponse
 where
     T1: MissionOperationRepository + Send + Sync,
     T2: MissionViewingRepository + Send + Sync,
 {
-    match user_case.to_completed(mission_id, user_id).await {
+    let reason = body.and_then(|Json(model)| model.reason);
+
+    match user_case.to_completed(mission_id, user_id, reason).await {
         Ok(mission_id) => (StatusCode::OK, mission_id.to_string()).into_response(),
 
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
@@ -62,20 +74,23 @@ pub async fn to_failed<T1, T2>(
     State(user_case): State<Arc<MissionOperationUseCase<T1, T2>>>,
     Extension(user_id): Extension<i32>,
     Path(mission_id): Path<i32>,
+    body: Option<Json<MissionTransitionModel>>,
 ) -> impl IntoResponse
 where
     T1: MissionOperationRepository + Send + Sync,
     T2: MissionViewingRepository + Send + Sync,
 {
-    match user_case.to_failed(mission_id, user_id).await {
+    let reason = body.and_then(|Json(model)| model.reason);
+
+    match user_case.to_failed(mission_id, user_id, reason).await {
         Ok(mission_id) => (StatusCode::OK, mission_id.to_string()).into_response(),
 
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
-pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
-    let mission_repository = MissionOperationPostgres::new(Arc::clone(&db_pool));
+pub fn routes(db_pool: Arc<PgPoolSquad>, xp_rules: XpRules) -> Router {
+    let mission_repository = MissionOperationPostgres::new(Arc::clone(&db_pool), xp_rules);
     let viewing_repositiory = MissionViewingPostgres::new(Arc::clone(&db_pool));
     let user_case =
         MissionOperationUseCase::new(Arc::new(mission_repository), Arc::new(viewing_repositiory));
@@ -84,6 +99,6 @@ pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
         .route("/in-progress/{mission_id}", patch(in_progress))
         .route("/to-completed/{mission_id}", patch(to_completed))
         .route("/to-failed/{mission_id}", patch(to_failed))
-        .route_layer(middleware::from_fn(auth))
+        .route_layer(middleware::from_fn_with_state(Arc::clone(&db_pool), auth))
         .with_state(Arc::new(user_case))
 }
@@ -0,0 +1,127 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{patch, post},
+};
+
+use crate::{
+    application::use_cases::mission_series::MissionSeriesUseCase,
+    domain::{
+        repositories::{
+            mission_management::MissionManagementRepository,
+            mission_series::MissionSeriesRepository,
+        },
+        value_objects::{
+            crew_requirement::CrewLimits,
+            mission_series::{AddMissionSeriesModel, EditMissionSeriesModel},
+        },
+    },
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{
+                mission_management::MissionManagementPostgres,
+                mission_series::MissionSeriesPostgres,
+            },
+        },
+        http::middlewares::auth::auth,
+    },
+};
+
+pub async fn add<T1, T2>(
+    State(user_case): State<Arc<MissionSeriesUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Json(model): Json<AddMissionSeriesModel>,
+) -> impl IntoResponse
+where
+    T1: MissionSeriesRepository + Send + Sync,
+    T2: MissionManagementRepository + Send + Sync,
+{
+    match user_case.add(user_id, model).await {
+        Ok(series_id) => {
+            let json_value = serde_json::json!({
+                "series_id": series_id,
+            });
+            (StatusCode::CREATED, axum::Json(json_value)).into_response()
+        }
+
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn get_all<T1, T2>(
+    State(user_case): State<Arc<MissionSeriesUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T1: MissionSeriesRepository + Send + Sync,
+    T2: MissionManagementRepository + Send + Sync,
+{
+    match user_case.get_all(user_id).await {
+        Ok(model) => (StatusCode::OK, Json(model)).into_response(),
+
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn edit<T1, T2>(
+    State(user_case): State<Arc<MissionSeriesUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Path(series_id): Path<i32>,
+    Json(model): Json<EditMissionSeriesModel>,
+) -> impl IntoResponse
+where
+    T1: MissionSeriesRepository + Send + Sync,
+    T2: MissionManagementRepository + Send + Sync,
+{
+    match user_case.edit(series_id, user_id, model).await {
+        Ok(series_id) => (
+            StatusCode::OK,
+            format!("Edit series_id: {} completed!!", series_id),
+        )
+            .into_response(),
+
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn remove<T1, T2>(
+    State(user_case): State<Arc<MissionSeriesUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Path(series_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: MissionSeriesRepository + Send + Sync,
+    T2: MissionManagementRepository + Send + Sync,
+{
+    match user_case.remove(series_id, user_id).await {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Remove series_id: {} completed!!", series_id),
+        )
+            .into_response(),
+
+        Err(e) => e.into_response(),
+    }
+}
+
+pub fn routes(db_pool: Arc<PgPoolSquad>, crew_limits: CrewLimits) -> Router {
+    let series_repository = MissionSeriesPostgres::new(Arc::clone(&db_pool));
+    let mission_repository = MissionManagementPostgres::new(Arc::clone(&db_pool));
+    let user_case = MissionSeriesUseCase::new(
+        Arc::new(series_repository),
+        Arc::new(mission_repository),
+        crew_limits,
+    );
+
+    Router::new()
+        .route("/", post(add).get(get_all))
+        .route("/{series_id}", patch(edit).delete(remove))
+        .route_layer(middleware::from_fn_with_state(Arc::clone(&db_pool), auth))
+        .with_state(Arc::new(user_case))
+}
@@ -0,0 +1,154 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{patch, post},
+};
+
+use crate::{
+    application::use_cases::mission_templates::MissionTemplateUseCase,
+    domain::{
+        repositories::{
+            mission_management::MissionManagementRepository,
+            mission_templates::MissionTemplateRepository,
+        },
+        value_objects::{
+            crew_requirement::CrewLimits,
+            mission_template::{
+                AddMissionTemplateModel, EditMissionTemplateModel, MissionFromTemplateModel,
+            },
+        },
+    },
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{
+                mission_management::MissionManagementPostgres,
+                mission_templates::MissionTemplatePostgres,
+            },
+        },
+        http::middlewares::auth::auth,
+    },
+};
+
+pub async fn add<T1, T2>(
+    State(user_case): State<Arc<MissionTemplateUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Json(model): Json<AddMissionTemplateModel>,
+) -> impl IntoResponse
+where
+    T1: MissionTemplateRepository + Send + Sync,
+    T2: MissionManagementRepository + Send + Sync,
+{
+    match user_case.add(user_id, model).await {
+        Ok(template_id) => {
+            let json_value = serde_json::json!({
+                "template_id": template_id,
+            });
+            (StatusCode::CREATED, axum::Json(json_value)).into_response()
+        }
+
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn get_all<T1, T2>(
+    State(user_case): State<Arc<MissionTemplateUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T1: MissionTemplateRepository + Send + Sync,
+    T2: MissionManagementRepository + Send + Sync,
+{
+    match user_case.get_all(user_id).await {
+        Ok(model) => (StatusCode::OK, Json(model)).into_response(),
+
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn edit<T1, T2>(
+    State(user_case): State<Arc<MissionTemplateUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Path(template_id): Path<i32>,
+    Json(model): Json<EditMissionTemplateModel>,
+) -> impl IntoResponse
+where
+    T1: MissionTemplateRepository + Send + Sync,
+    T2: MissionManagementRepository + Send + Sync,
+{
+    match user_case.edit(template_id, user_id, model).await {
+        Ok(template_id) => (
+            StatusCode::OK,
+            format!("Edit template_id: {} completed!!", template_id),
+        )
+            .into_response(),
+
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn remove<T1, T2>(
+    State(user_case): State<Arc<MissionTemplateUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Path(template_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: MissionTemplateRepository + Send + Sync,
+    T2: MissionManagementRepository + Send + Sync,
+{
+    match user_case.remove(template_id, user_id).await {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Remove template_id: {} completed!!", template_id),
+        )
+            .into_response(),
+
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn add_mission<T1, T2>(
+    State(user_case): State<Arc<MissionTemplateUseCase<T1, T2>>>,
+    Extension(user_id): Extension<i32>,
+    Path(template_id): Path<i32>,
+    body: Option<Json<MissionFromTemplateModel>>,
+) -> impl IntoResponse
+where
+    T1: MissionTemplateRepository + Send + Sync,
+    T2: MissionManagementRepository + Send + Sync,
+{
+    let model = body.map(|Json(model)| model).unwrap_or_default();
+
+    match user_case.add_mission(template_id, user_id, model).await {
+        Ok(mission_id) => {
+            let json_value = serde_json::json!({
+                "mission_id": mission_id,
+            });
+            (StatusCode::CREATED, axum::Json(json_value)).into_response()
+        }
+
+        Err(e) => e.into_response(),
+    }
+}
+
+pub fn routes(db_pool: Arc<PgPoolSquad>, crew_limits: CrewLimits) -> Router {
+    let template_repository = MissionTemplatePostgres::new(Arc::clone(&db_pool));
+    let mission_repository = MissionManagementPostgres::new(Arc::clone(&db_pool));
+    let user_case = MissionTemplateUseCase::new(
+        Arc::new(template_repository),
+        Arc::new(mission_repository),
+        crew_limits,
+    );
+
+    Router::new()
+        .route("/", post(add).get(get_all))
+        .route("/{template_id}", patch(edit).delete(remove))
+        .route("/{template_id}/missions", post(add_mission))
+        .route_layer(middleware::from_fn_with_state(Arc::clone(&db_pool), auth))
+        .with_state(Arc::new(user_case))
+}
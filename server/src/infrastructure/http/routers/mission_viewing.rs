@@ -11,82 +11,131 @@ use axum::{
 use crate::{
     application::use_cases::mission_viewing::MissionViewingUseCase,
     domain::{
-        repositories::mission_viewing::MissionViewingRepository,
-        value_objects::mission_filter::MissionFilter,
+        repositories::{
+            mission_status_history::MissionStatusHistoryRepository,
+            mission_viewing::MissionViewingRepository,
+        },
+        value_objects::{
+            brawler_progression::XpRules, mission_filter::MissionFilter,
+            pagination::MissionPageQuery,
+        },
     },
     infrastructure::database::{
-        postgresql_connection::PgPoolSquad, repositories::mission_viewing::MissionViewingPostgres,
+        postgresql_connection::PgPoolSquad,
+        repositories::{
+            mission_status_history::MissionStatusHistoryPostgres,
+            mission_viewing::MissionViewingPostgres,
+        },
     },
 };
 
-pub async fn get_one<T>(
-    State(user_case): State<Arc<MissionViewingUseCase<T>>>,
+pub async fn get_one<T1, T2>(
+    State(user_case): State<Arc<MissionViewingUseCase<T1, T2>>>,
     Path(mission_id): Path<i32>,
 ) -> impl IntoResponse
 where
-    T: MissionViewingRepository + Send + Sync,
+    T1: MissionViewingRepository + Send + Sync,
+    T2: MissionStatusHistoryRepository + Send + Sync,
 {
     match user_case.get_one(mission_id).await {
         Ok(model) => (StatusCode::OK, Json(model)).into_response(),
 
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
-pub async fn get_crew<T>(
-    State(user_case): State<Arc<MissionViewingUseCase<T>>>,
+pub async fn get_crew<T1, T2>(
+    State(user_case): State<Arc<MissionViewingUseCase<T1, T2>>>,
     Path(mission_id): Path<i32>,
 ) -> impl IntoResponse
 where
-    T: MissionViewingRepository + Send + Sync,
+    T1: MissionViewingRepository + Send + Sync,
+    T2: MissionStatusHistoryRepository + Send + Sync,
 {
     match user_case.get_crew(mission_id).await {
         Ok(model) => (StatusCode::OK, Json(model)).into_response(),
 
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
-pub async fn get_all<T>(
-    State(user_case): State<Arc<MissionViewingUseCase<T>>>,
+pub async fn get_all<T1, T2>(
+    State(user_case): State<Arc<MissionViewingUseCase<T1, T2>>>,
+    viewer: Option<Extension<i32>>,
     filter: Query<MissionFilter>,
+    page: Query<MissionPageQuery>,
 ) -> impl IntoResponse
 where
-    T: MissionViewingRepository + Send + Sync,
+    T1: MissionViewingRepository + Send + Sync,
+    T2: MissionStatusHistoryRepository + Send + Sync,
 {
-    match user_case.get_all(&filter).await {
+    let viewer_id = viewer.map(|Extension(user_id)| user_id);
+
+    match user_case.get_all(&filter, viewer_id, &page).await {
         Ok(model) => (StatusCode::OK, Json(model)).into_response(),
 
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
-pub async fn get_my_memberships<T>(
-    State(user_case): State<Arc<MissionViewingUseCase<T>>>,
+pub async fn get_my_memberships<T1, T2>(
+    State(user_case): State<Arc<MissionViewingUseCase<T1, T2>>>,
     Extension(user_id): Extension<i32>,
 ) -> impl IntoResponse
 where
-    T: MissionViewingRepository + Send + Sync,
+    T1: MissionViewingRepository + Send + Sync,
+    T2: MissionStatusHistoryRepository + Send + Sync,
 {
     match user_case.get_my_memberships(user_id).await {
         Ok(model) => (StatusCode::OK, Json(model)).into_response(),
 
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn get_history<T1, T2>(
+    State(user_case): State<Arc<MissionViewingUseCase<T1, T2>>>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: MissionViewingRepository + Send + Sync,
+    T2: MissionStatusHistoryRepository + Send + Sync,
+{
+    match user_case.get_history(mission_id).await {
+        Ok(model) => (StatusCode::OK, Json(model)).into_response(),
+
+        Err(e) => e.into_response(),
     }
 }
 
-pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+pub fn routes(db_pool: Arc<PgPoolSquad>, xp_rules: XpRules) -> Router {
     let viewing_repositiory = MissionViewingPostgres::new(Arc::clone(&db_pool));
-    let user_case = MissionViewingUseCase::new(Arc::new(viewing_repositiory));
+    let status_history_repository = MissionStatusHistoryPostgres::new(Arc::clone(&db_pool));
+    let user_case = MissionViewingUseCase::new(
+        Arc::new(viewing_repositiory),
+        Arc::new(status_history_repository),
+        xp_rules,
+    );
 
-    use crate::infrastructure::http::middlewares::auth::auth;
+    use crate::infrastructure::http::middlewares::auth::{auth, optional_auth};
     use axum::middleware;
 
     Router::new()
         .route("/{mission_id}", get(get_one))
-        .route("/filter", get(get_all))
+        .route(
+            "/filter",
+            get(get_all).route_layer(middleware::from_fn_with_state(
+                Arc::clone(&db_pool),
+                optional_auth,
+            )),
+        )
         .route("/crew/{mission_id}", get(get_crew))
-        .route("/my-memberships", get(get_my_memberships).route_layer(middleware::from_fn(auth)))
+        .route("/{mission_id}/history", get(get_history))
+        .route(
+            "/my-memberships",
+            get(get_my_memberships)
+                .route_layer(middleware::from_fn_with_state(Arc::clone(&db_pool), auth)),
+        )
         // .route_layer(middleware::from_fn(auth))
         .with_state(Arc::new(user_case))
 }
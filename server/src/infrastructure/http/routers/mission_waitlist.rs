@@ -0,0 +1,116 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{get, patch, post},
+};
+
+use crate::{
+    application::use_cases::mission_waitlist::MissionWaitlistUseCase,
+    domain::repositories::mission_waitlist::MissionWaitlistRepository,
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::mission_waitlist::MissionWaitlistPostgres,
+        },
+        http::middlewares::auth::auth,
+    },
+};
+
+pub async fn enqueue<T>(
+    State(user_case): State<Arc<MissionWaitlistUseCase<T>>>,
+    Extension(user_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: MissionWaitlistRepository + Send + Sync,
+{
+    match user_case.enqueue(mission_id, user_id).await {
+        Ok(model) => (StatusCode::CREATED, Json(model)).into_response(),
+
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn withdraw<T>(
+    State(user_case): State<Arc<MissionWaitlistUseCase<T>>>,
+    Extension(user_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: MissionWaitlistRepository + Send + Sync,
+{
+    match user_case.withdraw(mission_id, user_id).await {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Left waitlist of Mission_id:{}", mission_id),
+        )
+            .into_response(),
+
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn position<T>(
+    State(user_case): State<Arc<MissionWaitlistUseCase<T>>>,
+    Extension(user_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: MissionWaitlistRepository + Send + Sync,
+{
+    match user_case.position(mission_id, user_id).await {
+        Ok(model) => (StatusCode::OK, Json(model)).into_response(),
+
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn get_promotions<T>(
+    State(user_case): State<Arc<MissionWaitlistUseCase<T>>>,
+    Extension(user_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T: MissionWaitlistRepository + Send + Sync,
+{
+    match user_case.get_promotions(user_id).await {
+        Ok(model) => (StatusCode::OK, Json(model)).into_response(),
+
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn mark_promotion_seen<T>(
+    State(user_case): State<Arc<MissionWaitlistUseCase<T>>>,
+    Extension(user_id): Extension<i32>,
+    Path(promotion_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: MissionWaitlistRepository + Send + Sync,
+{
+    match user_case.mark_promotion_seen(promotion_id, user_id).await {
+        Ok(_) => StatusCode::NO_CONTENT.into_response(),
+
+        Err(e) => e.into_response(),
+    }
+}
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let waitlist_repository = MissionWaitlistPostgres::new(Arc::clone(&db_pool));
+    let user_case = MissionWaitlistUseCase::new(Arc::new(waitlist_repository));
+
+    Router::new()
+        .route("/{mission_id}", post(enqueue).delete(withdraw))
+        .route("/{mission_id}/position", get(position))
+        .route("/promotions", get(get_promotions))
+        .route(
+            "/promotions/{promotion_id}/seen",
+            patch(mark_promotion_seen),
+        )
+        .route_layer(middleware::from_fn_with_state(Arc::clone(&db_pool), auth))
+        .with_state(Arc::new(user_case))
+}
@@ -1,7 +1,15 @@
 pub mod authentication;
 pub mod brawlers;
 pub mod crew_operation;
+pub mod leaderboards;
 pub mod default_router;
+pub mod mission_attachments;
+pub mod mission_comments;
+pub mod mission_consensus;
+pub mod mission_debrief;
 pub mod mission_management;
 pub mod mission_operation;
+pub mod mission_series;
+pub mod mission_templates;
 pub mod mission_viewing;
+pub mod mission_waitlist;
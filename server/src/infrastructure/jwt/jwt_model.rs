@@ -3,41 +3,76 @@ use anyhow::Result;
 use chrono::Duration;
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
 use crate::config::config_loader::get_jwt_env;
+use crate::domain::value_objects::brawler_progression::Progression;
 use crate::infrastructure::jwt::generate_token;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Passport {
     // pub token_type: String,
     pub token: String,
-    // pub expires_in: usize,
+    pub expires_in: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refresh_token: Option<String>,
     pub display_name: String,
     pub avatar_url: Option<String>,
     pub id: i32,
+    #[serde(flatten)]
+    pub progression: Progression,
 }
 
 impl Passport {
-    pub fn new(user_id: i32, display_name: String, avatar_url: Option<String>) -> Result<Self> {
+    pub fn new(
+        user_id: i32,
+        token_generation: i32,
+        display_name: String,
+        avatar_url: Option<String>,
+        progression: Progression,
+    ) -> Result<Self> {
         let jwt_env = get_jwt_env()?;
         let claims = Claims {
             sub: user_id.to_string(),
-            exp: (Utc::now() + Duration::days(jwt_env.ttl)).timestamp() as usize,
+            jti: Uuid::new_v4().to_string(),
+            gen: token_generation,
+            exp: (Utc::now() + Duration::minutes(jwt_env.access_ttl)).timestamp() as usize,
             iat: Utc::now().timestamp() as usize,
         };
         let token = generate_token(jwt_env.secret, &claims)?;
         Ok(Self {
             token,
+            expires_in: (jwt_env.access_ttl * 60) as usize,
+            refresh_token: None,
             display_name,
             avatar_url,
             id: user_id,
+            progression,
         })
     }
+
+    pub fn with_refresh_token(mut self, refresh_token: String) -> Self {
+        self.refresh_token = Some(refresh_token);
+        self
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Claims {
     pub sub: String,
+    pub jti: String,
+    // must match brawlers.token_generation, bumped by "log out everywhere"
+    pub gen: i32,
+    pub exp: usize,
+    pub iat: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RefreshClaims {
+    pub sub: String,
+    pub jti: String,
+    // every token rotated from the same login shares a family
+    pub fid: String,
     pub exp: usize,
     pub iat: usize,
 }
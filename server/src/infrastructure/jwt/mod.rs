@@ -3,8 +3,10 @@ pub mod jwt_model;
 
 use anyhow::{Ok, Result};
 use jsonwebtoken::{DecodingKey, EncodingKey, Header, Validation, decode, encode};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
 
-pub fn generate_token(secret: String, claims: &jwt_model::Claims) -> Result<String> {
+pub fn generate_token<T: Serialize>(secret: String, claims: &T) -> Result<String> {
     let token = encode(
         &Header::default(),
         claims,
@@ -23,3 +25,20 @@ pub fn verify_token(secret: String, token: String) -> Result<jwt_model::Claims>
 
     Ok(token.claims)
 }
+
+pub fn verify_refresh_token(secret: String, token: String) -> Result<jwt_model::RefreshClaims> {
+    let token = decode::<jwt_model::RefreshClaims>(
+        &token,
+        &DecodingKey::from_secret(secret.as_ref()),
+        &Validation::default(),
+    )?;
+
+    Ok(token.claims)
+}
+
+// Refresh tokens are only ever stored as a SHA-256 digest
+pub fn hash_token(token: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(token.as_bytes());
+    format!("{:x}", hasher.finalize())
+}
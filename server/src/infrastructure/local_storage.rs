@@ -5,32 +5,33 @@ use std::fs;
 use std::path::Path;
 use uuid::Uuid;
 
+const UPLOADS_DIR: &str = "uploads";
+
 pub async fn save_locally(base64_image: Base64Img) -> Result<UploadedImg> {
-    let uploads_dir = "uploads";
+    let uploads_dir = UPLOADS_DIR;
     if !Path::new(uploads_dir).exists() {
         fs::create_dir_all(uploads_dir).context("failed to create uploads directory")?;
     }
 
+    let ext = match base64_image.mime_type() {
+        "image/png" => "png",
+        "image/jpeg" => "jpg",
+        "image/webp" => "webp",
+        "application/pdf" => "pdf",
+        _ => anyhow::bail!("unsupported image type"),
+    };
+
     let data = base64_image.into_inner();
-    
-    // Extract mime type and actual base64 data
+
+    // Extract the actual base64 data
     // Format: data:image/png;base64,iVBORw0KGgoAAAANSUhEU...
     let parts: Vec<&str> = data.split(',').collect();
     if parts.len() != 2 {
         anyhow::bail!("invalid base64 image format");
     }
 
-    let header = parts[0];
     let base64_data = parts[1];
 
-    let ext = if header.contains("image/png") {
-        "png"
-    } else if header.contains("image/jpeg") || header.contains("image/jpg") {
-        "jpg"
-    } else {
-        anyhow::bail!("unsupported image type");
-    };
-
     let filename = format!("{}.{}", Uuid::new_v4(), ext);
     let file_path = format!("{}/{}", uploads_dir, filename);
 
@@ -47,3 +48,18 @@ pub async fn save_locally(base64_image: Base64Img) -> Result<UploadedImg> {
         public_id: filename,
     })
 }
+
+// `public_id` is the file name `save_locally` picked. A file that is already gone is not
+// an error, there is nothing left to clean up.
+pub async fn remove_locally(public_id: &str) -> Result<()> {
+    let file_name = Path::new(public_id)
+        .file_name()
+        .context("invalid local file name")?;
+    let file_path = Path::new(UPLOADS_DIR).join(file_name);
+
+    match fs::remove_file(&file_path) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(e).context(format!("failed to remove {}", file_path.display())),
+    }
+}
@@ -4,3 +4,4 @@ pub mod cloudinary;
 pub mod database;
 pub mod http;
 pub mod jwt;
+pub mod scheduler;
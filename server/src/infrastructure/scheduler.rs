@@ -0,0 +1,127 @@
+use std::{sync::Arc, time::Duration};
+
+use chrono::{TimeDelta, Utc};
+use tokio::task::JoinHandle;
+use tracing::{error, info};
+
+use crate::{
+    application::use_cases::{
+        leaderboards::LeaderboardUseCase, mission_consensus::MissionConsensusUseCase,
+        mission_management::MissionManagementUseCase, mission_scheduler::MissionSchedulerUseCase,
+        mission_series::MissionSeriesUseCase,
+    },
+    config::config_model::DotEnvyConfig,
+    domain::value_objects::{
+        brawler_progression::XpRules, crew_requirement::CrewLimits,
+        mission_consensus::ConsensusRules, mission_retention::MissionRetention,
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        repositories::{
+            leaderboards::LeaderboardPostgres, mission_consensus::MissionConsensusPostgres,
+            mission_management::MissionManagementPostgres,
+            mission_operation::MissionOperationPostgres,
+            mission_scheduler::MissionSchedulerPostgres, mission_series::MissionSeriesPostgres,
+            mission_viewing::MissionViewingPostgres,
+        },
+    },
+};
+
+// Background jobs that create the upcoming occurrences of recurring series, keep
+// missions in line with their schedule, close consensus votes that ran out of time,
+// delete removed missions past their retention and rebuild the leaderboards.
+// The leaderboards run last so they already count what this tick changed.
+// A failed run is logged and retried on the next tick instead of stopping the loop.
+pub fn spawn(
+    db_pool: Arc<PgPoolSquad>,
+    config: &DotEnvyConfig,
+    xp_rules: XpRules,
+) -> JoinHandle<()> {
+    let interval = Duration::from_secs(config.scheduler.interval);
+    let series_lead = TimeDelta::hours(config.scheduler.series_lead);
+    let leaderboard_refresh = TimeDelta::minutes(config.scheduler.leaderboard_refresh);
+    let crew_limits = CrewLimits::new(config.crew.min_crew, config.crew.max_crew);
+
+    let mission_series_use_case = MissionSeriesUseCase::new(
+        Arc::new(MissionSeriesPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(MissionManagementPostgres::new(Arc::clone(&db_pool))),
+        crew_limits,
+    );
+    let mission_scheduler_use_case = MissionSchedulerUseCase::new(
+        Arc::new(MissionSchedulerPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(MissionOperationPostgres::new(
+            Arc::clone(&db_pool),
+            xp_rules.clone(),
+        )),
+    );
+    let mission_management_use_case = MissionManagementUseCase::new(
+        Arc::new(MissionManagementPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(MissionViewingPostgres::new(Arc::clone(&db_pool))),
+        crew_limits,
+        MissionRetention::new(config.retention.deleted_missions),
+    );
+
+    let mission_consensus_use_case = MissionConsensusUseCase::new(
+        Arc::new(MissionConsensusPostgres::new(
+            Arc::clone(&db_pool),
+            xp_rules,
+        )),
+        Arc::new(MissionViewingPostgres::new(Arc::clone(&db_pool))),
+        ConsensusRules::new(config.consensus.quorum, config.consensus.voting_window),
+    );
+
+    let leaderboard_use_case =
+        LeaderboardUseCase::new(Arc::new(LeaderboardPostgres::new(Arc::clone(&db_pool))));
+
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+
+            let now = Utc::now().naive_utc();
+            match mission_series_use_case
+                .materialize(now, now + series_lead)
+                .await
+            {
+                Ok(created) if !created.is_empty() => {
+                    info!("Mission series created missions {:?}", created)
+                }
+                Ok(_) => {}
+                Err(e) => error!("Mission series run failed: {}", e),
+            }
+
+            match mission_scheduler_use_case.run(now).await {
+                Ok(report) if !report.is_empty() => info!(
+                    "Mission scheduler started {:?}, failed {:?}, cancelled {:?}",
+                    report.started, report.failed, report.cancelled
+                ),
+                Ok(_) => {}
+                Err(e) => error!("Mission scheduler run failed: {}", e),
+            }
+
+            match mission_consensus_use_case.close_expired(now).await {
+                Ok(disputed) if !disputed.is_empty() => {
+                    info!("Mission consensus disputed missions {:?}", disputed)
+                }
+                Ok(_) => {}
+                Err(e) => error!("Mission consensus run failed: {}", e),
+            }
+
+            match mission_management_use_case.purge_deleted(now).await {
+                Ok(purged) if !purged.is_empty() => {
+                    info!("Mission retention purged missions {:?}", purged)
+                }
+                Ok(_) => {}
+                Err(e) => error!("Mission retention run failed: {}", e),
+            }
+
+            match leaderboard_use_case.refresh(now, leaderboard_refresh).await {
+                Ok(true) => info!("Leaderboards refreshed"),
+                Ok(false) => {}
+                Err(e) => error!("Leaderboard refresh failed: {}", e),
+            }
+        }
+    })
+}
@@ -0,0 +1,122 @@
+use std::sync::Arc;
+
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl, delete, insert_into};
+use server::{
+    application::use_cases::crew_operation::CrewOperationUseCase,
+    domain::{
+        entities::brawlers::RegisterBrawlerEntity, errors::AppError,
+        value_objects::{join_requests::JoinOutcome, mission_statuses::MissionStatuses},
+    },
+    infrastructure::database::{
+        postgresql_connection::{PgPoolSquad, establish_connection},
+        repositories::{
+            crew_operation::CrewOperationPostgres, join_requests::JoinRequestPostgres,
+            mission_viewing::MissionViewingPostgres,
+        },
+        schema::{brawlers, crew_memberships, missions},
+    },
+};
+
+const JOINERS: usize = 16;
+
+fn database_pool() -> Option<PgPoolSquad> {
+    dotenvy::dotenv().ok();
+    let database_url = std::env::var("DATABASE_URL").ok()?;
+    establish_connection(&database_url).ok()
+}
+
+fn create_brawler(pool: &PgPoolSquad, prefix: &str) -> i32 {
+    let mut conn = pool.get().unwrap();
+    let username = format!("{}-{}", prefix, uuid::Uuid::new_v4());
+    insert_into(brawlers::table)
+        .values(RegisterBrawlerEntity {
+            username: username.clone(),
+            password: "not-a-real-hash".to_string(),
+            display_name: username,
+        })
+        .returning(brawlers::id)
+        .get_result::<i32>(&mut conn)
+        .unwrap()
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 8)]
+async fn parallel_joins_never_overfill_a_mission() {
+    let Some(pool) = database_pool() else {
+        eprintln!("DATABASE_URL is not set, skipping crew join concurrency test");
+        return;
+    };
+    let pool = Arc::new(pool);
+
+    let chief_id = create_brawler(&pool, "chief");
+    let crew_ids: Vec<i32> = (0..JOINERS)
+        .map(|_| create_brawler(&pool, "crew"))
+        .collect();
+
+    let mission_id = {
+        let mut conn = pool.get().unwrap();
+        insert_into(missions::table)
+            .values((
+                missions::name.eq("crowded mission"),
+                missions::chief_id.eq(chief_id),
+                missions::status.eq(MissionStatuses::Open),
+                missions::difficulty.eq("Easy"),
+                missions::max_crew.eq(1),
+            ))
+            .returning(missions::id)
+            .get_result::<i32>(&mut conn)
+            .unwrap()
+    };
+
+    let use_case = Arc::new(CrewOperationUseCase::new(
+        Arc::new(CrewOperationPostgres::new(Arc::clone(&pool))),
+        Arc::new(MissionViewingPostgres::new(Arc::clone(&pool))),
+        Arc::new(JoinRequestPostgres::new(Arc::clone(&pool))),
+    ));
+
+    let handles: Vec<_> = crew_ids
+        .iter()
+        .map(|&brawler_id| {
+            let use_case = Arc::clone(&use_case);
+            tokio::spawn(async move { use_case.join(mission_id, brawler_id).await })
+        })
+        .collect();
+
+    let mut joined = 0;
+    let mut full = 0;
+    for handle in handles {
+        match handle.await.unwrap() {
+            Ok(JoinOutcome::Joined) => joined += 1,
+            Err(AppError::MissionFull(_)) => full += 1,
+            other => panic!("unexpected join result: {:?}", other),
+        }
+    }
+
+    let crew_count = {
+        let mut conn = pool.get().unwrap();
+        crew_memberships::table
+            .filter(crew_memberships::mission_id.eq(mission_id))
+            .count()
+            .get_result::<i64>(&mut conn)
+            .unwrap()
+    };
+
+    {
+        let mut conn = pool.get().unwrap();
+        delete(crew_memberships::table)
+            .filter(crew_memberships::mission_id.eq(mission_id))
+            .execute(&mut conn)
+            .unwrap();
+        delete(missions::table)
+            .filter(missions::id.eq(mission_id))
+            .execute(&mut conn)
+            .unwrap();
+        delete(brawlers::table)
+            .filter(brawlers::id.eq_any(crew_ids.iter().copied().chain([chief_id])))
+            .execute(&mut conn)
+            .unwrap();
+    }
+
+    assert_eq!(joined, 1);
+    assert_eq!(full, JOINERS - 1);
+    assert_eq!(crew_count, 1);
+}